@@ -258,6 +258,38 @@ def test_cursor_op():
     execute_insert("close cur",conn)
     drop_table_subscription()
 
+def test_cursor_ops_filter():
+    print(f"test_cursor_ops_filter")
+    create_table_subscription()
+    conn = psycopg2.connect(
+        host="localhost",
+        port="4566",
+        user="root",
+        database="dev"
+    )
+
+    execute_insert("create subscription sub_ops from t1 with(retention = '1D', ops = 'insert,delete')",conn)
+    execute_insert("declare cur subscription cursor for sub_ops",conn)
+
+    execute_insert("insert into t1 values(4,4)",conn)
+    execute_insert("flush",conn)
+    execute_insert("update t1 set v2 = 10 where v1 = 4",conn)
+    execute_insert("flush",conn)
+    execute_insert("delete from t1 where v1 = 4",conn)
+    execute_insert("flush",conn)
+
+    # The update halves are excluded by the `ops` option.
+    row = execute_query("fetch next from cur",conn)
+    check_rows_data([4,4],row[0],"Insert")
+    row = execute_query("fetch next from cur",conn)
+    check_rows_data([4,10],row[0],"Delete")
+    row = execute_query("fetch next from cur",conn)
+    assert row == []
+
+    execute_insert("close cur",conn)
+    execute_insert("drop subscription sub_ops",conn)
+    drop_table_subscription()
+
 def test_cursor_with_table_alter():
     print(f"test_cursor_with_table_alter")
     create_table_subscription()
@@ -580,6 +612,7 @@ def test_explain_cursor():
 if __name__ == "__main__":
     test_cursor_snapshot()
     test_cursor_op()
+    test_cursor_ops_filter()
     test_cursor_snapshot_log_store()
     test_cursor_since_rw_timestamp()
     test_cursor_since_now()
@@ -98,7 +98,7 @@ fn create_hash_agg_executor(
         .chain(agg_init_states.iter().map(|fac| fac.return_type()))
         .map(Field::unnamed)
         .collect_vec();
-    let schema = Schema { fields };
+    let schema = Schema::new(fields);
 
     Box::new(HashAggExecutor::<hash::Key64>::new(
         Arc::new(agg_init_states),
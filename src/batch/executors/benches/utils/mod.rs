@@ -77,9 +77,9 @@ pub fn create_input(
     chunk_size: usize,
     chunk_num: usize,
 ) -> BoxedExecutor {
-    let mut input = MockExecutor::new(Schema {
-        fields: input_types.iter().cloned().map(Field::unnamed).collect(),
-    });
+    let mut input = MockExecutor::new(Schema::new(
+        input_types.iter().cloned().map(Field::unnamed).collect(),
+    ));
     for c in gen_data(chunk_size, chunk_num, input_types) {
         input.add(c);
     }
@@ -78,9 +78,7 @@ impl DeleteExecutor {
             schema: if returning {
                 table_schema
             } else {
-                Schema {
-                    fields: vec![Field::unnamed(DataType::Int64)],
-                }
+                Schema::new(vec![Field::unnamed(DataType::Int64)])
             },
             identity,
             returning,
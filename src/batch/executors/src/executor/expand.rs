@@ -135,24 +135,20 @@ mod tests {
 
     #[tokio::test]
     async fn test_expand_executor() {
-        let mock_schema = Schema {
-            fields: vec![
-                Field::unnamed(DataType::Int32),
-                Field::unnamed(DataType::Int32),
-                Field::unnamed(DataType::Int32),
-            ],
-        };
-        let expand_schema = Schema {
-            fields: vec![
-                Field::unnamed(DataType::Int32),
-                Field::unnamed(DataType::Int32),
-                Field::unnamed(DataType::Int32),
-                Field::unnamed(DataType::Int32),
-                Field::unnamed(DataType::Int32),
-                Field::unnamed(DataType::Int32),
-                Field::unnamed(DataType::Int64),
-            ],
-        };
+        let mock_schema = Schema::new(vec![
+            Field::unnamed(DataType::Int32),
+            Field::unnamed(DataType::Int32),
+            Field::unnamed(DataType::Int32),
+        ]);
+        let expand_schema = Schema::new(vec![
+            Field::unnamed(DataType::Int32),
+            Field::unnamed(DataType::Int32),
+            Field::unnamed(DataType::Int32),
+            Field::unnamed(DataType::Int32),
+            Field::unnamed(DataType::Int32),
+            Field::unnamed(DataType::Int32),
+            Field::unnamed(DataType::Int64),
+        ]);
         let mut mock_executor = MockExecutor::new(mock_schema);
         mock_executor.add(DataChunk::from_pretty(
             "i i i
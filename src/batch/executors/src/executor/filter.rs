@@ -145,9 +145,8 @@ mod tests {
         let chunk = DataChunk::new(vec![builder.finish().into_ref()], 4);
 
         // Initialize mock executor
-        let mut mock_executor = MockExecutor::new(Schema {
-            fields: vec![Field::unnamed(DataType::Int32.list())],
-        });
+        let mut mock_executor =
+            MockExecutor::new(Schema::new(vec![Field::unnamed(DataType::Int32.list())]));
         mock_executor.add(chunk);
 
         // Initialize filter executor
@@ -188,12 +187,10 @@ mod tests {
 
     #[tokio::test]
     async fn test_filter_executor() {
-        let schema = Schema {
-            fields: vec![
-                Field::unnamed(DataType::Int32),
-                Field::unnamed(DataType::Int32),
-            ],
-        };
+        let schema = Schema::new(vec![
+            Field::unnamed(DataType::Int32),
+            Field::unnamed(DataType::Int32),
+        ]);
         let mut mock_executor = MockExecutor::new(schema);
         mock_executor.add(DataChunk::from_pretty(
             "i i
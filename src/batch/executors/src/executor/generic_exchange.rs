@@ -173,7 +173,7 @@ impl BoxedExecutorBuilder for GenericExchangeExecutorBuilder {
             source_creators,
             sequential,
             context: source.context().clone(),
-            schema: Schema { fields },
+            schema: Schema::new(fields),
             task_id: source.task_id.clone(),
             identity: source.plan_node().get_identity().clone(),
             metrics: source.context().batch_metrics(),
@@ -292,9 +292,7 @@ mod tests {
             source_creators,
             sequential: false,
             context,
-            schema: Schema {
-                fields: vec![Field::unnamed(DataType::Int32)],
-            },
+            schema: Schema::new(vec![Field::unnamed(DataType::Int32)]),
             task_id: TaskId::default(),
             identity: "GenericExchangeExecutor2".to_owned(),
         });
@@ -153,7 +153,7 @@ impl BoxedExecutorBuilder for GetChannelDeltaStatsExecutor {
             Field::new("send_throughput", DataType::Float64),
         ];
 
-        let schema = Schema { fields };
+        let schema = Schema::new(fields);
 
         // Get the MetricsReader from the batch task context
         let metrics_reader = source.context().metrics_reader();
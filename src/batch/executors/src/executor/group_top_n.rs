@@ -248,13 +248,11 @@ mod tests {
     async fn test_group_top_n_executor() {
         let parent_mem = MemoryContext::root(LabelGuardedIntGauge::test_int_gauge::<4>(), u64::MAX);
         {
-            let schema = Schema {
-                fields: vec![
-                    Field::unnamed(DataType::Int32),
-                    Field::unnamed(DataType::Int32),
-                    Field::unnamed(DataType::Int32),
-                ],
-            };
+            let schema = Schema::new(vec![
+                Field::unnamed(DataType::Int32),
+                Field::unnamed(DataType::Int32),
+                Field::unnamed(DataType::Int32),
+            ]);
             let mut mock_executor = MockExecutor::new(schema);
             mock_executor.add(DataChunk::from_pretty(
                 "i i i
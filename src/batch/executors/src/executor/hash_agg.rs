@@ -135,7 +135,7 @@ impl HashAggExecutorBuilder {
             group_key_columns,
             group_key_types,
             child,
-            schema: Schema { fields },
+            schema: Schema::new(fields),
             task_id,
             identity,
             chunk_size,
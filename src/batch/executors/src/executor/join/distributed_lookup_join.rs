@@ -110,8 +110,8 @@ impl BoxedExecutorBuilder for DistributedLookupJoinExecutorBuilder {
             .get_inner_side_column_ids()
             .clone();
 
-        let inner_side_schema = Schema {
-            fields: inner_side_column_ids
+        let inner_side_schema = Schema::new(
+            inner_side_column_ids
                 .iter()
                 .map(|&id| {
                     let column = table_desc
@@ -122,7 +122,7 @@ impl BoxedExecutorBuilder for DistributedLookupJoinExecutorBuilder {
                     Field::from(&ColumnDesc::from(column))
                 })
                 .collect_vec(),
-        };
+        );
 
         let fields = if join_type == JoinType::LeftSemi || join_type == JoinType::LeftAnti {
             outer_side_input.schema().fields.clone()
@@ -134,7 +134,7 @@ impl BoxedExecutorBuilder for DistributedLookupJoinExecutorBuilder {
             .concat()
         };
 
-        let original_schema = Schema { fields };
+        let original_schema = Schema::new(fields);
         let actual_schema = output_indices
             .iter()
             .map(|&idx| original_schema[idx].clone())
@@ -2639,12 +2639,10 @@ mod tests {
         }
 
         fn create_left_executor(&self) -> BoxedExecutor {
-            let schema = Schema {
-                fields: vec![
-                    Field::unnamed(DataType::Int32),
-                    Field::unnamed(DataType::Float32),
-                ],
-            };
+            let schema = Schema::new(vec![
+                Field::unnamed(DataType::Int32),
+                Field::unnamed(DataType::Float32),
+            ]);
             let mut executor = MockExecutor::new(schema);
 
             executor.add(DataChunk::from_pretty(
@@ -2669,12 +2667,10 @@ mod tests {
         }
 
         fn create_right_executor(&self) -> BoxedExecutor {
-            let schema = Schema {
-                fields: vec![
-                    Field::unnamed(DataType::Int32),
-                    Field::unnamed(DataType::Float64),
-                ],
-            };
+            let schema = Schema::new(vec![
+                Field::unnamed(DataType::Int32),
+                Field::unnamed(DataType::Float64),
+            ]);
             let mut executor = MockExecutor::new(schema);
 
             executor.add(DataChunk::from_pretty(
@@ -3272,12 +3268,10 @@ mod tests {
     /// These should not be buffered or we will have duplicate rows in output.
     #[tokio::test]
     async fn test_left_semi_join_with_non_equi_condition_duplicates() {
-        let schema = Schema {
-            fields: vec![
-                Field::unnamed(DataType::Int32),
-                Field::unnamed(DataType::Float32),
-            ],
-        };
+        let schema = Schema::new(vec![
+            Field::unnamed(DataType::Int32),
+            Field::unnamed(DataType::Float32),
+        ]);
 
         // Build side
         let mut left_executor = MockExecutor::new(schema);
@@ -3291,12 +3285,10 @@ mod tests {
         ));
 
         // Probe side
-        let schema = Schema {
-            fields: vec![
-                Field::unnamed(DataType::Int32),
-                Field::unnamed(DataType::Float64),
-            ],
-        };
+        let schema = Schema::new(vec![
+            Field::unnamed(DataType::Int32),
+            Field::unnamed(DataType::Float64),
+        ]);
         let mut right_executor = MockExecutor::new(schema);
         right_executor.add(DataChunk::from_pretty(
             "i F
@@ -4095,18 +4087,14 @@ mod tests {
 
     #[tokio::test]
     async fn test_batch_hash_join_asof_ge_returns_closest_match() {
-        let left_schema = Schema {
-            fields: vec![
-                Field::unnamed(DataType::Int32),
-                Field::unnamed(DataType::Int32),
-            ],
-        };
-        let right_schema = Schema {
-            fields: vec![
-                Field::unnamed(DataType::Int32),
-                Field::unnamed(DataType::Int32),
-            ],
-        };
+        let left_schema = Schema::new(vec![
+            Field::unnamed(DataType::Int32),
+            Field::unnamed(DataType::Int32),
+        ]);
+        let right_schema = Schema::new(vec![
+            Field::unnamed(DataType::Int32),
+            Field::unnamed(DataType::Int32),
+        ]);
 
         let mut left_executor = MockExecutor::new(left_schema);
         left_executor.add(DataChunk::from_pretty(
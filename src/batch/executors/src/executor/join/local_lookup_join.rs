@@ -310,8 +310,8 @@ impl BoxedExecutorBuilder for LocalLookupJoinExecutorBuilder {
         let table_desc = lookup_join_node.get_inner_side_table_desc()?;
         let inner_side_column_ids = lookup_join_node.get_inner_side_column_ids().clone();
 
-        let inner_side_schema = Schema {
-            fields: inner_side_column_ids
+        let inner_side_schema = Schema::new(
+            inner_side_column_ids
                 .iter()
                 .map(|&id| {
                     let column = table_desc
@@ -322,7 +322,7 @@ impl BoxedExecutorBuilder for LocalLookupJoinExecutorBuilder {
                     Field::from(&ColumnDesc::from(column))
                 })
                 .collect_vec(),
-        };
+        );
 
         let fields = if join_type == JoinType::LeftSemi || join_type == JoinType::LeftAnti {
             outer_side_input.schema().fields.clone()
@@ -334,7 +334,7 @@ impl BoxedExecutorBuilder for LocalLookupJoinExecutorBuilder {
             .concat()
         };
 
-        let original_schema = Schema { fields };
+        let original_schema = Schema::new(fields);
         let actual_schema = output_indices
             .iter()
             .map(|&idx| original_schema[idx].clone())
@@ -515,12 +515,10 @@ mod tests {
     const CHUNK_SIZE: usize = 1024;
 
     fn create_outer_side_input() -> BoxedExecutor {
-        let schema = Schema {
-            fields: vec![
-                Field::unnamed(DataType::Int32),
-                Field::unnamed(DataType::Float32),
-            ],
-        };
+        let schema = Schema::new(vec![
+            Field::unnamed(DataType::Int32),
+            Field::unnamed(DataType::Float32),
+        ]);
         let mut executor = MockExecutor::new(schema);
 
         executor.add(DataChunk::from_pretty(
@@ -557,11 +555,9 @@ mod tests {
             ]
             .concat()
         };
-        let original_schema = Schema { fields };
+        let original_schema = Schema::new(fields);
 
-        let inner_side_schema = Schema {
-            fields: outer_side_input.schema().fields.clone(),
-        };
+        let inner_side_schema = Schema::new(outer_side_input.schema().fields.clone());
 
         let inner_side_data_types = inner_side_schema.data_types();
         let outer_side_data_types = outer_side_input.schema().data_types();
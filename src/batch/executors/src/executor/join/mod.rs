@@ -228,9 +228,7 @@ mod tests {
     #[test]
     fn test_convert_row_to_chunk() {
         let row = vec![Some(ScalarRefImpl::Int32(3))];
-        let probe_side_schema = Schema {
-            fields: vec![Field::unnamed(DataType::Int32)],
-        };
+        let probe_side_schema = Schema::new(vec![Field::unnamed(DataType::Int32)]);
         let const_row_chunk =
             convert_datum_refs_to_chunk(&row, 5, &probe_side_schema.data_types()).unwrap();
         assert_eq!(const_row_chunk.capacity(), 5);
@@ -554,12 +554,10 @@ mod tests {
         }
 
         fn create_left_executor(&self) -> BoxedExecutor {
-            let schema = Schema {
-                fields: vec![
-                    Field::unnamed(DataType::Int32),
-                    Field::unnamed(DataType::Float32),
-                ],
-            };
+            let schema = Schema::new(vec![
+                Field::unnamed(DataType::Int32),
+                Field::unnamed(DataType::Float32),
+            ]);
             let mut executor = MockExecutor::new(schema);
 
             executor.add(DataChunk::from_pretty(
@@ -582,12 +580,10 @@ mod tests {
         }
 
         fn create_right_executor(&self) -> BoxedExecutor {
-            let schema = Schema {
-                fields: vec![
-                    Field::unnamed(DataType::Int32),
-                    Field::unnamed(DataType::Float64),
-                ],
-            };
+            let schema = Schema::new(vec![
+                Field::unnamed(DataType::Int32),
+                Field::unnamed(DataType::Float64),
+            ]);
             let mut executor = MockExecutor::new(schema);
 
             executor.add(DataChunk::from_pretty(
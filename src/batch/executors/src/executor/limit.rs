@@ -172,9 +172,7 @@ mod tests {
                 .collect_vec()
                 .as_slice(),
         );
-        let schema = Schema {
-            fields: vec![Field::unnamed(DataType::Int32)],
-        };
+        let schema = Schema::new(vec![Field::unnamed(DataType::Int32)]);
         let mut mock_executor = MockExecutor::new(schema);
 
         let data_chunk = DataChunk::new([col].to_vec(), row_num);
@@ -287,12 +285,10 @@ mod tests {
         let visible_array = BoolArray::from_iter(visible.iter().cloned());
 
         let col1 = visible_array.into_ref();
-        let schema = Schema {
-            fields: vec![
-                Field::unnamed(DataType::Int32),
-                Field::unnamed(DataType::Boolean),
-            ],
-        };
+        let schema = Schema::new(vec![
+            Field::unnamed(DataType::Int32),
+            Field::unnamed(DataType::Boolean),
+        ]);
         let mut mock_executor = MockExecutor::new(schema);
 
         let data_chunk = DataChunk::new([col0, col1].to_vec(), row_num);
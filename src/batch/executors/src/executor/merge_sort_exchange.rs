@@ -160,7 +160,7 @@ impl BoxedExecutorBuilder for MergeSortExchangeExecutorBuilder {
             column_orders,
             proto_sources,
             source_creators,
-            Schema { fields },
+            Schema::new(fields),
             source.task_id.clone(),
             source.plan_node().get_identity().clone(),
             source.context().get_config().developer.chunk_size,
@@ -210,9 +210,7 @@ mod tests {
             column_orders,
             proto_sources,
             source_creators,
-            Schema {
-                fields: vec![Field::unnamed(DataType::Int32)],
-            },
+            Schema::new(vec![Field::unnamed(DataType::Int32)]),
             TaskId::default(),
             "MergeSortExchangeExecutor2".to_owned(),
             CHUNK_SIZE,
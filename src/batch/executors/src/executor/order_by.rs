@@ -435,12 +435,10 @@ mod tests {
 
     #[tokio::test]
     async fn test_simple_order_by_executor() {
-        let schema = Schema {
-            fields: vec![
-                Field::unnamed(DataType::Int32),
-                Field::unnamed(DataType::Int32),
-            ],
-        };
+        let schema = Schema::new(vec![
+            Field::unnamed(DataType::Int32),
+            Field::unnamed(DataType::Int32),
+        ]);
         let mut mock_executor = MockExecutor::new(schema);
         mock_executor.add(DataChunk::from_pretty(
             "i i
@@ -486,12 +484,10 @@ mod tests {
 
     #[tokio::test]
     async fn test_encoding_for_float() {
-        let schema = Schema {
-            fields: vec![
-                Field::unnamed(DataType::Float32),
-                Field::unnamed(DataType::Float64),
-            ],
-        };
+        let schema = Schema::new(vec![
+            Field::unnamed(DataType::Float32),
+            Field::unnamed(DataType::Float64),
+        ]);
         let mut mock_executor = MockExecutor::new(schema);
         mock_executor.add(DataChunk::from_pretty(
             " f    F
@@ -540,12 +536,10 @@ mod tests {
 
     #[tokio::test]
     async fn test_bsc_for_string() {
-        let schema = Schema {
-            fields: vec![
-                Field::unnamed(DataType::Varchar),
-                Field::unnamed(DataType::Varchar),
-            ],
-        };
+        let schema = Schema::new(vec![
+            Field::unnamed(DataType::Varchar),
+            Field::unnamed(DataType::Varchar),
+        ]);
         let mut mock_executor = MockExecutor::new(schema);
         mock_executor.add(DataChunk::from_pretty(
             "T   T
@@ -591,13 +585,11 @@ mod tests {
     // TODO: write following tests in a more concise way
     #[tokio::test]
     async fn test_encoding_for_boolean_int32_float64() {
-        let schema = Schema {
-            fields: vec![
-                Field::unnamed(DataType::Boolean),
-                Field::unnamed(DataType::Int32),
-                Field::unnamed(DataType::Float64),
-            ],
-        };
+        let schema = Schema::new(vec![
+            Field::unnamed(DataType::Boolean),
+            Field::unnamed(DataType::Int32),
+            Field::unnamed(DataType::Float64),
+        ]);
         // f   3    .
         // t   3    .
         // .   .    3.5
@@ -657,13 +649,11 @@ mod tests {
 
     #[tokio::test]
     async fn test_encoding_for_decimal_date_varchar() {
-        let schema = Schema {
-            fields: vec![
-                Field::unnamed(DataType::Varchar),
-                Field::unnamed(DataType::Decimal),
-                Field::unnamed(DataType::Date),
-            ],
-        };
+        let schema = Schema::new(vec![
+            Field::unnamed(DataType::Varchar),
+            Field::unnamed(DataType::Decimal),
+            Field::unnamed(DataType::Date),
+        ]);
         // abc       .     123
         // b         -3    789
         // abc       .     456
@@ -739,13 +729,11 @@ mod tests {
 
     #[tokio::test]
     async fn test_encoding_for_time_timestamp_interval() {
-        let schema = Schema {
-            fields: vec![
-                Field::unnamed(DataType::Time),
-                Field::unnamed(DataType::Timestamp),
-                Field::unnamed(DataType::Interval),
-            ],
-        };
+        let schema = Schema::new(vec![
+            Field::unnamed(DataType::Time),
+            Field::unnamed(DataType::Timestamp),
+            Field::unnamed(DataType::Interval),
+        ]);
         // .     1:23  .
         // 4:56  4:56  1:2:3
         // .     7:89  .
@@ -847,14 +835,10 @@ mod tests {
 
     #[tokio::test]
     async fn test_encoding_for_struct_list() {
-        let schema = Schema {
-            fields: vec![
-                Field::unnamed(
-                    StructType::unnamed(vec![DataType::Varchar, DataType::Float32]).into(),
-                ),
-                Field::unnamed(DataType::Int64.list()),
-            ],
-        };
+        let schema = Schema::new(vec![
+            Field::unnamed(StructType::unnamed(vec![DataType::Varchar, DataType::Float32]).into()),
+            Field::unnamed(DataType::Int64.list()),
+        ]);
         let mut struct_builder = StructArrayBuilder::with_type(
             0,
             DataType::Struct(StructType::unnamed(vec![
@@ -994,12 +978,10 @@ mod tests {
 
     #[tokio::test]
     async fn test_spill_out() {
-        let schema = Schema {
-            fields: vec![
-                Field::unnamed(DataType::Float32),
-                Field::unnamed(DataType::Float64),
-            ],
-        };
+        let schema = Schema::new(vec![
+            Field::unnamed(DataType::Float32),
+            Field::unnamed(DataType::Float64),
+        ]);
         let mut mock_executor = MockExecutor::new(schema);
         mock_executor.add(DataChunk::from_pretty(
             " f    F
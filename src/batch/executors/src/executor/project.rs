@@ -98,7 +98,7 @@ impl BoxedExecutorBuilder for ProjectExecutor {
         Ok(Box::new(Self {
             expr: project_exprs,
             child,
-            schema: Schema { fields },
+            schema: Schema::new(fields),
             identity: source.plan_node().get_identity().clone(),
         }))
     }
@@ -146,7 +146,7 @@ mod tests {
         let proj_executor = Box::new(ProjectExecutor {
             expr: expr_vec,
             child: Box::new(mock_executor),
-            schema: Schema { fields },
+            schema: Schema::new(fields),
             identity: "ProjectExecutor".to_owned(),
         });
 
@@ -167,7 +167,7 @@ impl BoxedExecutorBuilder for ProjectSetExecutor {
         Ok(Box::new(Self {
             select_list,
             child,
-            schema: Schema { fields },
+            schema: Schema::new(fields),
             identity: source.plan_node().get_identity().clone(),
             chunk_size: source.context().get_config().developer.chunk_size,
         }))
@@ -275,7 +275,7 @@ mod tests {
         let proj_executor = Box::new(ProjectSetExecutor {
             select_list,
             child: Box::new(mock_executor),
-            schema: Schema { fields },
+            schema: Schema::new(fields),
             identity: "ProjectSetExecutor".to_owned(),
             chunk_size: CHUNK_SIZE,
         });
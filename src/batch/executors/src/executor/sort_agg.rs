@@ -82,7 +82,7 @@ impl BoxedExecutorBuilder for SortAggExecutor {
             aggs,
             group_key,
             child,
-            schema: Schema { fields },
+            schema: Schema::new(fields),
             identity: source.plan_node().get_identity().clone(),
             output_size_limit: source.context().get_config().developer.chunk_size,
             shutdown_rx: source.shutdown_rx().clone(),
@@ -372,13 +372,11 @@ mod tests {
     #[tokio::test]
     async fn execute_count_star_int32() -> Result<()> {
         // mock a child executor
-        let schema = Schema {
-            fields: vec![
-                Field::unnamed(DataType::Int32),
-                Field::unnamed(DataType::Int32),
-                Field::unnamed(DataType::Int32),
-            ],
-        };
+        let schema = Schema::new(vec![
+            Field::unnamed(DataType::Int32),
+            Field::unnamed(DataType::Int32),
+            Field::unnamed(DataType::Int32),
+        ]);
         let mut child = MockExecutor::new(schema);
         child.add(DataChunk::from_pretty(
             "i i i
@@ -418,7 +416,7 @@ mod tests {
             aggs,
             group_key: group_exprs,
             child: Box::new(child),
-            schema: Schema { fields },
+            schema: Schema::new(fields),
             identity: "SortAggExecutor".to_owned(),
             output_size_limit: 3,
             shutdown_rx: ShutdownToken::empty(),
@@ -447,13 +445,11 @@ mod tests {
     #[tokio::test]
     async fn execute_count_star_int32_grouped() -> Result<()> {
         // mock a child executor
-        let schema = Schema {
-            fields: vec![
-                Field::unnamed(DataType::Int32),
-                Field::unnamed(DataType::Int32),
-                Field::unnamed(DataType::Int32),
-            ],
-        };
+        let schema = Schema::new(vec![
+            Field::unnamed(DataType::Int32),
+            Field::unnamed(DataType::Int32),
+            Field::unnamed(DataType::Int32),
+        ]);
         let mut child = MockExecutor::new(schema);
         child.add(DataChunk::from_pretty(
             "i i i
@@ -502,7 +498,7 @@ mod tests {
             aggs,
             group_key: group_exprs,
             child: Box::new(child),
-            schema: Schema { fields },
+            schema: Schema::new(fields),
             identity: "SortAggExecutor".to_owned(),
             output_size_limit: 3,
             shutdown_rx: ShutdownToken::empty(),
@@ -562,9 +558,7 @@ mod tests {
 
     #[tokio::test]
     async fn execute_sum_int32() -> Result<()> {
-        let schema = Schema {
-            fields: vec![Field::unnamed(DataType::Int32)],
-        };
+        let schema = Schema::new(vec![Field::unnamed(DataType::Int32)]);
         let mut child = MockExecutor::new(schema);
         child.add(DataChunk::from_pretty(
             " i
@@ -594,7 +588,7 @@ mod tests {
             aggs,
             group_key: vec![],
             child: Box::new(child),
-            schema: Schema { fields },
+            schema: Schema::new(fields),
             identity: "SortAggExecutor".to_owned(),
             output_size_limit: 4,
             shutdown_rx: ShutdownToken::empty(),
@@ -616,13 +610,11 @@ mod tests {
     #[tokio::test]
     async fn execute_sum_int32_grouped() -> Result<()> {
         // mock a child executor
-        let schema = Schema {
-            fields: vec![
-                Field::unnamed(DataType::Int32),
-                Field::unnamed(DataType::Int32),
-                Field::unnamed(DataType::Int32),
-            ],
-        };
+        let schema = Schema::new(vec![
+            Field::unnamed(DataType::Int32),
+            Field::unnamed(DataType::Int32),
+            Field::unnamed(DataType::Int32),
+        ]);
         let mut child = MockExecutor::new(schema);
         child.add(DataChunk::from_pretty(
             "i i i
@@ -666,7 +658,7 @@ mod tests {
             aggs,
             group_key: group_exprs,
             child: Box::new(child),
-            schema: Schema { fields },
+            schema: Schema::new(fields),
             identity: "SortAggExecutor".to_owned(),
             output_size_limit,
             shutdown_rx: ShutdownToken::empty(),
@@ -711,13 +703,11 @@ mod tests {
     #[tokio::test]
     async fn execute_sum_int32_grouped_exceed_limit() -> Result<()> {
         // mock a child executor
-        let schema = Schema {
-            fields: vec![
-                Field::unnamed(DataType::Int32),
-                Field::unnamed(DataType::Int32),
-                Field::unnamed(DataType::Int32),
-            ],
-        };
+        let schema = Schema::new(vec![
+            Field::unnamed(DataType::Int32),
+            Field::unnamed(DataType::Int32),
+            Field::unnamed(DataType::Int32),
+        ]);
         let mut child = MockExecutor::new(schema);
         child.add(DataChunk::from_pretty(
             " i  i  i
@@ -757,7 +747,7 @@ mod tests {
             aggs,
             group_key: group_exprs,
             child: Box::new(child),
-            schema: Schema { fields },
+            schema: Schema::new(fields),
             identity: "SortAggExecutor".to_owned(),
             output_size_limit: 3,
             shutdown_rx: ShutdownToken::empty(),
@@ -852,7 +842,7 @@ mod tests {
             aggs,
             group_key: group_exprs,
             child: Box::new(child),
-            schema: Schema { fields },
+            schema: Schema::new(fields),
             identity: "SortAggExecutor".to_owned(),
             output_size_limit,
             shutdown_rx,
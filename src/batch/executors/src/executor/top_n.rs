@@ -302,12 +302,10 @@ mod tests {
 
     #[tokio::test]
     async fn test_simple_top_n_executor() {
-        let schema = Schema {
-            fields: vec![
-                Field::unnamed(DataType::Int32),
-                Field::unnamed(DataType::Int32),
-            ],
-        };
+        let schema = Schema::new(vec![
+            Field::unnamed(DataType::Int32),
+            Field::unnamed(DataType::Int32),
+        ]);
         let mut mock_executor = MockExecutor::new(schema);
         mock_executor.add(DataChunk::from_pretty(
             "i i
@@ -360,12 +358,10 @@ mod tests {
 
     #[tokio::test]
     async fn test_limit_0() {
-        let schema = Schema {
-            fields: vec![
-                Field::unnamed(DataType::Int32),
-                Field::unnamed(DataType::Int32),
-            ],
-        };
+        let schema = Schema::new(vec![
+            Field::unnamed(DataType::Int32),
+            Field::unnamed(DataType::Int32),
+        ]);
         let mut mock_executor = MockExecutor::new(schema);
         mock_executor.add(DataChunk::from_pretty(
             "i i
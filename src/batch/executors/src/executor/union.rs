@@ -97,12 +97,10 @@ mod tests {
 
     #[tokio::test]
     async fn test_union_executor() {
-        let schema = Schema {
-            fields: vec![
-                Field::unnamed(DataType::Int32),
-                Field::unnamed(DataType::Int32),
-            ],
-        };
+        let schema = Schema::new(vec![
+            Field::unnamed(DataType::Int32),
+            Field::unnamed(DataType::Int32),
+        ]);
         let mut mock_executor1 = MockExecutor::new(schema.clone());
         mock_executor1.add(DataChunk::from_pretty(
             "i i
@@ -84,9 +84,7 @@ impl UpdateExecutor {
             schema: if returning {
                 table_schema
             } else {
-                Schema {
-                    fields: vec![Field::unnamed(DataType::Int64)],
-                }
+                Schema::new(vec![Field::unnamed(DataType::Int64)])
             },
             identity,
             returning,
@@ -126,7 +126,7 @@ impl BoxedExecutorBuilder for ValuesExecutor {
 
         Ok(Box::new(Self {
             rows: rows.into_iter(),
-            schema: Schema { fields },
+            schema: Schema::new(fields),
             identity: source.plan_node().get_identity().clone(),
             chunk_size: source.context().get_config().developer.chunk_size,
         }))
@@ -177,7 +177,7 @@ mod tests {
 
         let values_executor = Box::new(ValuesExecutor {
             rows: vec![exprs].into_iter(),
-            schema: Schema { fields },
+            schema: Schema::new(fields),
             identity: "ValuesExecutor2".to_owned(),
             chunk_size: CHUNK_SIZE,
         });
@@ -240,7 +240,7 @@ mod tests {
 
         let values_executor = Box::new(ValuesExecutor::new(
             rows,
-            Schema { fields },
+            Schema::new(fields),
             "ValuesExecutor2".to_owned(),
             3,
         ));
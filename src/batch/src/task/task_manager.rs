@@ -145,6 +145,8 @@ impl BatchManager {
             ExprContext {
                 time_zone: "UTC".to_owned(),
                 strict_mode: false,
+                jsonb_decimal_as_string: false,
+                jsonb_cast_case_insensitive: false,
             },
         )
         .await
@@ -0,0 +1,44 @@
+// Copyright 2025 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use criterion::{Criterion, criterion_group, criterion_main};
+use risingwave_common::catalog::{Field, Schema};
+use risingwave_common::types::DataType;
+
+const NUM_FIELDS: usize = 1000;
+
+fn bench_schema_build(c: &mut Criterion) {
+    c.bench_function("push_field without capacity", |b| {
+        b.iter(|| {
+            let mut schema = Schema::new(Vec::new());
+            for i in 0..NUM_FIELDS {
+                schema.push_field(Field::with_name(DataType::Int64, format!("col_{}", i)));
+            }
+            schema
+        })
+    });
+
+    c.bench_function("push_field with capacity", |b| {
+        b.iter(|| {
+            let mut schema = Schema::with_capacity(NUM_FIELDS);
+            for i in 0..NUM_FIELDS {
+                schema.push_field(Field::with_name(DataType::Int64, format!("col_{}", i)));
+            }
+            schema
+        })
+    });
+}
+
+criterion_group!(benches, bench_schema_build);
+criterion_main!(benches);
@@ -93,11 +93,18 @@ pub enum RateLimit {
     Disabled,
     /// Rate limit with fixed rate.
     Fixed(NonZeroU64),
+    /// Rate limit with a rate adjusted by an AIMD controller driven by barrier latency.
+    /// See [`AdaptiveRateLimiter`].
+    Adaptive,
     /// Pause with 0 rate.
     Pause,
 }
 
 impl RateLimit {
+    /// Sentinel carried in the legacy `Option<u32>` rate limit plumbing (catalogs, protos
+    /// and `ALTER ... SET backfill_rate_limit`) to request the adaptive policy.
+    pub const ADAPTIVE: u32 = u32::MAX;
+
     /// Return if the rate limit is set to pause policy.
     pub fn is_paused(&self) -> bool {
         matches! { self, Self::Pause }
@@ -113,6 +120,7 @@ impl From<RateLimit> for u64 {
         match rate_limit {
             RateLimit::Disabled => u64::MAX,
             RateLimit::Fixed(rate) => rate.get(),
+            RateLimit::Adaptive => RateLimit::ADAPTIVE as u64,
             RateLimit::Pause => 0,
         }
     }
@@ -124,6 +132,7 @@ impl From<Option<u32>> for RateLimit {
         match value {
             None => Self::Disabled,
             Some(0) => Self::Pause,
+            Some(Self::ADAPTIVE) => Self::Adaptive,
             Some(rate) => Self::Fixed(unsafe { NonZeroU64::new_unchecked(rate as _) }),
         }
     }
@@ -155,27 +164,60 @@ pub trait RateLimiterTrait: Send + Sync + 'static {
     /// On failure, [`Check::Retry`] or [`Check::RetryAfter`] is returned.
     /// The caller is supposed to retry the check after the given duration or retry after receiving the signal.
     fn check(&self, quota: u64) -> Check;
+
+    /// Feed the recently observed barrier latency to the rate limiter.
+    ///
+    /// A no-op for all policies except [`RateLimit::Adaptive`], whose rate is adjusted
+    /// by an AIMD controller driven by these observations.
+    fn report_latency(&self, _latency: Duration) {}
+
+    /// The effective rate in quotas per second, for introspection.
+    ///
+    /// Unlike [`RateLimiterTrait::rate_limit`], this reflects the current learned rate of
+    /// the adaptive policy rather than the policy itself.
+    fn effective_rate(&self) -> u64 {
+        self.rate_limit().to_u64()
+    }
 }
 
 /// A rate limiter that supports multiple rate limit policy and online policy switch.
 pub struct RateLimiter {
     inner: ArcSwap<Box<dyn RateLimiterTrait>>,
+    /// Configuration applied whenever the policy is (or becomes) [`RateLimit::Adaptive`],
+    /// so that policy switches keep the configured bounds.
+    adaptive_config: AdaptiveRateLimitConfig,
 }
 
 impl RateLimiter {
-    fn new_inner(rate_limit: RateLimit) -> Box<dyn RateLimiterTrait> {
+    fn new_inner(
+        rate_limit: RateLimit,
+        adaptive_config: AdaptiveRateLimitConfig,
+    ) -> Box<dyn RateLimiterTrait> {
         match rate_limit {
             RateLimit::Disabled => Box::new(InfiniteRatelimiter),
             RateLimit::Fixed(rate) => Box::new(FixedRateLimiter::new(rate)),
+            RateLimit::Adaptive => Box::new(AdaptiveRateLimiter::new(adaptive_config)),
             RateLimit::Pause => Box::new(PausedRateLimiter::default()),
         }
     }
 
     /// Create a new rate limiter with given rate limit policy.
     pub fn new(rate_limit: RateLimit) -> Self {
-        let inner: Box<dyn RateLimiterTrait> = Self::new_inner(rate_limit);
+        Self::with_adaptive_config(rate_limit, AdaptiveRateLimitConfig::default())
+    }
+
+    /// Create a new rate limiter with given rate limit policy and the config to use for
+    /// the adaptive policy.
+    pub fn with_adaptive_config(
+        rate_limit: RateLimit,
+        adaptive_config: AdaptiveRateLimitConfig,
+    ) -> Self {
+        let inner: Box<dyn RateLimiterTrait> = Self::new_inner(rate_limit, adaptive_config);
         let inner = ArcSwap::new(Arc::new(inner));
-        Self { inner }
+        Self {
+            inner,
+            adaptive_config,
+        }
     }
 
     /// Update rate limit policy of the rate limiter.
@@ -186,7 +228,7 @@ impl RateLimiter {
         if self.rate_limit() == rate_limit {
             return old;
         }
-        let inner = Self::new_inner(rate_limit);
+        let inner = Self::new_inner(rate_limit, self.adaptive_config);
         self.inner.store(Arc::new(inner));
         old
     }
@@ -210,6 +252,17 @@ impl RateLimiter {
         self.inner.load().check(quota)
     }
 
+    /// Feed the recently observed barrier latency to the current policy.
+    /// Only the adaptive policy reacts; see [`RateLimiterTrait::report_latency`].
+    pub fn report_latency(&self, latency: Duration) {
+        self.inner.load().report_latency(latency)
+    }
+
+    /// The current effective rate in quotas per second.
+    pub fn effective_rate(&self) -> u64 {
+        self.inner.load().effective_rate()
+    }
+
     pub async fn wait(&self, quota: u64) {
         loop {
             match self.check(quota) {
@@ -245,6 +298,14 @@ impl RateLimiterTrait for RateLimiter {
     fn check(&self, quota: u64) -> Check {
         self.check(quota)
     }
+
+    fn report_latency(&self, latency: Duration) {
+        self.report_latency(latency)
+    }
+
+    fn effective_rate(&self) -> u64 {
+        self.effective_rate()
+    }
 }
 
 /// A rate limiter that supports multiple rate limit policy, online policy switch and metrics support.
@@ -274,14 +335,30 @@ impl RateLimiterTrait for MonitoredRateLimiter {
         }
         check
     }
+
+    fn report_latency(&self, latency: Duration) {
+        // The inherent method, which also refreshes the metric.
+        self.report_latency(latency)
+    }
+
+    fn effective_rate(&self) -> u64 {
+        self.inner.effective_rate()
+    }
 }
 
 impl MonitoredRateLimiter {
+    /// Feed the recently observed barrier latency and refresh the metric, as the
+    /// adaptive policy may adjust its rate on each observation.
+    pub fn report_latency(&self, latency: Duration) {
+        self.inner.report_latency(latency);
+        self.report();
+    }
+
     /// Report the rate limit policy to the metric if updated.
     ///
     /// `report` is called automatically by each `until` call.
     fn report(&self) {
-        let rate_limit = self.inner.rate_limit().to_u64();
+        let rate_limit = self.inner.effective_rate();
         if rate_limit != self.rate_limit.load(Ordering::Relaxed) {
             self.rate_limit.store(rate_limit, Ordering::Relaxed);
             self.metric.set(rate_limit);
@@ -361,6 +438,125 @@ impl RateLimiterTrait for FixedRateLimiter {
     }
 }
 
+/// Configuration of [`AdaptiveRateLimiter`].
+#[derive(Debug, Clone, Copy)]
+pub struct AdaptiveRateLimitConfig {
+    /// Lower bound of the adaptive rate, so backfill is never starved entirely.
+    pub min: NonZeroU64,
+    /// Upper bound of the adaptive rate.
+    pub max: NonZeroU64,
+    /// Initial rate, clamped into `[min, max]`.
+    pub initial: NonZeroU64,
+    /// Barrier latency ceiling the controller converges towards.
+    pub target_latency: Duration,
+    /// Quotas added per observation when latency is under the target.
+    pub additive_step: NonZeroU64,
+}
+
+impl Default for AdaptiveRateLimitConfig {
+    /// Defaults used by `backfill_rate_limit = 'adaptive'`: start at 10k rows/s,
+    /// adapt within `[256, 1M]` towards a 1s barrier latency ceiling.
+    fn default() -> Self {
+        Self {
+            min: NonZeroU64::new(256).unwrap(),
+            max: NonZeroU64::new(1 << 20).unwrap(),
+            initial: NonZeroU64::new(10_000).unwrap(),
+            target_latency: Duration::from_secs(1),
+            additive_step: NonZeroU64::new(1_000).unwrap(),
+        }
+    }
+}
+
+impl AdaptiveRateLimitConfig {
+    /// The default config with the `[min, max]` bounds overridden where given.
+    ///
+    /// Zero bounds are ignored, and a single explicit bound that crosses the other
+    /// side's default widens the band instead of producing a crossed one. The initial
+    /// rate is clamped into the resulting bounds.
+    pub fn with_bounds(min: Option<u64>, max: Option<u64>) -> Self {
+        let mut config = Self::default();
+        if let Some(min) = min.and_then(NonZeroU64::new) {
+            config.min = min;
+        }
+        if let Some(max) = max.and_then(NonZeroU64::new) {
+            config.max = max;
+        }
+        config.max = config.max.max(config.min);
+        config.initial = config.initial.clamp(config.min, config.max);
+        config
+    }
+}
+
+/// A rate limiter whose rate is adjusted by an AIMD (additive-increase,
+/// multiplicative-decrease) controller targeting a barrier-latency ceiling.
+///
+/// The caller periodically feeds the recently observed barrier latency via
+/// [`AdaptiveRateLimiter::report_latency`]: when the latency exceeds the target the rate
+/// is halved, otherwise it is additively increased, clamped into the configured
+/// `[min, max]` bounds so the consumer is never starved entirely.
+#[derive(Debug)]
+pub struct AdaptiveRateLimiter {
+    inner: LeakBucket,
+    config: AdaptiveRateLimitConfig,
+    rate: AtomicU64,
+}
+
+impl AdaptiveRateLimiter {
+    pub fn new(config: AdaptiveRateLimitConfig) -> Self {
+        let rate = config.initial.clamp(config.min, config.max);
+        Self {
+            inner: LeakBucket::new(rate),
+            config,
+            rate: AtomicU64::new(rate.get()),
+        }
+    }
+
+    /// Feed the recently observed barrier latency and adjust the rate with AIMD.
+    ///
+    /// Returns the new effective rate.
+    pub fn report_latency(&self, latency: Duration) -> u64 {
+        let current = self.rate.load(Ordering::Relaxed);
+        let adjusted = if latency > self.config.target_latency {
+            current / 2
+        } else {
+            current.saturating_add(self.config.additive_step.get())
+        };
+        let new = adjusted.clamp(self.config.min.get(), self.config.max.get());
+        if new != current {
+            self.rate.store(new, Ordering::Relaxed);
+            // `new` is clamped to the non-zero `min`, so this cannot fail.
+            self.inner.update(NonZeroU64::new(new).unwrap());
+        }
+        new
+    }
+
+    /// The current effective rate in quotas per second.
+    pub fn current_rate(&self) -> u64 {
+        self.rate.load(Ordering::Relaxed)
+    }
+}
+
+impl RateLimiterTrait for AdaptiveRateLimiter {
+    fn rate_limit(&self) -> RateLimit {
+        RateLimit::Adaptive
+    }
+
+    fn check(&self, quota: u64) -> Check {
+        match self.inner.check(quota) {
+            Ok(()) => Check::Ok,
+            Err(duration) => Check::Retry(duration),
+        }
+    }
+
+    fn report_latency(&self, latency: Duration) {
+        self.report_latency(latency);
+    }
+
+    fn effective_rate(&self) -> u64 {
+        self.current_rate()
+    }
+}
+
 /// A GCRA-like leak bucket visual scheduler that never deny request even whose weight is larger than tau and only count TAT.
 #[derive(Debug)]
 pub struct LeakBucket {
@@ -468,9 +664,8 @@ impl LeakBucket {
         self.total_waited_nanos.store(0, Ordering::Relaxed);
     }
 
-    // TODO(MrCroxx): Reserved for adaptive rate limiter.
     /// Update rate limit with the given rate.
-    fn _update(&self, rate: NonZeroU64) {
+    fn update(&self, rate: NonZeroU64) {
         let scale = Self::scale(rate);
         self.scale.store(scale, Ordering::Relaxed);
     }
@@ -599,6 +794,57 @@ mod tests {
         println!("eratio {eratio} < ERATIO {ERATIO}");
     }
 
+    #[test]
+    fn test_adaptive_config_bounds() {
+        let config = AdaptiveRateLimitConfig::with_bounds(Some(1000), Some(2000));
+        assert_eq!(config.min.get(), 1000);
+        assert_eq!(config.max.get(), 2000);
+        // The default initial rate of 10k is clamped into the bounds.
+        assert_eq!(config.initial.get(), 2000);
+
+        // A single bound crossing the other side's default widens the band.
+        let config = AdaptiveRateLimitConfig::with_bounds(Some(2 << 20), None);
+        assert_eq!(config.min.get(), 2 << 20);
+        assert_eq!(config.max.get(), 2 << 20);
+
+        // Zero and absent bounds fall back to the defaults.
+        let config = AdaptiveRateLimitConfig::with_bounds(Some(0), None);
+        assert_eq!(config.min, AdaptiveRateLimitConfig::default().min);
+        assert_eq!(config.max, AdaptiveRateLimitConfig::default().max);
+    }
+
+    #[test]
+    fn test_adaptive_rate_limiter_aimd() {
+        let limiter = AdaptiveRateLimiter::new(AdaptiveRateLimitConfig {
+            min: NonZeroU64::new(100).unwrap(),
+            max: NonZeroU64::new(4000).unwrap(),
+            initial: NonZeroU64::new(1000).unwrap(),
+            target_latency: Duration::from_secs(1),
+            additive_step: NonZeroU64::new(200).unwrap(),
+        });
+        assert_eq!(limiter.current_rate(), 1000);
+
+        // A slow downstream keeps the barrier latency above the target: the rate is
+        // halved on each observation but converges to `min` instead of starving.
+        assert_eq!(limiter.report_latency(Duration::from_secs(5)), 500);
+        assert_eq!(limiter.report_latency(Duration::from_secs(5)), 250);
+        assert_eq!(limiter.report_latency(Duration::from_secs(5)), 125);
+        assert_eq!(limiter.report_latency(Duration::from_secs(5)), 100);
+        assert_eq!(limiter.report_latency(Duration::from_secs(5)), 100);
+        assert_eq!(limiter.rate_limit(), RateLimit::Adaptive);
+        assert_eq!(limiter.effective_rate(), 100);
+
+        // Once the downstream catches up, the rate recovers additively and is capped
+        // by `max`.
+        for _ in 0..30 {
+            limiter.report_latency(Duration::from_millis(100));
+        }
+        assert_eq!(limiter.current_rate(), 4000);
+
+        // The limiter still admits quotas at the adapted rate.
+        assert!(limiter.check(1).is_ok());
+    }
+
     #[tokio::test]
     async fn test_pause_and_resume() {
         let l = Arc::new(RateLimiter::new(RateLimit::Pause));
@@ -227,6 +227,13 @@ impl BytesArrayBuilder {
         BytesWriter { builder: self }
     }
 
+    /// Reserves capacity for at least `additional` more bytes in the data buffer.
+    ///
+    /// This is a hint only: appending more data than reserved is still allowed.
+    pub fn reserve_data(&mut self, additional: usize) {
+        self.data.reserve(additional);
+    }
+
     /// `append_partial` will add a partial dirty data of the new record.
     /// The partial data will keep untracked until `finish_partial` was called.
     unsafe fn append_partial(&mut self, x: &[u8]) {
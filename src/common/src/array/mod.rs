@@ -551,6 +551,17 @@ impl ArrayBuilderImpl {
     pub fn is_empty(&self) -> bool {
         self.len() == 0
     }
+
+    /// Reserves capacity for at least `additional` more bytes of variable-length data.
+    ///
+    /// This is a no-op for builders of fixed-length types.
+    pub fn reserve_data(&mut self, additional: usize) {
+        match self {
+            Self::Utf8(inner) => inner.reserve_data(additional),
+            Self::Bytea(inner) => inner.reserve_data(additional),
+            _ => {}
+        }
+    }
 }
 
 impl ArrayImpl {
@@ -174,6 +174,13 @@ impl Utf8ArrayBuilder {
         }
     }
 
+    /// Reserves capacity for at least `additional` more bytes in the data buffer.
+    ///
+    /// This is a hint only: appending more data than reserved is still allowed.
+    pub fn reserve_data(&mut self, additional: usize) {
+        self.bytes.reserve_data(additional);
+    }
+
     /// Append an element as the `Display` format to the array.
     pub fn append_display(&mut self, value: Option<impl Display>) {
         if let Some(s) = value {
@@ -129,6 +129,9 @@ pub struct ColumnDesc {
     /// If a column is not nullable, BatchInsert/BatchUpdate operations will throw an error when NULL is inserted/updated into.
     /// The row contains NULL for this column will be ignored when streaming data into the table.
     pub nullable: bool,
+    /// Informational foreign-key reference like `users(id)`, recorded from `REFERENCES` column
+    /// options. Not enforced; surfaced in `pg_constraint`.
+    pub foreign_key: Option<String>,
 }
 
 impl AsRef<ColumnDesc> for ColumnDesc {
@@ -153,6 +156,7 @@ impl ColumnDesc {
             version: ColumnDescVersion::LATEST,
             system_column: None,
             nullable: true,
+            foreign_key: None,
         }
     }
 
@@ -213,6 +217,7 @@ impl ColumnDesc {
             additional_column: Some(self.additional_column.clone()),
             version: self.version as i32,
             nullable: Some(self.nullable),
+            foreign_key: self.foreign_key.clone(),
         }
     }
 
@@ -272,6 +277,7 @@ impl From<PbColumnDesc> for ColumnDesc {
             version,
             system_column: None,
             nullable: prost.nullable.unwrap_or(true),
+            foreign_key: prost.foreign_key,
         }
     }
 }
@@ -354,6 +360,17 @@ impl ColumnCatalog {
         }
     }
 
+    /// If the column has an explicit default value
+    pub fn default_expr(&self) -> Option<&ExprNode> {
+        if let Some(GeneratedOrDefaultColumn::DefaultColumn(desc)) =
+            &self.column_desc.generated_or_default_column
+        {
+            desc.expr.as_ref()
+        } else {
+            None
+        }
+    }
+
     /// If the columns is an `INCLUDE ... AS ...` connector column.
     pub fn is_connector_additional_column(&self) -> bool {
         self.column_desc.additional_column.column_type.is_some()
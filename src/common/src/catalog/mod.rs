@@ -17,6 +17,11 @@ mod external_table;
 mod internal_table;
 mod physical_table;
 mod schema;
+#[cfg(feature = "flatbuffers")]
+mod schema_flatbuffers;
+#[cfg(feature = "iceberg")]
+mod schema_iceberg;
+mod sql_dialect;
 pub mod test_utils;
 
 use std::fmt::Binary;
@@ -34,7 +39,14 @@ use risingwave_pb::catalog::{
     StreamJobStatus as PbStreamJobStatus,
 };
 use risingwave_pb::plan_common::ColumnDescVersion;
-pub use schema::{Field, FieldDisplay, FieldLike, Schema, test_utils as schema_test_utils};
+pub use schema::{
+    ColumnSourceRef, CompatibilityMode, DescribeRow, Field, FieldDisplay, FieldLike, ForeignKeyRef,
+    ProstOptions, Schema, SchemaBuilder, SchemaError, TextFormatDescriptor, TypeMismatchPolicy,
+    test_utils as schema_test_utils,
+};
+#[cfg(feature = "flatbuffers")]
+pub use schema_flatbuffers::SCHEMA_DESCRIPTOR_VERSION;
+pub use sql_dialect::{MySqlDialect, PostgresDialect, SqlDialect, sql_type_name};
 
 use crate::array::DataChunk;
 pub use crate::constants::hummock;
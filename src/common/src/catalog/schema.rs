@@ -12,16 +12,202 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::{HashMap, HashSet};
 use std::ops::Index;
 
 use educe::Educe;
 use risingwave_pb::plan_common::{PbColumnDesc, PbField};
 
 use super::ColumnDesc;
+use super::sql_dialect::{SqlDialect, sql_string_literal};
 use crate::array::ArrayBuilderImpl;
-use crate::types::{DataType, StructType};
+use crate::types::{DataType, DataTypeName, StructType};
 use crate::util::iter_util::ZipEqFast;
 
+/// Error type returned by fallible [`Schema`] and [`Field`] helpers.
+#[derive(thiserror::Error, Debug)]
+pub enum SchemaError {
+    #[error("type mismatch for column \"{name}\": {left} vs {right}")]
+    TypeMismatch {
+        name: String,
+        left: DataType,
+        right: DataType,
+    },
+    #[error("column \"{name}\" not found")]
+    ColumnNotFound { name: String },
+    #[error("column \"{name}\" is not a struct: {actual}")]
+    NotAStruct { name: String, actual: DataType },
+    #[error("invalid foreign key \"{definition}\": {reason}")]
+    InvalidForeignKey { definition: String, reason: String },
+    #[error("dangling foreign key reference(s): {}", refs.join(", "))]
+    DanglingForeignKey { refs: Vec<String> },
+    #[error("alias count {actual} does not match column count {expected}")]
+    AliasCountMismatch { expected: usize, actual: usize },
+    #[error("too many columns: {actual} exceeds the limit of {limit}")]
+    TooManyColumns { actual: usize, limit: usize },
+    #[error("expected exactly one column, got {actual}")]
+    NotSingleColumn { actual: usize },
+    #[error("invalid Kafka Connect schema: {reason}")]
+    InvalidConnectSchema { reason: String },
+    #[error("column \"{name}\" already exists")]
+    DuplicateColumn { name: String },
+    #[error("column name(s) reserved by the engine: {}", names.join(", "))]
+    ReservedColumn { names: Vec<String> },
+    #[error("invalid regex \"{pattern}\": {reason}")]
+    InvalidRegex { pattern: String, reason: String },
+    #[error("field count mismatch: expected {expected}, got {actual}")]
+    FieldCountMismatch { expected: usize, actual: usize },
+    #[cfg(feature = "flatbuffers")]
+    #[error("invalid FlatBuffers schema descriptor: {reason}")]
+    InvalidFlatBuffersDescriptor { reason: String },
+    #[cfg(feature = "iceberg")]
+    #[error("invalid Iceberg schema conversion: {reason}")]
+    InvalidIcebergSchema { reason: String },
+}
+
+/// Policy applied when two schemas disagree on the type of a same-named column.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TypeMismatchPolicy {
+    /// Return a [`SchemaError::TypeMismatch`] for the first mismatching column.
+    Error,
+    /// Silently drop the mismatching column from the result.
+    Skip,
+}
+
+/// How strictly [`Schema::assert_compatible_with`] compares field types.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CompatibilityMode {
+    /// Field types must be exactly equal.
+    #[default]
+    Exact,
+    /// Safe widenings are also accepted: `Int16 -> Int32 -> Int64`, `Float32 -> Float64`,
+    /// and integers into `Decimal`. Narrowing in the other direction is rejected.
+    Widening,
+    /// Any type change is accepted; only the shape (field count and names) is checked.
+    /// The caller is responsible for inserting the actual casts.
+    AnyCast,
+}
+
+/// Returns whether reinterpreting a value of type `from` as type `to` is a lossless
+/// widening, see [`CompatibilityMode::Widening`].
+///
+/// RisingWave decimals carry no precision/scale parameters, so every integer type widens
+/// into `Decimal`. Nested types are not recursed into and must match exactly.
+fn is_widening(from: &DataType, to: &DataType) -> bool {
+    use DataType::*;
+    matches!(
+        (from, to),
+        (Int16, Int32 | Int64 | Decimal)
+            | (Int32, Int64 | Decimal)
+            | (Int64, Decimal)
+            | (Float32, Float64)
+    )
+}
+
+/// Returns the smallest common supertype of `a` and `b`, if any.
+///
+/// Equal types are their own supertype, and a type widening into the other per
+/// [`is_widening`] yields the wider one. Mixing integers with floats has no lossless
+/// supertype, so it follows the usual SQL numeric hierarchy and widens to `Float64`.
+/// Everything else, including nested types, has no common supertype.
+fn common_supertype(a: &DataType, b: &DataType) -> Option<DataType> {
+    use DataType::*;
+    if a == b {
+        return Some(a.clone());
+    }
+    if is_widening(a, b) {
+        return Some(b.clone());
+    }
+    if is_widening(b, a) {
+        return Some(a.clone());
+    }
+    match (a, b) {
+        (Int16 | Int32 | Int64, Float32 | Float64) | (Float32 | Float64, Int16 | Int32 | Int64) => {
+            Some(Float64)
+        }
+        _ => None,
+    }
+}
+
+/// Options controlling which informational metadata is included when serializing a
+/// [`Schema`] or [`Field`] to protobuf, see [`Schema::to_prost_with`].
+///
+/// Defaults to excluding all metadata, matching [`Schema::to_prost`]: most consumers only
+/// need the runtime shape, and metadata may be sensitive or waste bandwidth.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ProstOptions {
+    /// Include per-column descriptions.
+    pub include_descriptions: bool,
+    /// Include foreign-key annotations.
+    pub include_foreign_keys: bool,
+    /// Include governance tags and the masking policy.
+    pub include_tags: bool,
+}
+
+/// One row of structured `DESCRIBE` output, see [`Schema::describe_rows`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DescribeRow {
+    /// The column name.
+    pub name: String,
+    /// The rendered type name, as RisingWave displays it.
+    pub type_name: String,
+    /// Whether the column is nullable.
+    pub nullable: bool,
+    /// Whether the column is part of the primary key.
+    pub is_pk: bool,
+    /// The raw foreign-key annotation, if any.
+    pub fk: Option<String>,
+    /// The column description, if any.
+    pub comment: Option<String>,
+}
+
+/// A parsed foreign-key reference of the form `table(col[, col...])`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ForeignKeyRef {
+    pub table: String,
+    pub columns: Vec<String>,
+}
+
+impl ForeignKeyRef {
+    /// Parses a `table(col[, col...])` foreign-key definition.
+    pub fn parse(definition: &str) -> Result<Self, SchemaError> {
+        let invalid = |reason: &str| SchemaError::InvalidForeignKey {
+            definition: definition.to_owned(),
+            reason: reason.to_owned(),
+        };
+        let trimmed = definition.trim();
+        let Some((table, rest)) = trimmed.split_once('(') else {
+            return Err(invalid("expected `table(col[, col...])`"));
+        };
+        let table = table.trim();
+        if table.is_empty() {
+            return Err(invalid("empty table name"));
+        }
+        let Some(columns) = rest.strip_suffix(')') else {
+            return Err(invalid("unbalanced parentheses"));
+        };
+        if columns.contains('(') || columns.contains(')') {
+            return Err(invalid("unbalanced parentheses"));
+        }
+        let columns: Vec<String> = columns.split(',').map(|c| c.trim().to_owned()).collect();
+        if columns.iter().any(|c| c.is_empty()) {
+            return Err(invalid("empty column name"));
+        }
+        Ok(Self {
+            table: table.to_owned(),
+            columns,
+        })
+    }
+}
+
+/// The source column a derived field originates from, as `relation.column`, used for
+/// column-level lineage. See [`Field::source_ref`] and [`Schema::lineage`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ColumnSourceRef {
+    pub relation: String,
+    pub column: String,
+}
+
 #[derive(Clone, Educe)]
 #[educe(PartialEq, Eq, Hash)]
 pub struct Field {
@@ -29,6 +215,63 @@ pub struct Field {
     #[educe(PartialEq(ignore))]
     #[educe(Hash(ignore))]
     pub name: String,
+    /// Free-form foreign-key annotation of the form `table(col[, col...])`, referencing
+    /// the column(s) of another relation that this field points to.
+    ///
+    /// Informational only: ignored for equality and hashing, and not serialized by
+    /// [`Field::to_prost`]. See [`Schema::validate_foreign_keys`] for validation.
+    #[educe(PartialEq(ignore))]
+    #[educe(Hash(ignore))]
+    pub foreign_key: Option<String>,
+    /// Human-readable per-column documentation.
+    ///
+    /// Informational only: ignored for equality and hashing, and only serialized on
+    /// demand via [`Field::to_prost_with`].
+    #[educe(PartialEq(ignore))]
+    #[educe(Hash(ignore))]
+    pub description: Option<String>,
+    /// Whether the field is part of the primary key of the relation.
+    ///
+    /// Informational only: ignored for equality and hashing, and not serialized by
+    /// [`Field::to_prost`]. Key column order is captured by [`Schema::primary_key`],
+    /// which may sync this flag on construction.
+    #[educe(PartialEq(ignore))]
+    #[educe(Hash(ignore))]
+    pub is_primary_key: bool,
+    /// Whether the field may contain `NULL`s. Defaults to `true`.
+    ///
+    /// Informational only: ignored for equality and hashing, and not serialized by
+    /// [`Field::to_prost`]. Nullability is enforced by [`ColumnDesc::nullable`], from
+    /// which this flag is populated when converting.
+    #[educe(PartialEq(ignore))]
+    #[educe(Hash(ignore))]
+    pub nullable: bool,
+    /// Governance tags on the column, e.g. `pii` or `sensitive`. A column with at least
+    /// one tag or a masking policy is considered sensitive, see
+    /// [`Schema::sensitive_columns`].
+    ///
+    /// Informational only: ignored for equality and hashing, and only serialized on
+    /// demand via [`Field::to_prost_with`].
+    #[educe(PartialEq(ignore))]
+    #[educe(Hash(ignore))]
+    pub tags: Vec<String>,
+    /// Name of the masking policy to apply when redacting the column on output, e.g. by
+    /// a sink writing to an external system.
+    ///
+    /// Informational only: ignored for equality and hashing, and only serialized on
+    /// demand via [`Field::to_prost_with`].
+    #[educe(PartialEq(ignore))]
+    #[educe(Hash(ignore))]
+    pub masking_policy: Option<String>,
+    /// The source column this field is derived from, for column-level lineage. Renames
+    /// and projections keep the reference so a derived schema can still report where
+    /// each column came from; see [`Schema::lineage`].
+    ///
+    /// Informational only: ignored for equality and hashing, and not serialized by
+    /// [`Field::to_prost`].
+    #[educe(PartialEq(ignore))]
+    #[educe(Hash(ignore))]
+    pub source_ref: Option<ColumnSourceRef>,
 }
 
 impl Field {
@@ -36,7 +279,115 @@ impl Field {
         Self {
             data_type,
             name: name.into(),
+            foreign_key: None,
+            description: None,
+            is_primary_key: false,
+            nullable: true,
+            tags: vec![],
+            masking_policy: None,
+            source_ref: None,
+        }
+    }
+
+    /// Sets the foreign-key annotation of the field.
+    pub fn with_foreign_key(mut self, foreign_key: impl Into<String>) -> Self {
+        self.foreign_key = Some(foreign_key.into());
+        self
+    }
+
+    /// Sets the human-readable description of the field.
+    pub fn with_description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Sets whether the field is part of the primary key.
+    pub fn with_is_primary_key(mut self, is_primary_key: bool) -> Self {
+        self.is_primary_key = is_primary_key;
+        self
+    }
+
+    /// Sets whether the field may contain `NULL`s.
+    pub fn with_nullable(mut self, nullable: bool) -> Self {
+        self.nullable = nullable;
+        self
+    }
+
+    /// Sets the governance tags of the field.
+    pub fn with_tags<S>(mut self, tags: impl IntoIterator<Item = S>) -> Self
+    where
+        S: Into<String>,
+    {
+        self.tags = tags.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Sets the masking policy of the field.
+    pub fn with_masking_policy(mut self, masking_policy: impl Into<String>) -> Self {
+        self.masking_policy = Some(masking_policy.into());
+        self
+    }
+
+    /// Sets the source column the field is derived from, see [`Field::source_ref`].
+    pub fn with_source_ref(
+        mut self,
+        relation: impl Into<String>,
+        column: impl Into<String>,
+    ) -> Self {
+        self.source_ref = Some(ColumnSourceRef {
+            relation: relation.into(),
+            column: column.into(),
+        });
+        self
+    }
+
+    /// Whether the field carries at least one governance tag or a masking policy.
+    pub fn is_sensitive(&self) -> bool {
+        !self.tags.is_empty() || self.masking_policy.is_some()
+    }
+
+    /// Returns the byte width of the field when written to a fixed-width target (e.g.
+    /// COBOL-style records) in the given dialect, or `None` for types without a
+    /// well-defined width, such as unbounded text. See
+    /// [`SqlDialect::scalar_byte_width`].
+    pub fn target_byte_width(&self, dialect: &dyn SqlDialect) -> Option<usize> {
+        dialect.scalar_byte_width(&self.data_type)
+    }
+
+    /// Returns the field name coerced to the identifier rules of the given dialect:
+    /// truncated to [`SqlDialect::max_identifier_length`] characters and folded to upper
+    /// case if [`SqlDialect::uppercase_identifiers`] says so.
+    pub fn coerced_name(&self, dialect: &dyn SqlDialect) -> String {
+        let mut name: String = match dialect.max_identifier_length() {
+            Some(limit) => self.name.chars().take(limit).collect(),
+            None => self.name.clone(),
+        };
+        if dialect.uppercase_identifiers() {
+            name = name.to_uppercase();
         }
+        name
+    }
+
+    /// Returns whether `self` and `other` have the same logical type, i.e. the same kind
+    /// of data type ignoring type parameters. Unlike comparing `data_type` for equality,
+    /// this considers e.g. two lists with different element types to be the same. Useful
+    /// for sinks that only care about the kind of a column.
+    ///
+    /// See [`DataType::same_logical_kind`].
+    pub fn same_logical_type(&self, other: &Field) -> bool {
+        self.data_type.same_logical_kind(&other.data_type)
+    }
+
+    /// Parses the foreign-key annotation of the field, if any.
+    ///
+    /// Returns `Ok(None)` when the field has no foreign key, and an
+    /// [`SchemaError::InvalidForeignKey`] if the annotation is malformed. See
+    /// [`ForeignKeyRef::parse`] for the accepted syntax.
+    pub fn parse_foreign_key(&self) -> Result<Option<ForeignKeyRef>, SchemaError> {
+        self.foreign_key
+            .as_deref()
+            .map(ForeignKeyRef::parse)
+            .transpose()
     }
 }
 
@@ -48,9 +399,32 @@ impl std::fmt::Debug for Field {
 
 impl Field {
     pub fn to_prost(&self) -> PbField {
+        self.to_prost_with(ProstOptions::default())
+    }
+
+    /// Like [`Field::to_prost`], but with control over which informational metadata is
+    /// included. Excluded metadata is emitted as `None`.
+    pub fn to_prost_with(&self, opts: ProstOptions) -> PbField {
         PbField {
             data_type: Some(self.data_type.to_protobuf()),
             name: self.name.clone(),
+            description: opts
+                .include_descriptions
+                .then(|| self.description.clone())
+                .flatten(),
+            foreign_key: opts
+                .include_foreign_keys
+                .then(|| self.foreign_key.clone())
+                .flatten(),
+            tags: if opts.include_tags {
+                self.tags.clone()
+            } else {
+                vec![]
+            },
+            masking_policy: opts
+                .include_tags
+                .then(|| self.masking_policy.clone())
+                .flatten(),
         }
     }
 
@@ -58,6 +432,13 @@ impl Field {
         Field {
             data_type: DataType::from(pb.data_type.as_ref().unwrap()),
             name: pb.name.clone(),
+            foreign_key: pb.foreign_key.clone(),
+            description: pb.description.clone(),
+            is_primary_key: false,
+            nullable: true,
+            tags: pb.tags.clone(),
+            masking_policy: pb.masking_policy.clone(),
+            source_ref: None,
         }
     }
 }
@@ -67,6 +448,13 @@ impl From<&ColumnDesc> for Field {
         Self {
             data_type: desc.data_type.clone(),
             name: desc.name.clone(),
+            foreign_key: desc.foreign_key.clone(),
+            description: desc.description.clone(),
+            is_primary_key: false,
+            nullable: desc.nullable,
+            tags: vec![],
+            masking_policy: None,
+            source_ref: None,
         }
     }
 }
@@ -76,6 +464,13 @@ impl From<ColumnDesc> for Field {
         Self {
             data_type: column_desc.data_type,
             name: column_desc.name,
+            foreign_key: column_desc.foreign_key,
+            description: column_desc.description,
+            is_primary_key: false,
+            nullable: column_desc.nullable,
+            tags: vec![],
+            masking_policy: None,
+            source_ref: None,
         }
     }
 }
@@ -85,6 +480,13 @@ impl From<&PbColumnDesc> for Field {
         Self {
             data_type: pb_column_desc.column_type.as_ref().unwrap().into(),
             name: pb_column_desc.name.clone(),
+            foreign_key: pb_column_desc.foreign_key.clone(),
+            description: None,
+            is_primary_key: false,
+            nullable: pb_column_desc.nullable.unwrap_or(true),
+            tags: vec![],
+            masking_policy: None,
+            source_ref: None,
         }
     }
 }
@@ -124,23 +526,60 @@ impl std::fmt::Display for FieldDisplay<'_> {
 #[macro_export]
 macro_rules! schema_unnamed {
     ($($t:expr),*) => {{
-        $crate::catalog::Schema {
-            fields: vec![
-                $( $crate::catalog::Field::unnamed($t) ),*
-            ],
-        }
+        $crate::catalog::Schema::new(vec![
+            $( $crate::catalog::Field::unnamed($t) ),*
+        ])
     }};
 }
 
 /// the schema of the executor's return data
-#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[derive(Clone, Debug, Default, Educe)]
+#[educe(PartialEq, Eq, Hash)]
 pub struct Schema {
     pub fields: Vec<Field>,
+    /// Human-readable documentation of the relation, e.g. set via `COMMENT ON`.
+    ///
+    /// Not part of the runtime shape: ignored for equality and hashing, and not carried by
+    /// [`Schema::to_prost`], which serializes the fields only.
+    #[educe(PartialEq(ignore))]
+    #[educe(Hash(ignore))]
+    pub description: Option<String>,
+    /// Machine-readable comment blob (e.g. JSON metadata) for sinks that distinguish it
+    /// from the human-readable [`description`](Schema::description).
+    ///
+    /// Like `description`, this is informational only and ignored for equality and hashing.
+    #[educe(PartialEq(ignore))]
+    #[educe(Hash(ignore))]
+    pub metadata_comment: Option<String>,
+    /// Names of the columns that form the ordering/watermark key of the output.
+    ///
+    /// Informational for downstream operators and sinks: ignored for equality and hashing,
+    /// and not carried by [`Schema::to_prost`]. Set via [`Schema::with_watermark_columns`],
+    /// which validates the names against the fields.
+    #[educe(PartialEq(ignore))]
+    #[educe(Hash(ignore))]
+    pub watermark_columns: Vec<String>,
+    /// Ordered primary-key column names, for catalog representations that carry the key
+    /// as a separate list rather than per-field flags, which also captures key column
+    /// order.
+    ///
+    /// Informational only: ignored for equality and hashing, and not carried by
+    /// [`Schema::to_prost`]. Set via [`Schema::with_primary_key`], which validates the
+    /// names against the fields.
+    #[educe(PartialEq(ignore))]
+    #[educe(Hash(ignore))]
+    pub primary_key: Option<Vec<String>>,
 }
 
 impl Schema {
     pub fn empty() -> &'static Self {
-        static EMPTY: Schema = Schema { fields: Vec::new() };
+        static EMPTY: Schema = Schema {
+            fields: Vec::new(),
+            description: None,
+            metadata_comment: None,
+            watermark_columns: Vec::new(),
+            primary_key: None,
+        };
         &EMPTY
     }
 
@@ -152,179 +591,1639 @@ impl Schema {
         self.fields.is_empty()
     }
 
+    /// Returns the only field of the schema, for operators that must produce exactly one
+    /// column (e.g. scalar subqueries). Errors when the schema has zero or more than one
+    /// column.
+    pub fn single_field(&self) -> Result<&Field, SchemaError> {
+        match self.fields.as_slice() {
+            [field] => Ok(field),
+            _ => Err(SchemaError::NotSingleColumn {
+                actual: self.fields.len(),
+            }),
+        }
+    }
+
+    /// Returns the data type of the only column of the schema, like [`Self::single_field`]
+    /// but without the field metadata. Useful for sinks that accept a single value column
+    /// of a composite type (e.g. a map, array or struct).
+    pub fn single_column_data_type(&self) -> Result<&DataType, SchemaError> {
+        self.single_field().map(|field| &field.data_type)
+    }
+
     pub fn new(fields: Vec<Field>) -> Self {
-        Self { fields }
+        Self {
+            fields,
+            description: None,
+            metadata_comment: None,
+            watermark_columns: Vec::new(),
+            primary_key: None,
+        }
     }
 
-    pub fn names(&self) -> Vec<String> {
-        self.fields().iter().map(|f| f.name.clone()).collect()
+    /// Creates an empty schema with space reserved for `capacity` fields.
+    ///
+    /// Useful together with [`Schema::push_field`] when building a schema
+    /// incrementally, e.g. when converting a wide external schema, to avoid
+    /// repeated reallocation of the field vector.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self::new(Vec::with_capacity(capacity))
     }
 
-    pub fn names_str(&self) -> Vec<&str> {
-        self.fields().iter().map(|f| f.name.as_str()).collect()
+    /// Appends a field to the schema.
+    pub fn push_field(&mut self, field: Field) {
+        self.fields.push(field);
     }
 
-    pub fn data_types(&self) -> Vec<DataType> {
-        self.fields
-            .iter()
-            .map(|field| field.data_type.clone())
-            .collect()
+    /// Returns a copy that keeps only field names and data types, dropping all schema-
+    /// and field-level metadata: descriptions, foreign keys, primary-key and watermark
+    /// information, and nullability.
+    ///
+    /// This is the canonical "runtime shape" for internal intermediate results, where
+    /// carrying metadata would only waste memory. Since all metadata is ignored for
+    /// equality and hashing, the stripped schema still compares equal to the original.
+    pub fn stripped(&self) -> Schema {
+        Schema::new(
+            self.fields
+                .iter()
+                .map(|field| Field::with_name(field.data_type.clone(), field.name.clone()))
+                .collect(),
+        )
     }
 
-    pub fn fields(&self) -> &[Field] {
-        &self.fields
+    /// Sets the human-readable description of the schema.
+    pub fn with_description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
     }
 
-    pub fn into_fields(self) -> Vec<Field> {
-        self.fields
+    /// Sets the machine-readable comment blob of the schema.
+    pub fn with_metadata_comment(mut self, comment: impl Into<String>) -> Self {
+        self.metadata_comment = Some(comment.into());
+        self
     }
 
-    /// Create array builders for all fields in this schema.
-    pub fn create_array_builders(&self, capacity: usize) -> Vec<ArrayBuilderImpl> {
-        self.fields
-            .iter()
-            .map(|field| field.data_type.create_array_builder(capacity))
-            .collect()
+    pub fn description(&self) -> Option<&str> {
+        self.description.as_deref()
     }
 
-    pub fn to_prost(&self) -> Vec<PbField> {
-        self.fields
-            .clone()
-            .into_iter()
-            .map(|field| field.to_prost())
-            .collect()
+    /// Copies field descriptions from `other` by field name, for enriching a bare schema
+    /// with comments from a documented one.
+    ///
+    /// A description is only copied when the target field's description is `None`; existing
+    /// descriptions are kept. The schema-level description is merged the same way. Names in
+    /// `other` that don't match any field here are ignored.
+    pub fn merge_descriptions_from(&mut self, other: &Schema) {
+        if self.description.is_none() {
+            self.description = other.description.clone();
+        }
+        for field in &mut self.fields {
+            if field.description.is_none()
+                && let Some(source) = other.fields.iter().find(|f| f.name == field.name)
+            {
+                field.description = source.description.clone();
+            }
+        }
     }
 
-    pub fn type_eq(&self, other: &Self) -> bool {
-        if self.len() != other.len() {
-            return false;
+    /// Appends `other`'s fields to a copy of this schema, suffixing any name of `other`
+    /// that collides with an existing one, in the style of pandas-like join suffixes.
+    /// Useful for building the output schema of a self-join, where both sides share
+    /// column names.
+    ///
+    /// The suffix is appended repeatedly until the name is unique, so the result is
+    /// guaranteed to have unique names even if e.g. both `id` and `id_right` exist.
+    /// If the suffix is empty (so appending it can never resolve a collision), a
+    /// numbered suffix (`_1`, `_2`, ...) is used instead. Non-colliding names are
+    /// kept as-is.
+    pub fn merge_dedup(&self, other: &Schema, right_suffix: &str) -> Schema {
+        let mut fields = self.fields.clone();
+        let mut names: HashSet<String> = fields.iter().map(|f| f.name.clone()).collect();
+        for field in &other.fields {
+            let mut name = field.name.clone();
+            if right_suffix.is_empty() {
+                let base = name.clone();
+                let mut i = 1;
+                while names.contains(&name) {
+                    name = format!("{base}_{i}");
+                    i += 1;
+                }
+            } else {
+                while names.contains(&name) {
+                    name.push_str(right_suffix);
+                }
+            }
+            names.insert(name.clone());
+            let mut field = field.clone();
+            field.name = name;
+            fields.push(field);
+        }
+        Schema::new(fields)
+    }
+
+    pub fn metadata_comment(&self) -> Option<&str> {
+        self.metadata_comment.as_deref()
+    }
+
+    /// Sets the ordering/watermark key columns of the schema, validating that each name
+    /// refers to an existing field.
+    ///
+    /// Errors with [`SchemaError::ColumnNotFound`] for the first unknown name.
+    pub fn with_watermark_columns(
+        mut self,
+        watermark_columns: Vec<String>,
+    ) -> Result<Self, SchemaError> {
+        for name in &watermark_columns {
+            if !self.fields.iter().any(|f| &f.name == name) {
+                return Err(SchemaError::ColumnNotFound { name: name.clone() });
+            }
         }
+        self.watermark_columns = watermark_columns;
+        Ok(self)
+    }
 
-        for (a, b) in self.fields.iter().zip_eq_fast(other.fields.iter()) {
-            if !a.data_type.equals_datatype(&b.data_type) {
-                return false;
+    /// Sets the ordered primary-key columns of the schema, validating that each name
+    /// refers to an existing field. With `sync_field_flags`, each field's
+    /// [`is_primary_key`](Field::is_primary_key) flag is also updated to match the list.
+    ///
+    /// Errors with [`SchemaError::ColumnNotFound`] for the first unknown name.
+    pub fn with_primary_key(
+        mut self,
+        primary_key: Vec<String>,
+        sync_field_flags: bool,
+    ) -> Result<Self, SchemaError> {
+        for name in &primary_key {
+            if !self.fields.iter().any(|f| &f.name == name) {
+                return Err(SchemaError::ColumnNotFound { name: name.clone() });
+            }
+        }
+        if sync_field_flags {
+            for field in &mut self.fields {
+                field.is_primary_key = primary_key.contains(&field.name);
             }
         }
+        self.primary_key = Some(primary_key);
+        Ok(self)
+    }
 
-        true
+    /// Returns the indices of the primary-key columns in key order.
+    ///
+    /// Prefers the explicit [`primary_key`](Schema::primary_key) list, which captures key
+    /// column order, and falls back to the per-field
+    /// [`is_primary_key`](Field::is_primary_key) flags in field order.
+    pub fn primary_key_in_order(&self) -> Vec<usize> {
+        match &self.primary_key {
+            Some(primary_key) => primary_key
+                .iter()
+                .map(|name| {
+                    self.fields
+                        .iter()
+                        .position(|f| &f.name == name)
+                        .expect("primary key columns are validated on construction")
+                })
+                .collect(),
+            None => self
+                .fields
+                .iter()
+                .enumerate()
+                .filter_map(|(i, f)| f.is_primary_key.then_some(i))
+                .collect(),
+        }
     }
 
-    pub fn all_type_eq<'a>(inputs: impl IntoIterator<Item = &'a Self>) -> bool {
-        let mut iter = inputs.into_iter();
-        if let Some(first) = iter.next() {
-            iter.all(|x| x.type_eq(first))
-        } else {
-            true
+    /// Forces primary-key fields to be non-nullable, mutating the schema in place.
+    ///
+    /// The primary-key and nullability flags are often populated independently (e.g. from
+    /// different connector metadata) and may disagree, while key columns can never contain
+    /// `NULL`s. Call this to normalize the [`nullable`](Field::nullable) flag before
+    /// persisting the schema. Both the explicit [`primary_key`](Schema::primary_key) list
+    /// and the per-field [`is_primary_key`](Field::is_primary_key) flags are honored.
+    pub fn enforce_key_not_null(&mut self) {
+        let primary_key = self.primary_key.as_deref();
+        for field in &mut self.fields {
+            if field.is_primary_key || primary_key.is_some_and(|pk| pk.contains(&field.name)) {
+                field.nullable = false;
+            }
         }
     }
 
-    pub fn formatted_col_names(&self) -> String {
+    /// Returns a copy of the schema where every field's data type is [`DataType::Varchar`],
+    /// for text-based sinks (e.g. CSV) that serialize every column as text.
+    ///
+    /// Field names, annotations and schema-level metadata are preserved. Use
+    /// [`Schema::text_format_descriptors`] to learn how each original type should be
+    /// stringified.
+    pub fn all_text(&self) -> Schema {
+        let mut schema = self.clone();
+        for field in &mut schema.fields {
+            field.data_type = DataType::Varchar;
+        }
+        schema
+    }
+
+    /// Describes, for each field, how its original type is stringified when the schema is
+    /// converted to an all-text form via [`Schema::all_text`].
+    pub fn text_format_descriptors(&self) -> Vec<TextFormatDescriptor> {
         self.fields
             .iter()
-            .map(|f| format!("\"{}\"", &f.name))
-            .collect::<Vec<_>>()
-            .join(", ")
+            .map(|f| TextFormatDescriptor::for_type(&f.data_type))
+            .collect()
     }
-}
 
-impl Field {
-    // TODO: rename to `new`
-    pub fn with_name<S>(data_type: DataType, name: S) -> Self
-    where
-        S: Into<String>,
-    {
-        Self {
-            data_type,
-            name: name.into(),
-        }
+    /// Returns the indices of the watermark columns, in the order they were declared.
+    pub fn watermark_column_indices(&self) -> Vec<usize> {
+        self.watermark_columns
+            .iter()
+            .map(|name| {
+                self.fields
+                    .iter()
+                    .position(|f| &f.name == name)
+                    .expect("watermark columns are validated on construction")
+            })
+            .collect()
     }
 
-    pub fn unnamed(data_type: DataType) -> Self {
-        Self {
-            data_type,
-            name: String::new(),
+    /// Returns a copy of the schema with per-position aliases applied, where `Some`
+    /// overrides the field name and `None` keeps it. Used for projections with column
+    /// aliases, e.g. `SELECT v AS renamed`.
+    ///
+    /// Renamed columns are also renamed in the schema-level
+    /// [`watermark_columns`](Schema::watermark_columns) and
+    /// [`primary_key`](Schema::primary_key) lists to keep them valid.
+    ///
+    /// Errors with [`SchemaError::AliasCountMismatch`] if the slice length does not match
+    /// the number of fields.
+    pub fn apply_aliases(&self, aliases: &[Option<String>]) -> Result<Schema, SchemaError> {
+        if aliases.len() != self.fields.len() {
+            return Err(SchemaError::AliasCountMismatch {
+                expected: self.fields.len(),
+                actual: aliases.len(),
+            });
         }
+        let mut schema = self.clone();
+        let mut renames = Vec::new();
+        for (field, alias) in schema.fields.iter_mut().zip_eq_fast(aliases) {
+            if let Some(alias) = alias {
+                renames.push((std::mem::replace(&mut field.name, alias.clone()), alias));
+            }
+        }
+        for (old, new) in renames {
+            for name in &mut schema.watermark_columns {
+                if *name == old {
+                    name.clone_from(new);
+                }
+            }
+            if let Some(primary_key) = &mut schema.primary_key {
+                for name in primary_key {
+                    if *name == old {
+                        name.clone_from(new);
+                    }
+                }
+            }
+        }
+        Ok(schema)
     }
 
-    pub fn data_type(&self) -> DataType {
-        self.data_type.clone()
+    /// Renames the field at `index` without any collision check, for
+    /// performance-sensitive internal use where the new name is known to be unique. Use
+    /// [`Self::rename_field_checked`] when the name comes from user input.
+    ///
+    /// Like [`Self::apply_aliases`], the renamed column is also renamed in the
+    /// schema-level [`watermark_columns`](Schema::watermark_columns) and
+    /// [`primary_key`](Schema::primary_key) lists to keep them valid.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn rename_field_at(&mut self, index: usize, new_name: &str) {
+        let old = std::mem::replace(&mut self.fields[index].name, new_name.to_owned());
+        for name in &mut self.watermark_columns {
+            if *name == old {
+                new_name.clone_into(name);
+            }
+        }
+        if let Some(primary_key) = &mut self.primary_key {
+            for name in primary_key {
+                if *name == old {
+                    new_name.clone_into(name);
+                }
+            }
+        }
     }
 
-    pub fn from_with_table_name_prefix(desc: &ColumnDesc, table_name: &str) -> Self {
-        Self {
-            data_type: desc.data_type.clone(),
-            name: format!("{}.{}", table_name, desc.name),
+    /// Renames the field at `index`, erroring with [`SchemaError::DuplicateColumn`] if
+    /// another field already carries `new_name`. Renaming a field to its current name is
+    /// a no-op.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn rename_field_checked(
+        &mut self,
+        index: usize,
+        new_name: &str,
+    ) -> Result<(), SchemaError> {
+        if self
+            .fields
+            .iter()
+            .enumerate()
+            .any(|(i, f)| i != index && f.name == new_name)
+        {
+            return Err(SchemaError::DuplicateColumn {
+                name: new_name.to_owned(),
+            });
         }
+        self.rename_field_at(index, new_name);
+        Ok(())
     }
-}
 
-impl From<&PbField> for Field {
-    fn from(prost_field: &PbField) -> Self {
-        Self {
-            data_type: DataType::from(prost_field.get_data_type().expect("data type not found")),
-            name: prost_field.get_name().clone(),
+    /// Renames the subfield `old_sub` of the struct column `column` to `new_sub`,
+    /// rebuilding the [`StructType`] in place so callers do not have to reconstruct the
+    /// whole `DataType` themselves.
+    ///
+    /// Errors with [`SchemaError::ColumnNotFound`] if the column or the subfield does not
+    /// exist, [`SchemaError::NotAStruct`] if the column is not a struct, and
+    /// [`SchemaError::DuplicateColumn`] if another subfield already carries `new_sub`.
+    /// Only top-level subfields are renamed; field ids of the struct, if any, are not
+    /// preserved.
+    pub fn rename_struct_subfield(
+        &mut self,
+        column: &str,
+        old_sub: &str,
+        new_sub: &str,
+    ) -> Result<(), SchemaError> {
+        let field = self
+            .fields
+            .iter_mut()
+            .find(|f| f.name == column)
+            .ok_or_else(|| SchemaError::ColumnNotFound {
+                name: column.to_owned(),
+            })?;
+        let DataType::Struct(struct_type) = &field.data_type else {
+            return Err(SchemaError::NotAStruct {
+                name: column.to_owned(),
+                actual: field.data_type.clone(),
+            });
+        };
+        if !struct_type.names().any(|name| name == old_sub) {
+            return Err(SchemaError::ColumnNotFound {
+                name: format!("{column}.{old_sub}"),
+            });
         }
+        if old_sub != new_sub && struct_type.names().any(|name| name == new_sub) {
+            return Err(SchemaError::DuplicateColumn {
+                name: format!("{column}.{new_sub}"),
+            });
+        }
+        let renamed = StructType::new(struct_type.iter().map(|(name, ty)| {
+            let name = if name == old_sub { new_sub } else { name };
+            (name, ty.clone())
+        }));
+        field.data_type = DataType::Struct(renamed);
+        Ok(())
     }
-}
 
-impl Index<usize> for Schema {
-    type Output = Field;
+    /// Returns a copy of the schema with every match of `pattern` in each field name
+    /// replaced by `replacement`, for bulk renames like stripping a `src_` prefix or
+    /// replacing separators. Capture groups can be referenced in `replacement`, e.g.
+    /// `$1`.
+    ///
+    /// Like [`Self::apply_aliases`], renamed columns are also renamed in the
+    /// schema-level name lists. Errors with [`SchemaError::InvalidRegex`] if the pattern
+    /// does not compile, and with [`SchemaError::DuplicateColumn`] if the substitution
+    /// makes two field names collide.
+    pub fn rename_with_regex(
+        &self,
+        pattern: &str,
+        replacement: &str,
+    ) -> Result<Schema, SchemaError> {
+        let regex = regex::Regex::new(pattern).map_err(|e| SchemaError::InvalidRegex {
+            pattern: pattern.to_owned(),
+            reason: e.to_string(),
+        })?;
+        let aliases: Vec<Option<String>> = self
+            .fields
+            .iter()
+            .map(|field| {
+                let new_name = regex.replace_all(&field.name, replacement);
+                (new_name != field.name).then(|| new_name.into_owned())
+            })
+            .collect();
+        let schema = self
+            .apply_aliases(&aliases)
+            .expect("alias count matches by construction");
 
-    fn index(&self, index: usize) -> &Self::Output {
-        &self.fields[index]
+        let mut seen = HashSet::new();
+        for field in &schema.fields {
+            if !seen.insert(field.name.as_str()) {
+                return Err(SchemaError::DuplicateColumn {
+                    name: field.name.clone(),
+                });
+            }
+        }
+        Ok(schema)
     }
-}
 
-impl FromIterator<Field> for Schema {
-    fn from_iter<I: IntoIterator<Item = Field>>(iter: I) -> Self {
-        Schema {
-            fields: iter.into_iter().collect::<Vec<_>>(),
+    /// Returns a copy of the schema with every field name coerced to the identifier
+    /// rules of the given dialect, see [`Field::coerced_name`].
+    ///
+    /// Like [`Self::apply_aliases`], coerced columns are also renamed in the
+    /// schema-level name lists. Errors with [`SchemaError::DuplicateColumn`] if
+    /// truncation makes two field names collide.
+    pub fn coerce_names_for(&self, dialect: &dyn SqlDialect) -> Result<Schema, SchemaError> {
+        let aliases: Vec<Option<String>> = self
+            .fields
+            .iter()
+            .map(|field| {
+                let coerced = field.coerced_name(dialect);
+                (coerced != field.name).then_some(coerced)
+            })
+            .collect();
+        let schema = self
+            .apply_aliases(&aliases)
+            .expect("alias count matches by construction");
+
+        let mut seen = HashSet::new();
+        for field in &schema.fields {
+            if !seen.insert(field.name.as_str()) {
+                return Err(SchemaError::DuplicateColumn {
+                    name: field.name.clone(),
+                });
+            }
         }
+        Ok(schema)
     }
-}
 
-impl From<&StructType> for Schema {
-    fn from(t: &StructType) -> Self {
-        Schema::new(
-            t.iter()
-                .map(|(s, d)| Field::with_name(d.clone(), s))
-                .collect(),
-        )
+    /// Returns whether all non-empty field names are unique.
+    ///
+    /// Empty names are ignored since unnamed fields (e.g. from expressions) are common
+    /// and never looked up by name. This is cheap enough to be used in debug assertions
+    /// at name-based lookup boundaries, which silently prefer one of the duplicates
+    /// otherwise.
+    pub fn has_unique_names(&self) -> bool {
+        let mut seen = HashSet::with_capacity(self.fields.len());
+        (self.fields.iter())
+            .filter(|f| !f.name.is_empty())
+            .all(|f| seen.insert(f.name.as_str()))
     }
-}
-
-pub mod test_utils {
-    use super::*;
 
-    pub fn field_n<const N: usize>(data_type: DataType) -> Schema {
-        Schema::new(vec![Field::unnamed(data_type); N])
+    /// Returns a map from field name to data type, for name-based lookups.
+    ///
+    /// When two fields share a name, the last one wins.
+    pub fn name_type_map(&self) -> HashMap<&str, &DataType> {
+        debug_assert!(
+            self.has_unique_names(),
+            "duplicate field names in schema: {:?}",
+            self.names()
+        );
+        self.fields
+            .iter()
+            .map(|f| (f.name.as_str(), &f.data_type))
+            .collect()
     }
 
-    fn int32_n<const N: usize>() -> Schema {
-        field_n::<N>(DataType::Int32)
+    /// Returns whether `self` and `other` contain the same columns with the same types,
+    /// matching columns by name and ignoring positional order.
+    ///
+    /// Returns `false` if either schema has a column the other lacks. Intended for
+    /// comparisons where column order is irrelevant, e.g. a catalog schema against a sink
+    /// target schema.
+    pub fn equals_by_name(&self, other: &Schema) -> bool {
+        if self.fields.len() != other.fields.len() {
+            return false;
+        }
+        let self_map = self.name_type_map();
+        let other_map = other.name_type_map();
+        self.fields
+            .iter()
+            .all(|f| other_map.get(f.name.as_str()) == Some(&&f.data_type))
+            && other
+                .fields
+                .iter()
+                .all(|f| self_map.get(f.name.as_str()) == Some(&&f.data_type))
     }
 
-    /// Create a util schema **for test only** with two int32 fields.
-    pub fn ii() -> Schema {
-        int32_n::<2>()
+    /// Returns a schema containing the columns at `indices` in the given order.
+    ///
+    /// Fields are cloned as a whole, so informational metadata such as descriptions and
+    /// lineage ([`Field::source_ref`]) is carried into the projected schema. The
+    /// schema-level [`primary_key`](Schema::primary_key) and
+    /// [`watermark_columns`](Schema::watermark_columns) lists are not, since a
+    /// projection generally invalidates them.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any index in `indices` is out of range.
+    pub fn project(&self, indices: &[usize]) -> Schema {
+        Schema::new(indices.iter().map(|&i| self.fields[i].clone()).collect())
     }
 
-    /// Create a util schema **for test only** with three int32 fields.
-    pub fn iii() -> Schema {
-        int32_n::<3>()
+    /// Returns a schema containing only the first `n` columns, for fixed-arity targets
+    /// that accept at most `n` columns.
+    ///
+    /// Any columns beyond the first `n` are silently dropped, losing their data; callers
+    /// wanting to reject over-wide schemas instead should use
+    /// [`Schema::validate_max_columns`]. If the schema has no more than `n` columns, it
+    /// is returned unchanged. Like [`Schema::project`], the schema-level
+    /// [`primary_key`](Schema::primary_key) and
+    /// [`watermark_columns`](Schema::watermark_columns) lists are dropped.
+    pub fn truncate_to(&self, n: usize) -> Schema {
+        Schema::new(self.fields.iter().take(n).cloned().collect())
     }
 
-    fn varchar_n<const N: usize>() -> Schema {
-        field_n::<N>(DataType::Varchar)
+    /// Returns a schema padded with placeholder columns of type `fill` up to exactly `n`
+    /// columns, for fixed-arity targets that require at least `n` columns.
+    ///
+    /// Placeholder columns are named by appending their column index to `name_prefix`,
+    /// e.g. `pad_to(4, ..., "_pad_")` on a 2-column schema appends `_pad_2` and `_pad_3`.
+    /// If the schema already has at least `n` columns, it is returned unchanged; combine
+    /// with [`Schema::truncate_to`] to force exactly `n`.
+    pub fn pad_to(&self, n: usize, fill: DataType, name_prefix: &str) -> Schema {
+        let mut fields = self.fields.clone();
+        for i in fields.len()..n {
+            fields.push(Field::with_name(fill.clone(), format!("{name_prefix}{i}")));
+        }
+        Schema::new(fields)
     }
 
-    /// Create a util schema **for test only** with three varchar fields.
-    pub fn sss() -> Schema {
-        varchar_n::<3>()
+    /// Returns the source column each field is derived from, with `None` for fields
+    /// without lineage information. See [`Field::source_ref`].
+    pub fn lineage(&self) -> Vec<Option<&ColumnSourceRef>> {
+        self.fields
+            .iter()
+            .map(|field| field.source_ref.as_ref())
+            .collect()
     }
 
-    fn decimal_n<const N: usize>() -> Schema {
-        field_n::<N>(DataType::Decimal)
+    /// Splits the schema into a key schema and a value schema, as used by state-table
+    /// encodings.
+    ///
+    /// The key schema contains the columns at `key_indices` in the given order; the value
+    /// schema contains the remaining columns in their original order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any index in `key_indices` is out of range or duplicated.
+    pub fn split_key_value(&self, key_indices: &[usize]) -> (Schema, Schema) {
+        let mut is_key = vec![false; self.fields.len()];
+        for &i in key_indices {
+            assert!(i < self.fields.len(), "key index {i} out of range");
+            assert!(!is_key[i], "duplicate key index {i}");
+            is_key[i] = true;
+        }
+        let key = key_indices.iter().map(|&i| self.fields[i].clone());
+        let value = self
+            .fields
+            .iter()
+            .zip_eq_fast(is_key)
+            .filter_map(|(field, is_key)| (!is_key).then(|| field.clone()));
+        (Schema::from_iter(key), Schema::from_iter(value))
+    }
+
+    /// Builds a schema from a struct type with per-field nullability supplied by the
+    /// caller, since [`StructType`] does not carry it. Like the plain
+    /// `From<&StructType>` conversion otherwise.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `nullables` does not have one entry per struct field.
+    pub fn from_struct_with_nullability(t: &StructType, nullables: &[bool]) -> Schema {
+        Schema::new(
+            t.iter()
+                .zip_eq_fast(nullables)
+                .map(|((name, data_type), &nullable)| {
+                    Field::with_name(data_type.clone(), name).with_nullable(nullable)
+                })
+                .collect(),
+        )
+    }
+
+    /// Builds a schema from a Debezium/Kafka Connect schema JSON object.
+    ///
+    /// The top-level schema must be a `struct`. Connect physical types map to the
+    /// corresponding [`DataType`]s, well-known logical types (identified by `name`, e.g.
+    /// Connect `Timestamp`) take precedence over their physical encoding, nullability is
+    /// derived from `optional`, and `doc` is carried into the field description.
+    pub fn from_kafka_connect_schema(json: &serde_json::Value) -> Result<Schema, SchemaError> {
+        let invalid = |reason: &str| SchemaError::InvalidConnectSchema {
+            reason: reason.to_owned(),
+        };
+        if json.get("type").and_then(|t| t.as_str()) != Some("struct") {
+            return Err(invalid("top-level schema must be a struct"));
+        }
+        let fields = json
+            .get("fields")
+            .and_then(|f| f.as_array())
+            .ok_or_else(|| invalid("missing `fields` array"))?;
+        let fields = fields
+            .iter()
+            .map(connect_field_to_field)
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Schema::new(fields))
+    }
+
+    /// Renders the schema as a GraphQL SDL object type named `type_name`, for services
+    /// exposing RisingWave relations over GraphQL.
+    ///
+    /// Scalar types map to the built-in GraphQL scalars where one exists and to
+    /// conventional custom scalars (e.g. `BigInt`, `DateTime`) otherwise; lists map to
+    /// GraphQL lists. Struct fields become additional object types named after the
+    /// parent type and field, appended after the parent definition. Non-nullable
+    /// fields are marked with `!`, and descriptions are emitted as `"""docstrings"""`.
+    pub fn to_graphql_type(&self, type_name: &str) -> String {
+        use std::fmt::Write;
+
+        let mut out = String::new();
+        let mut nested: Vec<(String, StructType)> = Vec::new();
+        if let Some(description) = &self.description {
+            writeln!(out, "\"\"\"{}\"\"\"", description).unwrap();
+        }
+        writeln!(out, "type {} {{", type_name).unwrap();
+        for field in &self.fields {
+            if let Some(description) = &field.description {
+                writeln!(out, "  \"\"\"{}\"\"\"", description).unwrap();
+            }
+            let graphql_type =
+                graphql_field_type(&field.data_type, type_name, &field.name, &mut nested);
+            let non_null = if field.nullable { "" } else { "!" };
+            writeln!(out, "  {}: {}{}", field.name, graphql_type, non_null).unwrap();
+        }
+        out.push_str("}\n");
+        for (name, struct_type) in nested {
+            out.push('\n');
+            out.push_str(&Schema::from(&struct_type).to_graphql_type(&name));
+        }
+        out
+    }
+
+    /// Generates a ClickHouse `CREATE TABLE` statement for the schema, e.g. for
+    /// auto-creating the target table of a ClickHouse sink.
+    ///
+    /// Nullable fields are wrapped in `Nullable(...)`, except for primary-key fields
+    /// since ClickHouse rejects nullable key columns. Fields tagged `low_cardinality`
+    /// are wrapped in `LowCardinality(...)`. The `ORDER BY` clause is derived from the
+    /// primary key of the schema, falling back to `tuple()` when there is none.
+    pub fn to_clickhouse_ddl(&self, table: &str, engine: &str) -> String {
+        use std::fmt::Write;
+
+        let quote = |name: &str| format!("`{}`", name.replace('`', "\\`"));
+        let primary_key = self.primary_key.as_deref().unwrap_or(&[]);
+
+        let mut out = format!("CREATE TABLE {} (", quote(table));
+        for (i, field) in self.fields.iter().enumerate() {
+            let mut column_type = clickhouse_type(&field.data_type);
+            if field.nullable && !primary_key.contains(&field.name) {
+                column_type = format!("Nullable({})", column_type);
+            }
+            if field.tags.iter().any(|tag| tag == "low_cardinality") {
+                column_type = format!("LowCardinality({})", column_type);
+            }
+            let sep = if i == 0 { "" } else { "," };
+            write!(out, "{}\n    {} {}", sep, quote(&field.name), column_type).unwrap();
+        }
+        write!(out, "\n) ENGINE = {}", engine).unwrap();
+        let order_by = if primary_key.is_empty() {
+            "tuple()".to_owned()
+        } else {
+            format!(
+                "({})",
+                (primary_key.iter())
+                    .map(|name| quote(name))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )
+        };
+        write!(out, "\nORDER BY {}", order_by).unwrap();
+        out
+    }
+
+    /// Generates a Parquet message-type schema text for direct Parquet writing, e.g.
+    /// `message m { required int32 id; optional binary name (UTF8); }` rendered over
+    /// multiple lines.
+    ///
+    /// Repetition is derived from nullability: non-nullable fields are `required`,
+    /// everything else `optional`. Logical annotations follow the converted-type names,
+    /// e.g. `UTF8` for strings, `DECIMAL(38,18)` for RisingWave's parameterless
+    /// decimals, and `TIMESTAMP_MICROS` for timestamps. Lists and maps use the standard
+    /// three-level group encoding; nested fields are always `optional` since nested
+    /// nullability is not tracked.
+    pub fn to_parquet_message_type(&self, message_name: &str) -> String {
+        let mut out = format!("message {} {{\n", message_name);
+        for field in &self.fields {
+            let repetition = if field.nullable {
+                "optional"
+            } else {
+                "required"
+            };
+            write_parquet_field(&mut out, 1, repetition, &field.name, &field.data_type);
+        }
+        out.push('}');
+        out
+    }
+
+    /// Renders `COMMENT ON` statements for the schema and field descriptions of `table`,
+    /// one statement per description.
+    ///
+    /// The schema description, if set, yields a `COMMENT ON TABLE` statement first,
+    /// followed by a `COMMENT ON COLUMN` statement for each field with a description.
+    /// Identifiers are quoted by the dialect and embedded quotes in descriptions are
+    /// escaped, so the statements can be executed verbatim, e.g. for incrementally
+    /// syncing comments to an external catalog.
+    pub fn comment_statements(&self, table: &str, dialect: &dyn SqlDialect) -> Vec<String> {
+        let table = dialect.quote_ident(table);
+        let mut statements = Vec::new();
+        if let Some(description) = &self.description {
+            statements.push(format!(
+                "COMMENT ON TABLE {} IS {}",
+                table,
+                sql_string_literal(description)
+            ));
+        }
+        for field in &self.fields {
+            if let Some(description) = &field.description {
+                statements.push(format!(
+                    "COMMENT ON COLUMN {}.{} IS {}",
+                    table,
+                    dialect.quote_ident(&field.name),
+                    sql_string_literal(description)
+                ));
+            }
+        }
+        statements
+    }
+
+    /// Returns one [`DescribeRow`] per field, so the `DESCRIBE` command can build a
+    /// result chunk directly instead of parsing a formatted string.
+    ///
+    /// Primary-key membership follows [`Self::primary_key_in_order`], so the
+    /// schema-level key list takes precedence over the per-field flags.
+    pub fn describe_rows(&self) -> Vec<DescribeRow> {
+        let pk_indices = self.primary_key_in_order();
+        self.fields
+            .iter()
+            .enumerate()
+            .map(|(i, field)| DescribeRow {
+                name: field.name.clone(),
+                type_name: field.data_type.to_string(),
+                nullable: field.nullable,
+                is_pk: pk_indices.contains(&i),
+                fk: field.foreign_key.clone(),
+                comment: field.description.clone(),
+            })
+            .collect()
+    }
+
+    pub fn names(&self) -> Vec<String> {
+        self.fields().iter().map(|f| f.name.clone()).collect()
+    }
+
+    pub fn names_str(&self) -> Vec<&str> {
+        self.fields().iter().map(|f| f.name.as_str()).collect()
+    }
+
+    /// Returns a lazy iterator over the field names, without collecting them into a `Vec`
+    /// like [`Self::names`] or [`Self::names_str`].
+    pub fn field_names(&self) -> impl Iterator<Item = &str> {
+        self.fields().iter().map(|f| f.name.as_str())
+    }
+
+    pub fn data_types(&self) -> Vec<DataType> {
+        self.fields
+            .iter()
+            .map(|field| field.data_type.clone())
+            .collect()
+    }
+
+    /// Returns a histogram of the field count per type kind, ignoring type parameters
+    /// (e.g. all struct fields count towards [`DataTypeName::Struct`]).
+    ///
+    /// This is a cheap input for planner heuristics that only care about the shape of a
+    /// relation, like preferring row format for varchar-heavy tables. Type kinds without
+    /// any field are absent from the map.
+    pub fn type_histogram(&self) -> HashMap<DataTypeName, usize> {
+        let mut histogram = HashMap::new();
+        for field in &self.fields {
+            *histogram.entry(field.data_type.type_name()).or_insert(0) += 1;
+        }
+        histogram
+    }
+
+    /// Returns the indices of the sensitive columns, i.e. those carrying at least one
+    /// governance tag or a masking policy. Sinks can consult these to redact output.
+    pub fn sensitive_columns(&self) -> Vec<usize> {
+        self.fields
+            .iter()
+            .enumerate()
+            .filter(|(_, field)| field.is_sensitive())
+            .map(|(i, _)| i)
+            .collect()
+    }
+
+    /// Like [`Self::sensitive_columns`], but returns the column names.
+    pub fn sensitive_column_names(&self) -> Vec<&str> {
+        self.fields
+            .iter()
+            .filter(|field| field.is_sensitive())
+            .map(|field| field.name.as_str())
+            .collect()
+    }
+
+    pub fn fields(&self) -> &[Field] {
+        &self.fields
+    }
+
+    pub fn into_fields(self) -> Vec<Field> {
+        self.fields
+    }
+
+    /// Pairs each field with the value at the same position, e.g. to build a row or chunk
+    /// from a schema and a slice of scalars in tests.
+    ///
+    /// The lengths must match; this is checked by a debug assertion only, and in release
+    /// builds the iterator simply stops at the shorter of the two.
+    pub fn zip_with<'a, V>(&'a self, values: &'a [V]) -> impl Iterator<Item = (&'a Field, &'a V)> {
+        debug_assert_eq!(
+            self.fields.len(),
+            values.len(),
+            "schema and values must have the same length"
+        );
+        self.fields.iter().zip(values.iter())
+    }
+
+    /// Create array builders for all fields in this schema.
+    pub fn create_array_builders(&self, capacity: usize) -> Vec<ArrayBuilderImpl> {
+        self.fields
+            .iter()
+            .map(|field| field.data_type.create_array_builder(capacity))
+            .collect()
+    }
+
+    /// Create array builders like [`Schema::create_array_builders`], additionally reserving
+    /// the given byte capacities for the data buffers of variable-length columns.
+    ///
+    /// `byte_hints` maps a column index to the expected total byte size of that column's
+    /// data. Hints for columns whose builders have no variable-length data buffer are
+    /// ignored, so callers may pass hints for all columns indiscriminately.
+    pub fn create_array_builders_with_hints(
+        &self,
+        row_capacity: usize,
+        byte_hints: &HashMap<usize, usize>,
+    ) -> Vec<ArrayBuilderImpl> {
+        let mut builders = self.create_array_builders(row_capacity);
+        for (&idx, &bytes) in byte_hints {
+            if let Some(builder) = builders.get_mut(idx) {
+                builder.reserve_data(bytes);
+            }
+        }
+        builders
+    }
+
+    /// Validates all foreign-key annotations of the fields against a resolver that tells
+    /// whether a `(table, column)` pair exists.
+    ///
+    /// Malformed annotations fail fast with [`SchemaError::InvalidForeignKey`]; dangling
+    /// references are collected across all fields and reported in a single
+    /// [`SchemaError::DanglingForeignKey`], so one call surfaces every problem at once.
+    pub fn validate_foreign_keys<F>(&self, resolver: F) -> Result<(), SchemaError>
+    where
+        F: Fn(&str, &str) -> bool,
+    {
+        let mut dangling = Vec::new();
+        for field in &self.fields {
+            let Some(definition) = &field.foreign_key else {
+                continue;
+            };
+            let fk = ForeignKeyRef::parse(definition)?;
+            for column in &fk.columns {
+                if !resolver(&fk.table, column) {
+                    dangling.push(format!("{} -> {}({})", field.name, fk.table, column));
+                }
+            }
+        }
+        if dangling.is_empty() {
+            Ok(())
+        } else {
+            Err(SchemaError::DanglingForeignKey { refs: dangling })
+        }
+    }
+
+    /// Checks that no field shadows one of the engine-internal column names in
+    /// `reserved`, e.g. [`ROW_ID_COLUMN_NAME`](super::ROW_ID_COLUMN_NAME) or
+    /// [`RW_TIMESTAMP_COLUMN_NAME`](super::RW_TIMESTAMP_COLUMN_NAME), so sources can
+    /// reject user schemas that would collide with internal columns.
+    ///
+    /// Collisions are collected across all fields and reported in a single
+    /// [`SchemaError::ReservedColumn`], like [`Schema::validate_foreign_keys`].
+    pub fn validate_no_reserved_names(&self, reserved: &[&str]) -> Result<(), SchemaError> {
+        let colliding: Vec<String> = self
+            .fields
+            .iter()
+            .filter(|field| reserved.contains(&field.name.as_str()))
+            .map(|field| field.name.clone())
+            .collect();
+        if colliding.is_empty() {
+            Ok(())
+        } else {
+            Err(SchemaError::ReservedColumn { names: colliding })
+        }
+    }
+
+    /// Checks that the schema does not exceed `limit` columns.
+    ///
+    /// Target systems commonly impose column-count limits, so sinks can call this as a
+    /// cheap guard before attempting DDL against the downstream system.
+    pub fn validate_max_columns(&self, limit: usize) -> Result<(), SchemaError> {
+        if self.fields.len() > limit {
+            Err(SchemaError::TooManyColumns {
+                actual: self.fields.len(),
+                limit,
+            })
+        } else {
+            Ok(())
+        }
+    }
+
+    pub fn to_prost(&self) -> Vec<PbField> {
+        self.fields
+            .clone()
+            .into_iter()
+            .map(|field| field.to_prost())
+            .collect()
+    }
+
+    /// Like [`Schema::to_prost`], but with control over which informational metadata is
+    /// included, see [`ProstOptions`].
+    pub fn to_prost_with(&self, opts: ProstOptions) -> Vec<PbField> {
+        self.fields
+            .iter()
+            .map(|field| field.to_prost_with(opts))
+            .collect()
+    }
+
+    pub fn type_eq(&self, other: &Self) -> bool {
+        if self.len() != other.len() {
+            return false;
+        }
+
+        for (a, b) in self.fields.iter().zip_eq_fast(other.fields.iter()) {
+            if !a.data_type.equals_datatype(&b.data_type) {
+                return false;
+            }
+        }
+
+        true
+    }
+
+    pub fn all_type_eq<'a>(inputs: impl IntoIterator<Item = &'a Self>) -> bool {
+        let mut iter = inputs.into_iter();
+        if let Some(first) = iter.next() {
+            iter.all(|x| x.type_eq(first))
+        } else {
+            true
+        }
+    }
+
+    /// Returns the fields of `self` whose names also appear in `other`, in the order requested
+    /// by `other`. This aligns a (possibly evolved) file schema with the schema a reader asks
+    /// for: columns missing from either side are dropped, and columns present on both sides
+    /// with incompatible types are handled according to `policy`.
+    pub fn intersect_by_name(
+        &self,
+        other: &Schema,
+        policy: TypeMismatchPolicy,
+    ) -> Result<Schema, SchemaError> {
+        let mut fields = Vec::new();
+        for requested in &other.fields {
+            let Some(field) = self.fields.iter().find(|f| f.name == requested.name) else {
+                continue;
+            };
+            if field.data_type.equals_datatype(&requested.data_type) {
+                fields.push(field.clone());
+            } else if let TypeMismatchPolicy::Error = policy {
+                return Err(SchemaError::TypeMismatch {
+                    name: field.name.clone(),
+                    left: field.data_type.clone(),
+                    right: requested.data_type.clone(),
+                });
+            }
+        }
+        Ok(Schema::new(fields))
+    }
+
+    /// Returns the common widened schema across `self` and `others`, for multi-source
+    /// ingestion where each source reports slightly different types for the same
+    /// columns.
+    ///
+    /// The result contains the fields of `self`, in order, that appear in every other
+    /// schema by name; each takes the smallest common supertype across all sources,
+    /// e.g. `Int16 + Int32 -> Int32` and integers mixed with floats -> `Float64`.
+    /// Errors with [`SchemaError::TypeMismatch`] for the first column without a common
+    /// supertype.
+    pub fn widen_with(&self, others: &[&Schema]) -> Result<Schema, SchemaError> {
+        let mut fields = Vec::new();
+        'outer: for field in &self.fields {
+            let mut widened = field.clone();
+            for other in others {
+                let Some(other_field) = other.fields.iter().find(|f| f.name == field.name) else {
+                    continue 'outer;
+                };
+                widened.data_type = common_supertype(&widened.data_type, &other_field.data_type)
+                    .ok_or_else(|| SchemaError::TypeMismatch {
+                        name: field.name.clone(),
+                        left: widened.data_type.clone(),
+                        right: other_field.data_type.clone(),
+                    })?;
+            }
+            fields.push(widened);
+        }
+        Ok(Schema::new(fields))
+    }
+
+    /// Returns whether every field of `self` exists in `base` with a compatible type,
+    /// regardless of order. Useful for validating that a projection only references
+    /// columns of a base schema.
+    pub fn is_name_subset_of(&self, base: &Schema) -> bool {
+        self.missing_columns(base).is_empty()
+    }
+
+    /// Returns the fields of `self` that are not covered by `base`: fields missing from
+    /// `base` entirely, as well as fields present under the same name but with an
+    /// incompatible type. The companion of [`Schema::is_name_subset_of`] for error
+    /// reporting.
+    pub fn missing_columns<'a>(&'a self, base: &Schema) -> Vec<&'a Field> {
+        self.fields
+            .iter()
+            .filter(|field| {
+                !base
+                    .fields
+                    .iter()
+                    .any(|b| b.name == field.name && b.data_type.equals_datatype(&field.data_type))
+            })
+            .collect()
+    }
+
+    /// Asserts that a value of this schema can be read as a value of `target`, comparing
+    /// the fields positionally.
+    ///
+    /// The schemas must have the same number of fields and matching field names. How the
+    /// types may differ is controlled by `mode`, see [`CompatibilityMode`].
+    pub fn assert_compatible_with(
+        &self,
+        target: &Schema,
+        mode: CompatibilityMode,
+    ) -> Result<(), SchemaError> {
+        if self.len() != target.len() {
+            return Err(SchemaError::FieldCountMismatch {
+                expected: target.len(),
+                actual: self.len(),
+            });
+        }
+        for (field, expected) in self.fields.iter().zip_eq_fast(&target.fields) {
+            if field.name != expected.name {
+                return Err(SchemaError::ColumnNotFound {
+                    name: expected.name.clone(),
+                });
+            }
+            let compatible = match mode {
+                CompatibilityMode::Exact => field.data_type.equals_datatype(&expected.data_type),
+                CompatibilityMode::Widening => {
+                    field.data_type.equals_datatype(&expected.data_type)
+                        || is_widening(&field.data_type, &expected.data_type)
+                }
+                CompatibilityMode::AnyCast => true,
+            };
+            if !compatible {
+                return Err(SchemaError::TypeMismatch {
+                    name: field.name.clone(),
+                    left: field.data_type.clone(),
+                    right: expected.data_type.clone(),
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the positions of the given column names, in the requested order.
+    ///
+    /// Errors with the first requested name that does not exist in the schema. Duplicate
+    /// requested names are allowed and resolve to the same position each time.
+    pub fn column_positions(&self, names: &[&str]) -> Result<Vec<usize>, SchemaError> {
+        debug_assert!(
+            self.has_unique_names(),
+            "duplicate field names in schema: {:?}",
+            self.names()
+        );
+        names
+            .iter()
+            .map(|name| {
+                self.fields
+                    .iter()
+                    .position(|f| f.name == *name)
+                    .ok_or_else(|| SchemaError::ColumnNotFound {
+                        name: (*name).to_owned(),
+                    })
+            })
+            .collect()
+    }
+
+    pub fn formatted_col_names(&self) -> String {
+        self.fields
+            .iter()
+            .map(|f| format!("\"{}\"", &f.name))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
+
+impl Field {
+    // TODO: rename to `new`
+    pub fn with_name<S>(data_type: DataType, name: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            data_type,
+            name: name.into(),
+            foreign_key: None,
+            description: None,
+            is_primary_key: false,
+            nullable: true,
+            tags: vec![],
+            masking_policy: None,
+            source_ref: None,
+        }
+    }
+
+    pub fn unnamed(data_type: DataType) -> Self {
+        Self {
+            data_type,
+            name: String::new(),
+            foreign_key: None,
+            description: None,
+            is_primary_key: false,
+            nullable: true,
+            tags: vec![],
+            masking_policy: None,
+            source_ref: None,
+        }
+    }
+
+    pub fn data_type(&self) -> DataType {
+        self.data_type.clone()
+    }
+
+    pub fn from_with_table_name_prefix(desc: &ColumnDesc, table_name: &str) -> Self {
+        Self {
+            data_type: desc.data_type.clone(),
+            name: format!("{}.{}", table_name, desc.name),
+            foreign_key: desc.foreign_key.clone(),
+            description: desc.description.clone(),
+            is_primary_key: false,
+            nullable: desc.nullable,
+            tags: vec![],
+            masking_policy: None,
+            source_ref: None,
+        }
+    }
+}
+
+impl From<&PbField> for Field {
+    fn from(prost_field: &PbField) -> Self {
+        Self {
+            data_type: DataType::from(prost_field.get_data_type().expect("data type not found")),
+            name: prost_field.get_name().clone(),
+            foreign_key: prost_field.foreign_key.clone(),
+            description: prost_field.description.clone(),
+            is_primary_key: false,
+            nullable: true,
+            tags: prost_field.tags.clone(),
+            masking_policy: prost_field.masking_policy.clone(),
+            source_ref: None,
+        }
+    }
+}
+
+/// Converts one entry of a Kafka Connect `fields` array into a [`Field`], see
+/// [`Schema::from_kafka_connect_schema`].
+fn connect_field_to_field(json: &serde_json::Value) -> Result<Field, SchemaError> {
+    let invalid = |reason: &str| SchemaError::InvalidConnectSchema {
+        reason: reason.to_owned(),
+    };
+    let name = json
+        .get("field")
+        .and_then(|f| f.as_str())
+        .ok_or_else(|| invalid("missing `field` name"))?;
+    let data_type = connect_type_to_data_type(json)?;
+    // Connect fields are non-optional unless stated otherwise.
+    let nullable = json
+        .get("optional")
+        .and_then(|o| o.as_bool())
+        .unwrap_or(false);
+    let mut field = Field::with_name(data_type, name).with_nullable(nullable);
+    if let Some(doc) = json.get("doc").and_then(|d| d.as_str()) {
+        field = field.with_description(doc);
+    }
+    Ok(field)
+}
+
+/// Maps a Kafka Connect type to a [`DataType`], with well-known logical types (identified
+/// by `name`) taking precedence over their physical encoding.
+fn connect_type_to_data_type(json: &serde_json::Value) -> Result<DataType, SchemaError> {
+    let invalid = |reason: String| SchemaError::InvalidConnectSchema { reason };
+    if let Some(logical) = json.get("name").and_then(|n| n.as_str()) {
+        match logical {
+            "org.apache.kafka.connect.data.Date" | "io.debezium.time.Date" => {
+                return Ok(DataType::Date);
+            }
+            "org.apache.kafka.connect.data.Time" => return Ok(DataType::Time),
+            "org.apache.kafka.connect.data.Timestamp" | "io.debezium.time.Timestamp" => {
+                return Ok(DataType::Timestamp);
+            }
+            "io.debezium.time.ZonedTimestamp" => return Ok(DataType::Timestamptz),
+            // Unknown logical types fall back to their physical encoding.
+            _ => {}
+        }
+    }
+    match json.get("type").and_then(|t| t.as_str()) {
+        Some("boolean") => Ok(DataType::Boolean),
+        Some("int8") | Some("int16") => Ok(DataType::Int16),
+        Some("int32") => Ok(DataType::Int32),
+        Some("int64") => Ok(DataType::Int64),
+        Some("float") => Ok(DataType::Float32),
+        Some("double") => Ok(DataType::Float64),
+        Some("string") => Ok(DataType::Varchar),
+        Some("bytes") => Ok(DataType::Bytea),
+        Some("array") => {
+            let items = json
+                .get("items")
+                .ok_or_else(|| invalid("array type without `items`".to_owned()))?;
+            Ok(connect_type_to_data_type(items)?.list())
+        }
+        Some("struct") => {
+            let fields = json
+                .get("fields")
+                .and_then(|f| f.as_array())
+                .ok_or_else(|| invalid("struct type without `fields`".to_owned()))?;
+            let fields = fields
+                .iter()
+                .map(|f| {
+                    let field = connect_field_to_field(f)?;
+                    Ok((field.name, field.data_type))
+                })
+                .collect::<Result<Vec<_>, SchemaError>>()?;
+            Ok(DataType::Struct(StructType::new(fields)))
+        }
+        Some(other) => Err(invalid(format!("unsupported type `{other}`"))),
+        None => Err(invalid("missing `type`".to_owned())),
+    }
+}
+
+/// Maps a [`DataType`] to a GraphQL type reference for [`Schema::to_graphql_type`].
+///
+/// Structs are replaced by a reference to a nested object type named after the parent
+/// type and field, which is recorded in `nested` for the caller to render.
+fn graphql_field_type(
+    data_type: &DataType,
+    parent_type_name: &str,
+    field_name: &str,
+    nested: &mut Vec<(String, StructType)>,
+) -> String {
+    match data_type {
+        DataType::Boolean => "Boolean".to_owned(),
+        DataType::Int16 | DataType::Int32 => "Int".to_owned(),
+        DataType::Int64 | DataType::Serial | DataType::Int256 => "BigInt".to_owned(),
+        DataType::Float32 | DataType::Float64 => "Float".to_owned(),
+        DataType::Decimal => "Decimal".to_owned(),
+        DataType::Date => "Date".to_owned(),
+        DataType::Time => "Time".to_owned(),
+        DataType::Timestamp | DataType::Timestamptz => "DateTime".to_owned(),
+        DataType::Interval => "Interval".to_owned(),
+        DataType::Varchar => "String".to_owned(),
+        DataType::Bytea => "Bytes".to_owned(),
+        // GraphQL has no standard representation for dynamic or keyed collections.
+        DataType::Jsonb | DataType::Map(_) => "JSON".to_owned(),
+        DataType::Vector(_) => "[Float]".to_owned(),
+        DataType::List(list) => format!(
+            "[{}]",
+            graphql_field_type(list.elem(), parent_type_name, field_name, nested)
+        ),
+        DataType::Struct(struct_type) => {
+            let name = format!("{}{}", parent_type_name, pascal_case(field_name));
+            nested.push((name.clone(), struct_type.clone()));
+            name
+        }
+    }
+}
+
+/// Maps a RisingWave data type to the corresponding ClickHouse type.
+fn clickhouse_type(data_type: &DataType) -> String {
+    match data_type {
+        DataType::Boolean => "Bool".to_owned(),
+        DataType::Int16 => "Int16".to_owned(),
+        DataType::Int32 => "Int32".to_owned(),
+        DataType::Int64 | DataType::Serial => "Int64".to_owned(),
+        DataType::Int256 => "Int256".to_owned(),
+        DataType::Float32 => "Float32".to_owned(),
+        DataType::Float64 => "Float64".to_owned(),
+        // RisingWave decimals carry no precision or scale, so pick a wide default.
+        DataType::Decimal => "Decimal(38, 18)".to_owned(),
+        DataType::Date => "Date32".to_owned(),
+        DataType::Timestamp => "DateTime64(6)".to_owned(),
+        DataType::Timestamptz => "DateTime64(6, 'UTC')".to_owned(),
+        DataType::Varchar => "String".to_owned(),
+        // ClickHouse has no dedicated types for these; render them as text.
+        DataType::Time | DataType::Interval | DataType::Jsonb => "String".to_owned(),
+        DataType::Bytea => "String".to_owned(),
+        DataType::List(list) => format!("Array({})", clickhouse_type(list.elem())),
+        DataType::Vector(_) => "Array(Float32)".to_owned(),
+        DataType::Map(map) => format!(
+            "Map({}, {})",
+            clickhouse_type(map.key()),
+            clickhouse_type(map.value())
+        ),
+        DataType::Struct(struct_type) => format!(
+            "Tuple({})",
+            (struct_type.iter())
+                .map(|(name, data_type)| format!("{} {}", name, clickhouse_type(data_type)))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+    }
+}
+
+/// Appends one Parquet schema-text field line (or group block) for the given type, see
+/// [`Schema::to_parquet_message_type`].
+fn write_parquet_field(
+    out: &mut String,
+    indent: usize,
+    repetition: &str,
+    name: &str,
+    data_type: &DataType,
+) {
+    use std::fmt::Write;
+
+    let pad = "  ".repeat(indent);
+    match data_type {
+        DataType::Struct(struct_type) => {
+            writeln!(out, "{pad}{repetition} group {name} {{").unwrap();
+            for (name, data_type) in struct_type.iter() {
+                write_parquet_field(out, indent + 1, "optional", name, data_type);
+            }
+            writeln!(out, "{pad}}}").unwrap();
+        }
+        DataType::List(list) => {
+            // The standard three-level list encoding.
+            writeln!(out, "{pad}{repetition} group {name} (LIST) {{").unwrap();
+            writeln!(out, "{pad}  repeated group list {{").unwrap();
+            write_parquet_field(out, indent + 2, "optional", "element", list.elem());
+            writeln!(out, "{pad}  }}").unwrap();
+            writeln!(out, "{pad}}}").unwrap();
+        }
+        DataType::Map(map) => {
+            writeln!(out, "{pad}{repetition} group {name} (MAP) {{").unwrap();
+            writeln!(out, "{pad}  repeated group key_value {{").unwrap();
+            write_parquet_field(out, indent + 2, "required", "key", map.key());
+            write_parquet_field(out, indent + 2, "optional", "value", map.value());
+            writeln!(out, "{pad}  }}").unwrap();
+            writeln!(out, "{pad}}}").unwrap();
+        }
+        _ => {
+            let (physical, annotation) = parquet_primitive_type(data_type);
+            match annotation {
+                Some(annotation) => {
+                    writeln!(out, "{pad}{repetition} {physical} {name} ({annotation});").unwrap()
+                }
+                None => writeln!(out, "{pad}{repetition} {physical} {name};").unwrap(),
+            }
+        }
+    }
+}
+
+/// Maps a non-nested RisingWave data type to a Parquet physical type and optional
+/// converted-type annotation.
+fn parquet_primitive_type(data_type: &DataType) -> (&'static str, Option<&'static str>) {
+    match data_type {
+        DataType::Boolean => ("boolean", None),
+        DataType::Int16 => ("int32", Some("INT_16")),
+        DataType::Int32 => ("int32", None),
+        DataType::Int64 | DataType::Serial => ("int64", None),
+        DataType::Float32 => ("float", None),
+        DataType::Float64 => ("double", None),
+        // RisingWave decimals carry no precision or scale, so pick a wide default.
+        DataType::Decimal => ("fixed_len_byte_array(16)", Some("DECIMAL(38,18)")),
+        DataType::Date => ("int32", Some("DATE")),
+        DataType::Time => ("int64", Some("TIME_MICROS")),
+        DataType::Timestamp | DataType::Timestamptz => ("int64", Some("TIMESTAMP_MICROS")),
+        DataType::Varchar => ("binary", Some("UTF8")),
+        DataType::Jsonb => ("binary", Some("JSON")),
+        // Types without a Parquet counterpart are stored as raw bytes.
+        DataType::Bytea | DataType::Interval | DataType::Int256 | DataType::Vector(_) => {
+            ("binary", None)
+        }
+        DataType::Struct(_) | DataType::List(_) | DataType::Map(_) => {
+            unreachable!("nested types are handled by `write_parquet_field`")
+        }
+    }
+}
+
+/// Converts a snake_case field name to PascalCase for nested GraphQL type names.
+fn pascal_case(name: &str) -> String {
+    name.split('_')
+        .flat_map(|part| {
+            let mut chars = part.chars();
+            chars
+                .next()
+                .map(|c| c.to_ascii_uppercase())
+                .into_iter()
+                .chain(chars)
+        })
+        .collect()
+}
+
+impl Index<usize> for Schema {
+    type Output = Field;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.fields[index]
+    }
+}
+
+impl FromIterator<Field> for Schema {
+    fn from_iter<I: IntoIterator<Item = Field>>(iter: I) -> Self {
+        Schema::new(iter.into_iter().collect::<Vec<_>>())
+    }
+}
+
+impl IntoIterator for Schema {
+    type IntoIter = std::vec::IntoIter<Field>;
+    type Item = Field;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.fields.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a Schema {
+    type IntoIter = std::slice::Iter<'a, Field>;
+    type Item = &'a Field;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.fields.iter()
+    }
+}
+
+impl From<&StructType> for Schema {
+    fn from(t: &StructType) -> Self {
+        Schema::new(
+            t.iter()
+                .map(|(s, d)| Field::with_name(d.clone(), s))
+                .collect(),
+        )
+    }
+}
+
+/// How a value of a given type is stringified when a schema is converted to an all-text
+/// form via [`Schema::all_text`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TextFormatDescriptor {
+    /// Already text; passed through unchanged.
+    Identity,
+    /// Rendered in the default textual way, e.g. numbers, booleans and intervals.
+    Plain,
+    /// Rendered as an ISO-8601 date/time string.
+    DateTime,
+    /// Rendered as JSON text.
+    Json,
+    /// Hex-encoded with a `\x` prefix.
+    Hex,
+    /// Rendered with brace/parenthesis syntax, e.g. `{1,2,3}` for arrays and `(a,b)` for
+    /// structs.
+    Composite,
+}
+
+impl TextFormatDescriptor {
+    /// The descriptor for a value of the given type.
+    pub fn for_type(data_type: &DataType) -> Self {
+        match data_type {
+            DataType::Varchar => Self::Identity,
+            DataType::Date | DataType::Time | DataType::Timestamp | DataType::Timestamptz => {
+                Self::DateTime
+            }
+            DataType::Jsonb => Self::Json,
+            DataType::Bytea => Self::Hex,
+            DataType::List(_) | DataType::Struct(_) | DataType::Map(_) => Self::Composite,
+            _ => Self::Plain,
+        }
+    }
+}
+
+/// A mutable builder for deriving a new [`Schema`] from an existing one, e.g. when adding,
+/// dropping or renaming columns.
+#[derive(Clone, Debug, Default)]
+pub struct SchemaBuilder {
+    fields: Vec<Field>,
+    description: Option<String>,
+    metadata_comment: Option<String>,
+    watermark_columns: Vec<String>,
+    primary_key: Option<Vec<String>>,
+}
+
+impl SchemaBuilder {
+    /// Seeds a builder with all fields and schema-level metadata of `schema`.
+    pub fn from_schema(schema: &Schema) -> Self {
+        Self {
+            fields: schema.fields.clone(),
+            description: schema.description.clone(),
+            metadata_comment: schema.metadata_comment.clone(),
+            watermark_columns: schema.watermark_columns.clone(),
+            primary_key: schema.primary_key.clone(),
+        }
+    }
+
+    /// Appends a field.
+    pub fn add_field(mut self, field: Field) -> Self {
+        self.fields.push(field);
+        self
+    }
+
+    /// Drops all fields with the given name. No-op if no such field exists.
+    pub fn drop_field(mut self, name: &str) -> Self {
+        self.fields.retain(|f| f.name != name);
+        self
+    }
+
+    /// Renames all fields named `old` to `new`. No-op if no such field exists.
+    pub fn rename_field(mut self, old: &str, new: &str) -> Self {
+        for field in &mut self.fields {
+            if field.name == old {
+                field.name = new.to_owned();
+            }
+        }
+        for name in &mut self.watermark_columns {
+            if name == old {
+                *name = new.to_owned();
+            }
+        }
+        if let Some(primary_key) = &mut self.primary_key {
+            for name in primary_key {
+                if name == old {
+                    *name = new.to_owned();
+                }
+            }
+        }
+        self
+    }
+
+    /// Builds the schema.
+    pub fn build(mut self) -> Schema {
+        // Dropped fields are removed from the watermark and primary-key columns to keep
+        // them valid.
+        self.watermark_columns
+            .retain(|name| self.fields.iter().any(|f| &f.name == name));
+        if let Some(primary_key) = &mut self.primary_key {
+            primary_key.retain(|name| self.fields.iter().any(|f| &f.name == name));
+        }
+        Schema {
+            fields: self.fields,
+            description: self.description,
+            metadata_comment: self.metadata_comment,
+            watermark_columns: self.watermark_columns,
+            primary_key: self.primary_key,
+        }
+    }
+}
+
+pub mod test_utils {
+    use super::*;
+
+    pub fn field_n<const N: usize>(data_type: DataType) -> Schema {
+        Schema::new(vec![Field::unnamed(data_type); N])
+    }
+
+    fn int32_n<const N: usize>() -> Schema {
+        field_n::<N>(DataType::Int32)
+    }
+
+    /// Create a util schema **for test only** with two int32 fields.
+    pub fn ii() -> Schema {
+        int32_n::<2>()
+    }
+
+    /// Create a util schema **for test only** with three int32 fields.
+    pub fn iii() -> Schema {
+        int32_n::<3>()
+    }
+
+    fn varchar_n<const N: usize>() -> Schema {
+        field_n::<N>(DataType::Varchar)
+    }
+
+    /// Create a util schema **for test only** with three varchar fields.
+    pub fn sss() -> Schema {
+        varchar_n::<3>()
+    }
+
+    fn decimal_n<const N: usize>() -> Schema {
+        field_n::<N>(DataType::Decimal)
     }
 
     /// Create a util schema **for test only** with three decimal fields.
@@ -332,3 +2231,1446 @@ pub mod test_utils {
         decimal_n::<3>()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_intersect_by_name() {
+        let file = Schema::new(vec![
+            Field::with_name(DataType::Int32, "id"),
+            Field::with_name(DataType::Varchar, "name"),
+            Field::with_name(DataType::Float64, "score"),
+        ]);
+        let requested = Schema::new(vec![
+            Field::with_name(DataType::Varchar, "name"),
+            Field::with_name(DataType::Int32, "id"),
+            Field::with_name(DataType::Int64, "missing"),
+        ]);
+
+        // Partial overlap: only common columns survive, in requested order.
+        let aligned = file
+            .intersect_by_name(&requested, TypeMismatchPolicy::Error)
+            .unwrap();
+        assert_eq!(aligned.names(), vec!["name".to_owned(), "id".to_owned()]);
+        assert_eq!(
+            aligned.data_types(),
+            vec![DataType::Varchar, DataType::Int32]
+        );
+
+        // Type mismatch: error or skip according to the policy.
+        let mismatched = Schema::new(vec![Field::with_name(DataType::Varchar, "id")]);
+        assert!(matches!(
+            file.intersect_by_name(&mismatched, TypeMismatchPolicy::Error),
+            Err(SchemaError::TypeMismatch { name, .. }) if name == "id"
+        ));
+        let skipped = file
+            .intersect_by_name(&mismatched, TypeMismatchPolicy::Skip)
+            .unwrap();
+        assert!(skipped.is_empty());
+    }
+
+    #[test]
+    fn test_widen_with() {
+        let source_a = Schema::new(vec![
+            Field::with_name(DataType::Int16, "id"),
+            Field::with_name(DataType::Int32, "score"),
+            Field::with_name(DataType::Varchar, "name"),
+            Field::with_name(DataType::Int32, "only_here"),
+        ]);
+        let source_b = Schema::new(vec![
+            Field::with_name(DataType::Int32, "id"),
+            Field::with_name(DataType::Float32, "score"),
+            Field::with_name(DataType::Varchar, "name"),
+        ]);
+        let source_c = Schema::new(vec![
+            Field::with_name(DataType::Int64, "id"),
+            Field::with_name(DataType::Int64, "score"),
+            Field::with_name(DataType::Varchar, "name"),
+        ]);
+
+        // Columns missing from some source are dropped; the rest take the smallest
+        // common supertype across all three sources.
+        let widened = source_a.widen_with(&[&source_b, &source_c]).unwrap();
+        assert_eq!(widened.names(), vec!["id", "score", "name"]);
+        assert_eq!(
+            widened.data_types(),
+            vec![DataType::Int64, DataType::Float64, DataType::Varchar]
+        );
+
+        // Widening with nothing is the identity.
+        assert_eq!(source_a.widen_with(&[]).unwrap(), source_a);
+
+        // A column without a common supertype is an error.
+        let incompatible = Schema::new(vec![Field::with_name(DataType::Varchar, "id")]);
+        assert!(matches!(
+            source_a.widen_with(&[&incompatible]),
+            Err(SchemaError::TypeMismatch { name, .. }) if name == "id"
+        ));
+    }
+
+    #[test]
+    fn test_into_iterator() {
+        let schema = Schema::new(vec![
+            Field::with_name(DataType::Int32, "id"),
+            Field::with_name(DataType::Varchar, "name"),
+        ]);
+
+        // By reference: the schema stays usable afterwards.
+        let names: Vec<_> = (&schema).into_iter().map(|f| f.name.as_str()).collect();
+        assert_eq!(names, vec!["id", "name"]);
+        for field in &schema {
+            assert!(!field.name.is_empty());
+        }
+
+        // By value: fields are moved out, and round-trip through `FromIterator`.
+        let round_tripped: Schema = schema.clone().into_iter().collect();
+        assert_eq!(round_tripped, schema);
+        let fields: Vec<Field> = schema.into_iter().collect();
+        assert_eq!(fields.len(), 2);
+    }
+
+    #[test]
+    fn test_zip_with() {
+        let schema = Schema::new(vec![
+            Field::with_name(DataType::Int32, "id"),
+            Field::with_name(DataType::Varchar, "name"),
+        ]);
+        let values = ["1", "alice"];
+        let pairs: Vec<_> = schema
+            .zip_with(&values)
+            .map(|(f, v)| (f.name.as_str(), *v))
+            .collect();
+        assert_eq!(pairs, vec![("id", "1"), ("name", "alice")]);
+    }
+
+    #[test]
+    #[should_panic(expected = "schema and values must have the same length")]
+    #[cfg(debug_assertions)]
+    fn test_zip_with_length_mismatch() {
+        let schema = Schema::new(vec![Field::with_name(DataType::Int32, "id")]);
+        let _ = schema.zip_with(&["1", "2"]);
+    }
+
+    #[test]
+    fn test_schema_description_and_metadata_comment() {
+        let bare = Schema::new(vec![Field::with_name(DataType::Int32, "id")]);
+
+        // Both fields can be set independently.
+        let documented = bare.clone().with_description("user ids");
+        let tagged = bare.clone().with_metadata_comment(r#"{"owner":"ingest"}"#);
+        let both = documented.clone().with_metadata_comment(r#"{"pii":false}"#);
+        assert_eq!(documented.description(), Some("user ids"));
+        assert_eq!(documented.metadata_comment(), None);
+        assert_eq!(tagged.description(), None);
+        assert_eq!(tagged.metadata_comment(), Some(r#"{"owner":"ingest"}"#));
+        assert_eq!(both.description(), Some("user ids"));
+        assert_eq!(both.metadata_comment(), Some(r#"{"pii":false}"#));
+
+        // Metadata survives clone but does not participate in equality.
+        assert_eq!(bare, both);
+        assert_eq!(both.clone().metadata_comment(), Some(r#"{"pii":false}"#));
+    }
+
+    #[test]
+    fn test_merge_descriptions_from() {
+        let documented = Schema::new(vec![
+            Field::with_name(DataType::Int32, "id").with_description("user id"),
+            Field::with_name(DataType::Varchar, "name").with_description("display name"),
+            Field::with_name(DataType::Int64, "extra").with_description("not present in target"),
+        ])
+        .with_description("users");
+
+        let mut target = Schema::new(vec![
+            Field::with_name(DataType::Int32, "id"),
+            Field::with_name(DataType::Varchar, "name").with_description("kept as-is"),
+            Field::with_name(DataType::Float64, "score"),
+        ]);
+        target.merge_descriptions_from(&documented);
+
+        // Copied where missing, kept where present, untouched where unmatched.
+        assert_eq!(target.description(), Some("users"));
+        assert_eq!(target.fields[0].description.as_deref(), Some("user id"));
+        assert_eq!(target.fields[1].description.as_deref(), Some("kept as-is"));
+        assert_eq!(target.fields[2].description, None);
+    }
+
+    #[test]
+    fn test_merge_dedup() {
+        let left = Schema::new(vec![
+            Field::with_name(DataType::Int32, "id"),
+            Field::with_name(DataType::Varchar, "name"),
+        ]);
+        let right = Schema::new(vec![
+            Field::with_name(DataType::Int32, "id"),
+            Field::with_name(DataType::Float64, "score"),
+        ]);
+
+        // The colliding `id` gets the suffix, the non-colliding `score` is kept as-is.
+        let merged = left.merge_dedup(&right, "_right");
+        assert_eq!(merged.names(), vec!["id", "name", "id_right", "score"]);
+        assert_eq!(merged.fields[2].data_type, DataType::Int32);
+
+        // Without collisions the merge is a plain concatenation.
+        let other = Schema::new(vec![Field::with_name(DataType::Int64, "ts")]);
+        assert_eq!(
+            left.merge_dedup(&other, "_right").names(),
+            vec!["id", "name", "ts"]
+        );
+
+        // The suffix is appended repeatedly until the name is unique.
+        let tricky = Schema::new(vec![
+            Field::with_name(DataType::Int32, "id"),
+            Field::with_name(DataType::Int32, "id_right"),
+        ]);
+        assert_eq!(
+            tricky.merge_dedup(&right, "_right").names(),
+            vec!["id", "id_right", "id_right_right", "score"]
+        );
+
+        // An empty suffix falls back to numbered suffixes instead of looping forever.
+        assert_eq!(
+            left.merge_dedup(&right, "").names(),
+            vec!["id", "name", "id_1", "score"]
+        );
+    }
+
+    #[test]
+    fn test_assert_compatible_with() {
+        let schema = |ty: DataType| Schema::new(vec![Field::with_name(ty, "v")]);
+
+        // `Exact` requires equal types.
+        assert!(
+            schema(DataType::Int16)
+                .assert_compatible_with(&schema(DataType::Int16), CompatibilityMode::Exact)
+                .is_ok()
+        );
+        assert!(
+            schema(DataType::Int16)
+                .assert_compatible_with(&schema(DataType::Int32), CompatibilityMode::Exact)
+                .is_err()
+        );
+
+        // `Widening` accepts lossless upcasts but rejects narrowing.
+        assert!(
+            schema(DataType::Int16)
+                .assert_compatible_with(&schema(DataType::Int32), CompatibilityMode::Widening)
+                .is_ok()
+        );
+        assert!(
+            schema(DataType::Float32)
+                .assert_compatible_with(&schema(DataType::Float64), CompatibilityMode::Widening)
+                .is_ok()
+        );
+        assert!(
+            schema(DataType::Int64)
+                .assert_compatible_with(&schema(DataType::Decimal), CompatibilityMode::Widening)
+                .is_ok()
+        );
+        assert!(matches!(
+            schema(DataType::Int64)
+                .assert_compatible_with(&schema(DataType::Int32), CompatibilityMode::Widening),
+            Err(SchemaError::TypeMismatch { .. })
+        ));
+
+        // `AnyCast` only checks the shape.
+        assert!(
+            schema(DataType::Int16)
+                .assert_compatible_with(&schema(DataType::Int32), CompatibilityMode::AnyCast)
+                .is_ok()
+        );
+        assert!(
+            schema(DataType::Int64)
+                .assert_compatible_with(&schema(DataType::Int32), CompatibilityMode::AnyCast)
+                .is_ok()
+        );
+        assert!(matches!(
+            schema(DataType::Int32).assert_compatible_with(
+                &Schema::new(vec![Field::with_name(DataType::Int32, "other")]),
+                CompatibilityMode::AnyCast
+            ),
+            Err(SchemaError::ColumnNotFound { .. })
+        ));
+        assert!(matches!(
+            Schema::empty()
+                .assert_compatible_with(&schema(DataType::Int32), CompatibilityMode::AnyCast),
+            Err(SchemaError::FieldCountMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn test_create_array_builders_with_hints() {
+        let schema = Schema::new(vec![
+            Field::with_name(DataType::Int32, "id"),
+            Field::with_name(DataType::Varchar, "name"),
+            Field::with_name(DataType::Bytea, "payload"),
+        ]);
+        // Hints for fixed-length columns (0) and out-of-range indices (7) are ignored.
+        let hints = HashMap::from([(0, 1024), (1, 1024), (2, 4096), (7, 1024)]);
+        let mut builders = schema.create_array_builders_with_hints(16, &hints);
+        assert_eq!(builders.len(), 3);
+
+        // The hinted builders must still behave like freshly created ones.
+        for builder in &mut builders {
+            builder.append_null();
+        }
+        builders[1].append(Some(crate::types::ScalarImpl::Utf8("hello".into())));
+        assert_eq!(builders[0].len(), 1);
+        assert_eq!(builders[1].len(), 2);
+        assert_eq!(builders[2].len(), 1);
+    }
+
+    #[test]
+    fn test_column_positions() {
+        let schema = Schema::new(vec![
+            Field::with_name(DataType::Int32, "a"),
+            Field::with_name(DataType::Varchar, "b"),
+            Field::with_name(DataType::Float64, "c"),
+        ]);
+
+        // Positions come back in the requested order, and duplicates are allowed.
+        assert_eq!(schema.column_positions(&["c", "a"]).unwrap(), vec![2, 0]);
+        assert_eq!(
+            schema.column_positions(&["a", "a", "b"]).unwrap(),
+            vec![0, 0, 1]
+        );
+        assert_eq!(schema.column_positions(&[]).unwrap(), Vec::<usize>::new());
+
+        // The first missing name is reported.
+        let err = schema.column_positions(&["a", "d", "e"]).unwrap_err();
+        let SchemaError::ColumnNotFound { name } = err else {
+            panic!("expected ColumnNotFound, got {err:?}");
+        };
+        assert_eq!(name, "d");
+    }
+
+    #[test]
+    fn test_validate_foreign_keys() {
+        let schema = Schema::new(vec![
+            Field::with_name(DataType::Int32, "id"),
+            Field::with_name(DataType::Int32, "user_id").with_foreign_key("users(id)"),
+            Field::with_name(DataType::Int32, "order_id").with_foreign_key("orders(id, seq)"),
+        ]);
+
+        // A resolver that knows all referenced columns.
+        assert!(
+            schema
+                .validate_foreign_keys(|table, column| {
+                    matches!(
+                        (table, column),
+                        ("users", "id") | ("orders", "id") | ("orders", "seq")
+                    )
+                })
+                .is_ok()
+        );
+
+        // All dangling references are reported at once.
+        let err = schema
+            .validate_foreign_keys(|table, column| table == "users" && column == "id")
+            .unwrap_err();
+        let SchemaError::DanglingForeignKey { refs } = err else {
+            panic!("expected DanglingForeignKey, got {err:?}");
+        };
+        assert_eq!(
+            refs,
+            vec!["order_id -> orders(id)", "order_id -> orders(seq)"]
+        );
+
+        // Malformed definitions fail fast.
+        let schema = Schema::new(vec![
+            Field::with_name(DataType::Int32, "bad").with_foreign_key("users(id"),
+        ]);
+        assert!(matches!(
+            schema.validate_foreign_keys(|_, _| true),
+            Err(SchemaError::InvalidForeignKey { .. })
+        ));
+    }
+
+    #[test]
+    fn test_is_name_subset_of() {
+        let base = Schema::new(vec![
+            Field::with_name(DataType::Int32, "a"),
+            Field::with_name(DataType::Varchar, "b"),
+            Field::with_name(DataType::Float64, "c"),
+        ]);
+
+        // A subset matches regardless of order.
+        let subset = Schema::new(vec![
+            Field::with_name(DataType::Varchar, "b"),
+            Field::with_name(DataType::Int32, "a"),
+        ]);
+        assert!(subset.is_name_subset_of(&base));
+        assert!(subset.missing_columns(&base).is_empty());
+
+        // A superset is not a subset; the extra column is reported.
+        let superset = Schema::new(vec![
+            Field::with_name(DataType::Int32, "a"),
+            Field::with_name(DataType::Varchar, "b"),
+            Field::with_name(DataType::Float64, "c"),
+            Field::with_name(DataType::Boolean, "d"),
+        ]);
+        assert!(!superset.is_name_subset_of(&base));
+        let missing = superset.missing_columns(&base);
+        assert_eq!(missing.len(), 1);
+        assert_eq!(missing[0].name, "d");
+
+        // A column with a mismatched type is also reported as missing.
+        let mismatch = Schema::new(vec![Field::with_name(DataType::Varchar, "a")]);
+        assert!(!mismatch.is_name_subset_of(&base));
+        let missing = mismatch.missing_columns(&base);
+        assert_eq!(missing.len(), 1);
+        assert_eq!(missing[0].name, "a");
+    }
+
+    #[test]
+    fn test_all_text() {
+        let schema = Schema::new(vec![
+            Field::with_name(DataType::Int32, "id").with_foreign_key("users(id)"),
+            Field::with_name(DataType::Varchar, "name"),
+            Field::with_name(DataType::Timestamptz, "created_at"),
+            Field::with_name(DataType::Jsonb, "payload"),
+            Field::with_name(DataType::Bytea, "raw"),
+            Field::with_name(DataType::list(DataType::Int32), "tags"),
+        ]);
+
+        let text_schema = schema.all_text();
+        assert_eq!(text_schema.names(), schema.names());
+        assert!(
+            text_schema
+                .fields
+                .iter()
+                .all(|f| f.data_type == DataType::Varchar)
+        );
+        // Annotations are preserved.
+        assert_eq!(
+            text_schema.fields[0].foreign_key.as_deref(),
+            Some("users(id)")
+        );
+
+        assert_eq!(
+            schema.text_format_descriptors(),
+            vec![
+                TextFormatDescriptor::Plain,
+                TextFormatDescriptor::Identity,
+                TextFormatDescriptor::DateTime,
+                TextFormatDescriptor::Json,
+                TextFormatDescriptor::Hex,
+                TextFormatDescriptor::Composite,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_to_prost_with() {
+        let schema = Schema::new(vec![
+            Field::with_name(DataType::Int32, "id")
+                .with_foreign_key("users(id)")
+                .with_description("user id"),
+            Field::with_name(DataType::Varchar, "name"),
+        ]);
+
+        // By default, informational metadata is not serialized.
+        let prost = schema.to_prost();
+        assert_eq!(prost, schema.to_prost_with(ProstOptions::default()));
+        assert!(prost.iter().all(|f| f.description.is_none()));
+        assert!(prost.iter().all(|f| f.foreign_key.is_none()));
+
+        // When included, metadata round-trips through `from_prost`.
+        let prost = schema.to_prost_with(ProstOptions {
+            include_descriptions: true,
+            include_foreign_keys: true,
+            include_tags: false,
+        });
+        assert_eq!(prost[0].description.as_deref(), Some("user id"));
+        assert_eq!(prost[0].foreign_key.as_deref(), Some("users(id)"));
+        let round_tripped: Vec<Field> = prost.iter().map(Field::from_prost).collect();
+        assert_eq!(round_tripped, schema.fields);
+        assert_eq!(round_tripped[0].description.as_deref(), Some("user id"));
+        assert_eq!(round_tripped[0].foreign_key.as_deref(), Some("users(id)"));
+    }
+
+    #[test]
+    fn test_target_byte_width() {
+        use crate::catalog::{MySqlDialect, PostgresDialect};
+
+        let cases = [
+            (DataType::Int16, Some(2)),
+            (DataType::Int32, Some(4)),
+            (DataType::Int64, Some(8)),
+            (DataType::Float64, Some(8)),
+            (DataType::Date, Some(4)),
+            // Unbounded text and nested types have no fixed width.
+            (DataType::Varchar, None),
+            (DataType::Bytea, None),
+            (DataType::list(DataType::Int32), None),
+        ];
+        for (ty, expected) in cases {
+            let field = Field::with_name(ty, "f");
+            assert_eq!(field.target_byte_width(&PostgresDialect), expected);
+        }
+
+        // MySQL renders intervals as `TEXT`, so they have no fixed width there.
+        let field = Field::with_name(DataType::Interval, "f");
+        assert_eq!(field.target_byte_width(&PostgresDialect), Some(16));
+        assert_eq!(field.target_byte_width(&MySqlDialect), None);
+    }
+
+    #[test]
+    fn test_single_field() {
+        let field = Field::with_name(DataType::Int32, "v");
+        let schema = Schema::new(vec![field.clone()]);
+        assert_eq!(schema.single_field().unwrap(), &field);
+
+        assert!(matches!(
+            Schema::empty().single_field(),
+            Err(SchemaError::NotSingleColumn { actual: 0 })
+        ));
+        let schema = Schema::new(vec![field.clone(), field]);
+        assert!(matches!(
+            schema.single_field(),
+            Err(SchemaError::NotSingleColumn { actual: 2 })
+        ));
+    }
+
+    #[test]
+    fn test_single_column_data_type() {
+        let struct_type = DataType::Struct(StructType::new(vec![
+            ("k", DataType::Varchar),
+            ("v", DataType::Int64),
+        ]));
+        let schema = Schema::new(vec![Field::with_name(struct_type.clone(), "payload")]);
+        assert_eq!(schema.single_column_data_type().unwrap(), &struct_type);
+
+        let schema = Schema::new(vec![
+            Field::with_name(struct_type, "payload"),
+            Field::with_name(DataType::Int32, "extra"),
+        ]);
+        assert!(matches!(
+            schema.single_column_data_type(),
+            Err(SchemaError::NotSingleColumn { actual: 2 })
+        ));
+    }
+
+    #[test]
+    fn test_sensitive_columns() {
+        let schema = Schema::new(vec![
+            Field::with_name(DataType::Int64, "id"),
+            Field::with_name(DataType::Varchar, "email")
+                .with_tags(["pii"])
+                .with_masking_policy("mask_email"),
+            Field::with_name(DataType::Varchar, "ssn").with_tags(["pii", "sensitive"]),
+        ]);
+
+        assert!(!schema.fields[0].is_sensitive());
+        assert!(schema.fields[1].is_sensitive());
+        assert!(schema.fields[2].is_sensitive());
+        assert_eq!(schema.sensitive_columns(), vec![1, 2]);
+        assert_eq!(schema.sensitive_column_names(), vec!["email", "ssn"]);
+
+        // By default, tags and the masking policy are not serialized.
+        let pb = schema.fields[1].to_prost();
+        assert!(pb.tags.is_empty());
+        assert!(pb.masking_policy.is_none());
+
+        // When included, they round-trip through `from_prost`.
+        let pb = schema.fields[1].to_prost_with(ProstOptions {
+            include_tags: true,
+            ..Default::default()
+        });
+        assert_eq!(pb.tags, vec!["pii".to_owned()]);
+        assert_eq!(pb.masking_policy.as_deref(), Some("mask_email"));
+        let field = Field::from_prost(&pb);
+        assert_eq!(field.tags, vec!["pii".to_owned()]);
+        assert_eq!(field.masking_policy.as_deref(), Some("mask_email"));
+    }
+
+    #[test]
+    fn test_apply_aliases() {
+        let schema = Schema::new(vec![
+            Field::with_name(DataType::Int32, "a"),
+            Field::with_name(DataType::Varchar, "b"),
+            Field::with_name(DataType::Timestamptz, "c"),
+        ])
+        .with_watermark_columns(vec!["c".to_owned()])
+        .unwrap();
+
+        // `Some` overrides the name, `None` keeps it.
+        let aliased = schema
+            .apply_aliases(&[Some("renamed_a".to_owned()), None, Some("ts".to_owned())])
+            .unwrap();
+        assert_eq!(aliased.names(), vec!["renamed_a", "b", "ts"]);
+        // Data types are untouched, and schema-level name lists follow the rename.
+        assert_eq!(aliased.data_types(), schema.data_types());
+        assert_eq!(aliased.watermark_columns, vec!["ts".to_owned()]);
+        assert_eq!(aliased.watermark_column_indices(), vec![2]);
+
+        // The alias count must match the column count.
+        assert!(matches!(
+            schema.apply_aliases(&[None, None]),
+            Err(SchemaError::AliasCountMismatch {
+                expected: 3,
+                actual: 2,
+            })
+        ));
+    }
+
+    #[test]
+    fn test_type_histogram() {
+        let schema = Schema::new(vec![
+            Field::with_name(DataType::Int32, "a"),
+            Field::with_name(DataType::Varchar, "b"),
+            Field::with_name(DataType::Varchar, "c"),
+            Field::with_name(DataType::list(DataType::Int32), "d"),
+            Field::with_name(
+                DataType::Struct(StructType::new(vec![("x", DataType::Int64)])),
+                "e",
+            ),
+        ]);
+
+        let histogram = schema.type_histogram();
+        assert_eq!(histogram[&DataTypeName::Int32], 1);
+        assert_eq!(histogram[&DataTypeName::Varchar], 2);
+        // Type parameters are ignored; only the kind is counted.
+        assert_eq!(histogram[&DataTypeName::List], 1);
+        assert_eq!(histogram[&DataTypeName::Struct], 1);
+        // Absent kinds are not in the map.
+        assert!(!histogram.contains_key(&DataTypeName::Boolean));
+
+        assert!(Schema::empty().type_histogram().is_empty());
+    }
+
+    #[test]
+    fn test_same_logical_type() {
+        // Decimals carry no precision in RisingWave, so any two decimal fields are both
+        // exactly equal in type and of the same logical type.
+        let a = Field::with_name(DataType::Decimal, "a");
+        let b = Field::with_name(DataType::Decimal, "b");
+        assert_eq!(a.data_type, b.data_type);
+        assert!(a.same_logical_type(&b));
+
+        // Parameterized types may differ in exact type but still share the logical kind.
+        let xs = Field::with_name(DataType::list(DataType::Int32), "xs");
+        let ys = Field::with_name(DataType::list(DataType::Varchar), "ys");
+        assert_ne!(xs.data_type, ys.data_type);
+        assert!(xs.same_logical_type(&ys));
+
+        let s1 = Field::with_name(
+            DataType::Struct(StructType::new(vec![("x", DataType::Int64)])),
+            "s1",
+        );
+        let s2 = Field::with_name(
+            DataType::Struct(StructType::new(vec![("y", DataType::Varchar)])),
+            "s2",
+        );
+        assert_ne!(s1.data_type, s2.data_type);
+        assert!(s1.same_logical_type(&s2));
+
+        // Different kinds are never the same logical type.
+        assert!(!a.same_logical_type(&xs));
+        assert!(!Field::with_name(DataType::Int32, "i").same_logical_type(&a));
+    }
+
+    #[test]
+    fn test_rename_field_checked() {
+        let mut schema = Schema::new(vec![
+            Field::with_name(DataType::Int32, "a"),
+            Field::with_name(DataType::Varchar, "b"),
+        ])
+        .with_primary_key(vec!["a".to_owned()], true)
+        .unwrap();
+
+        // A successful rename also renames the column in the primary-key list.
+        schema.rename_field_checked(0, "id").unwrap();
+        assert_eq!(schema.names(), vec!["id", "b"]);
+        assert_eq!(schema.primary_key.as_deref(), Some(&["id".to_owned()][..]));
+
+        // Renaming to the name of another column is rejected and leaves the schema
+        // untouched.
+        assert!(matches!(
+            schema.rename_field_checked(1, "id"),
+            Err(SchemaError::DuplicateColumn { name }) if name == "id"
+        ));
+        assert_eq!(schema.names(), vec!["id", "b"]);
+
+        // Renaming a column to its current name is a no-op.
+        schema.rename_field_checked(1, "b").unwrap();
+        assert_eq!(schema.names(), vec!["id", "b"]);
+    }
+
+    #[test]
+    fn test_lineage() {
+        let orders_id = ColumnSourceRef {
+            relation: "orders".to_owned(),
+            column: "id".to_owned(),
+        };
+        let orders_amount = ColumnSourceRef {
+            relation: "orders".to_owned(),
+            column: "amount".to_owned(),
+        };
+        let mut schema = Schema::new(vec![
+            Field::with_name(DataType::Int64, "id").with_source_ref("orders", "id"),
+            Field::with_name(DataType::Decimal, "amount").with_source_ref("orders", "amount"),
+            // A computed column has no source to point back to.
+            Field::with_name(DataType::Decimal, "amount_with_tax"),
+        ]);
+        assert_eq!(
+            schema.lineage(),
+            vec![Some(&orders_id), Some(&orders_amount), None]
+        );
+
+        // Renaming a derived column keeps its lineage.
+        schema.rename_field_checked(0, "order_id").unwrap();
+        assert_eq!(schema.fields[0].source_ref.as_ref(), Some(&orders_id));
+
+        // Projection carries lineage through, reordered along with the columns.
+        let projected = schema.project(&[2, 0]);
+        assert_eq!(projected.names(), vec!["amount_with_tax", "order_id"]);
+        assert_eq!(projected.lineage(), vec![None, Some(&orders_id)]);
+    }
+
+    #[test]
+    fn test_rename_with_regex() {
+        let schema = Schema::new(vec![
+            Field::with_name(DataType::Int32, "src_id"),
+            Field::with_name(DataType::Varchar, "src_name"),
+            Field::with_name(DataType::Timestamptz, "ts"),
+        ])
+        .with_primary_key(vec!["src_id".to_owned()], true)
+        .unwrap();
+
+        // Strip the `src_` prefix; untouched names are kept and the primary-key list
+        // follows the rename.
+        let renamed = schema.rename_with_regex("^src_", "").unwrap();
+        assert_eq!(renamed.names(), vec!["id", "name", "ts"]);
+        assert_eq!(renamed.primary_key.as_deref(), Some(&["id".to_owned()][..]));
+        assert_eq!(renamed.data_types(), schema.data_types());
+
+        // A substitution that makes two names collide is rejected.
+        assert!(matches!(
+            schema.rename_with_regex("^src_.*", "col"),
+            Err(SchemaError::DuplicateColumn { name }) if name == "col"
+        ));
+
+        // An invalid pattern is reported as such.
+        assert!(matches!(
+            schema.rename_with_regex("(src_", ""),
+            Err(SchemaError::InvalidRegex { .. })
+        ));
+    }
+
+    #[test]
+    fn test_rename_struct_subfield() {
+        let addr = || {
+            DataType::Struct(StructType::new(vec![
+                ("street", DataType::Varchar),
+                ("zip", DataType::Varchar),
+            ]))
+        };
+        let mut schema = Schema::new(vec![
+            Field::with_name(DataType::Int32, "id"),
+            Field::with_name(addr(), "addr"),
+        ]);
+
+        schema
+            .rename_struct_subfield("addr", "zip", "postal_code")
+            .unwrap();
+        assert_eq!(
+            schema.fields[1].data_type,
+            DataType::Struct(StructType::new(vec![
+                ("street", DataType::Varchar),
+                ("postal_code", DataType::Varchar),
+            ]))
+        );
+
+        // Unknown columns and subfields, non-struct columns and colliding subfield names
+        // are rejected.
+        assert!(matches!(
+            schema.rename_struct_subfield("address", "zip", "postal_code"),
+            Err(SchemaError::ColumnNotFound { name }) if name == "address"
+        ));
+        assert!(matches!(
+            schema.rename_struct_subfield("addr", "zip", "postal_code"),
+            Err(SchemaError::ColumnNotFound { name }) if name == "addr.zip"
+        ));
+        assert!(matches!(
+            schema.rename_struct_subfield("id", "zip", "postal_code"),
+            Err(SchemaError::NotAStruct { name, actual }) if name == "id" && actual == DataType::Int32
+        ));
+        assert!(matches!(
+            schema.rename_struct_subfield("addr", "street", "postal_code"),
+            Err(SchemaError::DuplicateColumn { name }) if name == "addr.postal_code"
+        ));
+    }
+
+    #[test]
+    fn test_coerce_names_for() {
+        use crate::catalog::{PostgresDialect, SqlDialect};
+
+        /// An Oracle-like dialect: identifiers are at most 30 characters and folded to
+        /// upper case.
+        struct OracleLikeDialect;
+
+        impl SqlDialect for OracleLikeDialect {
+            fn name(&self) -> &'static str {
+                "oracle-like"
+            }
+
+            fn scalar_type_name(&self, data_type: &DataType) -> String {
+                data_type.to_string()
+            }
+
+            fn max_identifier_length(&self) -> Option<usize> {
+                Some(30)
+            }
+
+            fn uppercase_identifiers(&self) -> bool {
+                true
+            }
+        }
+
+        let schema = Schema::new(vec![
+            Field::with_name(DataType::Int32, "id"),
+            Field::with_name(DataType::Varchar, "a_rather_long_descriptive_column_name"),
+        ])
+        .with_primary_key(vec!["id".to_owned()], true)
+        .unwrap();
+
+        assert_eq!(
+            schema.fields[1].coerced_name(&OracleLikeDialect),
+            "A_RATHER_LONG_DESCRIPTIVE_COLU"
+        );
+
+        // Postgres imposes no rules here, so coercion is a no-op.
+        let coerced = schema.coerce_names_for(&PostgresDialect).unwrap();
+        assert_eq!(coerced.names(), schema.names());
+
+        // Coerced names follow into the schema-level name lists.
+        let coerced = schema.coerce_names_for(&OracleLikeDialect).unwrap();
+        assert_eq!(
+            coerced.names(),
+            vec!["ID", "A_RATHER_LONG_DESCRIPTIVE_COLU"]
+        );
+        assert_eq!(coerced.primary_key.as_deref(), Some(&["ID".to_owned()][..]));
+
+        // Two names identical up to the 30th character collide after truncation.
+        let colliding = Schema::new(vec![
+            Field::with_name(DataType::Int32, "a_rather_long_descriptive_column_name"),
+            Field::with_name(DataType::Varchar, "a_rather_long_descriptive_column_title"),
+        ]);
+        assert!(matches!(
+            colliding.coerce_names_for(&OracleLikeDialect),
+            Err(SchemaError::DuplicateColumn { name }) if name == "A_RATHER_LONG_DESCRIPTIVE_COLU"
+        ));
+    }
+
+    #[test]
+    fn test_has_unique_names() {
+        let schema = Schema::new(vec![
+            Field::with_name(DataType::Int32, "a"),
+            Field::with_name(DataType::Varchar, "b"),
+        ]);
+        assert!(schema.has_unique_names());
+
+        let duplicated = Schema::new(vec![
+            Field::with_name(DataType::Int32, "a"),
+            Field::with_name(DataType::Varchar, "a"),
+        ]);
+        assert!(!duplicated.has_unique_names());
+
+        // Unnamed fields do not count as duplicates.
+        let unnamed = Schema::new(vec![
+            Field::unnamed(DataType::Int32),
+            Field::unnamed(DataType::Varchar),
+        ]);
+        assert!(unnamed.has_unique_names());
+    }
+
+    #[test]
+    fn test_equals_by_name() {
+        let schema = Schema::new(vec![
+            Field::with_name(DataType::Int32, "a"),
+            Field::with_name(DataType::Varchar, "b"),
+        ]);
+        let reordered = Schema::new(vec![
+            Field::with_name(DataType::Varchar, "b"),
+            Field::with_name(DataType::Int32, "a"),
+        ]);
+        let mismatched = Schema::new(vec![
+            Field::with_name(DataType::Int64, "a"),
+            Field::with_name(DataType::Varchar, "b"),
+        ]);
+        let extra = Schema::new(vec![
+            Field::with_name(DataType::Int32, "a"),
+            Field::with_name(DataType::Varchar, "b"),
+            Field::with_name(DataType::Varchar, "c"),
+        ]);
+
+        assert!(schema.equals_by_name(&reordered));
+        // Types of same-named columns must match.
+        assert!(!schema.equals_by_name(&mismatched));
+        // Unmatched columns on either side fail the comparison.
+        assert!(!schema.equals_by_name(&extra));
+        assert!(!extra.equals_by_name(&schema));
+
+        let map = schema.name_type_map();
+        assert_eq!(map["a"], &DataType::Int32);
+        assert_eq!(map["b"], &DataType::Varchar);
+    }
+
+    #[test]
+    fn test_from_struct_with_nullability() {
+        let struct_type =
+            StructType::new(vec![("id", DataType::Int32), ("name", DataType::Varchar)]);
+
+        // The plain conversion defaults every field to nullable.
+        let schema = Schema::from(&struct_type);
+        assert!(schema.fields.iter().all(|f| f.nullable));
+
+        // Caller-supplied nullability survives the conversion.
+        let schema = Schema::from_struct_with_nullability(&struct_type, &[false, true]);
+        assert_eq!(schema.names(), vec!["id", "name"]);
+        assert!(!schema.fields[0].nullable);
+        assert!(schema.fields[1].nullable);
+    }
+
+    #[test]
+    fn test_truncate_to_and_pad_to() {
+        let orders = Schema::new(vec![
+            Field::with_name(DataType::Int64, "id"),
+            Field::with_name(DataType::Varchar, "customer"),
+            Field::with_name(DataType::Decimal, "amount"),
+            Field::with_name(DataType::Timestamptz, "created_at"),
+        ]);
+
+        // Truncation keeps the first `n` columns and drops the rest.
+        let truncated = orders.truncate_to(2);
+        assert_eq!(truncated.names(), vec!["id", "customer"]);
+        // Truncating to the current width or wider is a no-op.
+        assert_eq!(orders.truncate_to(4), orders);
+
+        // Padding appends placeholder columns named after their index.
+        let padded = truncated.pad_to(4, DataType::Varchar, "_pad_");
+        assert_eq!(padded.names(), vec!["id", "customer", "_pad_2", "_pad_3"]);
+        assert_eq!(padded.fields[2].data_type, DataType::Varchar);
+        // Padding to the current width or narrower is a no-op.
+        assert_eq!(padded.pad_to(3, DataType::Varchar, "_pad_"), padded);
+    }
+
+    #[test]
+    fn test_split_key_value() {
+        let schema = Schema::new(vec![
+            Field::with_name(DataType::Int32, "a"),
+            Field::with_name(DataType::Varchar, "b"),
+            Field::with_name(DataType::Int64, "c"),
+            Field::with_name(DataType::Float64, "d"),
+        ]);
+
+        // The key schema follows the given order, the value schema keeps field order.
+        let (key, value) = schema.split_key_value(&[2, 0]);
+        assert_eq!(key.names(), vec!["c", "a"]);
+        assert_eq!(value.names(), vec!["b", "d"]);
+
+        // An empty key puts everything in the value part.
+        let (key, value) = schema.split_key_value(&[]);
+        assert!(key.is_empty());
+        assert_eq!(value.names(), schema.names());
+    }
+
+    #[test]
+    #[should_panic(expected = "duplicate key index")]
+    fn test_split_key_value_duplicate_index() {
+        let schema = Schema::new(vec![
+            Field::with_name(DataType::Int32, "a"),
+            Field::with_name(DataType::Varchar, "b"),
+        ]);
+        schema.split_key_value(&[0, 0]);
+    }
+
+    #[test]
+    fn test_from_kafka_connect_schema() {
+        let json = serde_json::json!({
+            "type": "struct",
+            "optional": false,
+            "name": "dbserver1.inventory.orders.Value",
+            "fields": [
+                {
+                    "type": "int32",
+                    "optional": false,
+                    "field": "id",
+                    "doc": "order identifier",
+                },
+                {
+                    "type": "int64",
+                    "optional": true,
+                    "name": "org.apache.kafka.connect.data.Timestamp",
+                    "field": "created_at",
+                },
+                {
+                    "type": "struct",
+                    "optional": true,
+                    "field": "address",
+                    "fields": [
+                        {"type": "string", "optional": false, "field": "city"},
+                        {"type": "string", "optional": true, "field": "zipcode"},
+                    ],
+                },
+            ],
+        });
+        let schema = Schema::from_kafka_connect_schema(&json).unwrap();
+        assert_eq!(schema.names(), vec!["id", "created_at", "address"]);
+
+        assert_eq!(schema.fields[0].data_type, DataType::Int32);
+        assert!(!schema.fields[0].nullable);
+        assert_eq!(
+            schema.fields[0].description.as_deref(),
+            Some("order identifier")
+        );
+
+        // The logical timestamp type takes precedence over its `int64` encoding.
+        assert_eq!(schema.fields[1].data_type, DataType::Timestamp);
+        assert!(schema.fields[1].nullable);
+
+        assert_eq!(
+            schema.fields[2].data_type,
+            DataType::Struct(StructType::new(vec![
+                ("city", DataType::Varchar),
+                ("zipcode", DataType::Varchar),
+            ]))
+        );
+
+        // Non-struct top level is rejected.
+        assert!(matches!(
+            Schema::from_kafka_connect_schema(&serde_json::json!({"type": "int32"})),
+            Err(SchemaError::InvalidConnectSchema { .. })
+        ));
+    }
+
+    #[test]
+    fn test_validate_no_reserved_names() {
+        use crate::catalog::{ROW_ID_COLUMN_NAME, RW_TIMESTAMP_COLUMN_NAME};
+
+        let reserved = [ROW_ID_COLUMN_NAME, RW_TIMESTAMP_COLUMN_NAME];
+
+        let schema = Schema::new(vec![
+            Field::with_name(DataType::Int32, "id"),
+            Field::with_name(DataType::Varchar, "name"),
+        ]);
+        assert!(schema.validate_no_reserved_names(&reserved).is_ok());
+
+        let schema = Schema::new(vec![
+            Field::with_name(DataType::Serial, ROW_ID_COLUMN_NAME),
+            Field::with_name(DataType::Varchar, "name"),
+        ]);
+        assert!(matches!(
+            schema.validate_no_reserved_names(&reserved),
+            Err(SchemaError::ReservedColumn { names }) if names == vec![ROW_ID_COLUMN_NAME]
+        ));
+    }
+
+    #[test]
+    fn test_validate_max_columns() {
+        let schema = Schema::new(vec![
+            Field::with_name(DataType::Int32, "a"),
+            Field::with_name(DataType::Varchar, "b"),
+            Field::with_name(DataType::Int32, "c"),
+        ]);
+
+        // Under and at the limit are fine; only exceeding it is rejected.
+        assert!(schema.validate_max_columns(4).is_ok());
+        assert!(schema.validate_max_columns(3).is_ok());
+        assert!(matches!(
+            schema.validate_max_columns(2),
+            Err(SchemaError::TooManyColumns {
+                actual: 3,
+                limit: 2
+            })
+        ));
+    }
+
+    #[test]
+    fn test_with_capacity() {
+        let mut schema = Schema::with_capacity(3);
+        assert!(schema.is_empty());
+        assert!(schema.fields.capacity() >= 3);
+
+        schema.push_field(Field::with_name(DataType::Int32, "a"));
+        schema.push_field(Field::with_name(DataType::Varchar, "b"));
+        schema.push_field(Field::with_name(DataType::Int32, "c"));
+
+        // Pushing up to the reserved capacity does not reallocate.
+        assert!(schema.fields.capacity() >= 3);
+        assert_eq!(
+            schema,
+            Schema::new(vec![
+                Field::with_name(DataType::Int32, "a"),
+                Field::with_name(DataType::Varchar, "b"),
+                Field::with_name(DataType::Int32, "c"),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_stripped() {
+        let schema = Schema::new(vec![
+            Field::with_name(DataType::Int32, "id")
+                .with_is_primary_key(true)
+                .with_nullable(false)
+                .with_description("primary key"),
+            Field::with_name(DataType::Int32, "other_id").with_foreign_key("other(id)"),
+        ])
+        .with_description("a relation")
+        .with_metadata_comment("{}")
+        .with_watermark_columns(vec!["id".to_owned()])
+        .unwrap()
+        .with_primary_key(vec!["id".to_owned()], true)
+        .unwrap();
+
+        let stripped = schema.stripped();
+        // Metadata is ignored for equality, so the stripped schema still compares
+        // equal to the original.
+        assert_eq!(stripped, schema);
+        // ...but carries no metadata at all.
+        assert_eq!(stripped.description, None);
+        assert_eq!(stripped.metadata_comment, None);
+        assert!(stripped.watermark_columns.is_empty());
+        assert_eq!(stripped.primary_key, None);
+        for field in stripped.fields() {
+            assert_eq!(field.foreign_key, None);
+            assert_eq!(field.description, None);
+            assert!(!field.is_primary_key);
+            assert!(field.nullable);
+        }
+        // Names and data types are preserved.
+        assert_eq!(stripped.names(), schema.names());
+        assert_eq!(stripped.data_types(), schema.data_types());
+    }
+
+    #[test]
+    fn test_to_graphql_type() {
+        let schema = Schema::new(vec![
+            Field::with_name(DataType::Int64, "order_id")
+                .with_nullable(false)
+                .with_description("Unique order identifier."),
+            Field::with_name(
+                DataType::Struct(StructType::new(vec![
+                    ("name", DataType::Varchar),
+                    ("address", DataType::Varchar),
+                ])),
+                "customer",
+            )
+            .with_description("The customer who placed the order."),
+            Field::with_name(DataType::Decimal, "price").with_nullable(false),
+            Field::with_name(DataType::Varchar.list(), "tags"),
+            Field::with_name(DataType::Timestamptz, "created_at"),
+        ])
+        .with_description("An order placed by a customer.");
+
+        let expected = r#""""An order placed by a customer."""
+type Order {
+  """Unique order identifier."""
+  order_id: BigInt!
+  """The customer who placed the order."""
+  customer: OrderCustomer
+  price: Decimal!
+  tags: [String]
+  created_at: DateTime
+}
+
+type OrderCustomer {
+  name: String
+  address: String
+}
+"#;
+        assert_eq!(schema.to_graphql_type("Order"), expected);
+    }
+
+    #[test]
+    fn test_to_clickhouse_ddl() {
+        let schema = Schema::new(vec![
+            Field::with_name(DataType::Int64, "id").with_nullable(false),
+            Field::with_name(DataType::Varchar, "country").with_tags(["low_cardinality"]),
+            Field::with_name(DataType::Decimal, "price"),
+            Field::with_name(DataType::Varchar.list(), "tags").with_nullable(false),
+        ])
+        .with_primary_key(vec!["id".to_owned()], true)
+        .unwrap();
+
+        let expected = r#"CREATE TABLE `orders` (
+    `id` Int64,
+    `country` LowCardinality(Nullable(String)),
+    `price` Nullable(Decimal(38, 18)),
+    `tags` Array(String)
+) ENGINE = MergeTree
+ORDER BY (`id`)"#;
+        assert_eq!(schema.to_clickhouse_ddl("orders", "MergeTree"), expected);
+
+        // Without a primary key, the `ORDER BY` clause falls back to `tuple()`.
+        let schema = Schema::new(vec![Field::with_name(DataType::Int32, "v")]);
+        assert!(
+            schema
+                .to_clickhouse_ddl("t", "MergeTree")
+                .ends_with("ORDER BY tuple()")
+        );
+    }
+
+    #[test]
+    fn test_to_parquet_message_type() {
+        let schema = Schema::new(vec![
+            Field::with_name(DataType::Int64, "id").with_nullable(false),
+            Field::with_name(DataType::Varchar, "name"),
+            Field::with_name(DataType::Decimal, "amount"),
+            Field::with_name(DataType::Timestamp, "created_at"),
+            Field::with_name(DataType::Varchar.list(), "tags"),
+        ]);
+
+        let expected = r#"message orders {
+  required int64 id;
+  optional binary name (UTF8);
+  optional fixed_len_byte_array(16) amount (DECIMAL(38,18));
+  optional int64 created_at (TIMESTAMP_MICROS);
+  optional group tags (LIST) {
+    repeated group list {
+      optional binary element (UTF8);
+    }
+  }
+}"#;
+        assert_eq!(schema.to_parquet_message_type("orders"), expected);
+    }
+
+    #[test]
+    fn test_comment_statements() {
+        use crate::catalog::PostgresDialect;
+
+        let schema = Schema::new(vec![
+            Field::with_name(DataType::Int64, "id").with_description("the order's id"),
+            Field::with_name(DataType::Varchar, "name"),
+            Field::with_name(DataType::Decimal, "price").with_description("in \"minor\" units"),
+        ])
+        .with_description("customers' orders");
+
+        // The table comment comes first; fields without a description are skipped.
+        // Embedded single quotes are doubled and identifier quotes are escaped.
+        assert_eq!(
+            schema.comment_statements("orders", &PostgresDialect),
+            vec![
+                "COMMENT ON TABLE \"orders\" IS 'customers'' orders'",
+                "COMMENT ON COLUMN \"orders\".\"id\" IS 'the order''s id'",
+                "COMMENT ON COLUMN \"orders\".\"price\" IS 'in \"minor\" units'",
+            ]
+        );
+
+        // No descriptions, no statements.
+        let bare = Schema::new(vec![Field::with_name(DataType::Int32, "v")]);
+        assert!(bare.comment_statements("t", &PostgresDialect).is_empty());
+    }
+
+    #[test]
+    fn test_describe_rows() {
+        let schema = Schema::new(vec![
+            Field::with_name(DataType::Int64, "id").with_nullable(false),
+            Field::with_name(DataType::Varchar, "name").with_description("customer name"),
+            Field::with_name(DataType::Int64, "customer_id").with_foreign_key("customers(id)"),
+        ])
+        .with_primary_key(vec!["id".to_owned()], false)
+        .unwrap();
+
+        assert_eq!(
+            schema.describe_rows(),
+            vec![
+                DescribeRow {
+                    name: "id".to_owned(),
+                    type_name: "bigint".to_owned(),
+                    nullable: false,
+                    is_pk: true,
+                    fk: None,
+                    comment: None,
+                },
+                DescribeRow {
+                    name: "name".to_owned(),
+                    type_name: "character varying".to_owned(),
+                    nullable: true,
+                    is_pk: false,
+                    fk: None,
+                    comment: Some("customer name".to_owned()),
+                },
+                DescribeRow {
+                    name: "customer_id".to_owned(),
+                    type_name: "bigint".to_owned(),
+                    nullable: true,
+                    is_pk: false,
+                    fk: Some("customers(id)".to_owned()),
+                    comment: None,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_field_names() {
+        let schema = Schema::new(vec![
+            Field::with_name(DataType::Int32, "a"),
+            Field::with_name(DataType::Varchar, "b"),
+            Field::with_name(DataType::Int32, "c"),
+        ]);
+
+        // The borrowing iterator yields names in field order.
+        assert_eq!(
+            schema.field_names().collect::<Vec<_>>(),
+            vec!["a", "b", "c"]
+        );
+        // It agrees with the `Vec`-returning methods.
+        assert_eq!(schema.field_names().collect::<Vec<_>>(), schema.names_str());
+    }
+
+    #[test]
+    fn test_primary_key_in_order() {
+        let schema = Schema::new(vec![
+            Field::with_name(DataType::Varchar, "a"),
+            Field::with_name(DataType::Int32, "b"),
+            Field::with_name(DataType::Int32, "c"),
+        ]);
+
+        // Without any primary-key information, the key is empty.
+        assert!(schema.primary_key_in_order().is_empty());
+
+        // The explicit list captures key column order, which may diverge from field order.
+        let schema = schema
+            .with_primary_key(vec!["c".to_owned(), "a".to_owned()], true)
+            .unwrap();
+        assert_eq!(schema.primary_key_in_order(), vec![2, 0]);
+        // Flags are synced, but carry no ordering on their own.
+        assert!(schema.fields[0].is_primary_key);
+        assert!(!schema.fields[1].is_primary_key);
+        assert!(schema.fields[2].is_primary_key);
+
+        // Without the explicit list, the flags are used in field order instead.
+        let mut flags_only = schema.clone();
+        flags_only.primary_key = None;
+        assert_eq!(flags_only.primary_key_in_order(), vec![0, 2]);
+
+        // Unknown names are rejected.
+        let schema = Schema::new(vec![Field::with_name(DataType::Int32, "a")]);
+        assert!(matches!(
+            schema.with_primary_key(vec!["d".to_owned()], false),
+            Err(SchemaError::ColumnNotFound { name }) if name == "d"
+        ));
+    }
+
+    #[test]
+    fn test_enforce_key_not_null() {
+        let mut schema = Schema::new(vec![
+            Field::with_name(DataType::Int32, "id")
+                .with_is_primary_key(true)
+                .with_nullable(true),
+            Field::with_name(DataType::Varchar, "v"),
+        ]);
+
+        // The disagreeing nullability flag on the key field is corrected in place, while
+        // non-key fields keep theirs.
+        schema.enforce_key_not_null();
+        assert!(!schema.fields[0].nullable);
+        assert!(schema.fields[1].nullable);
+
+        // Keys recorded only in the explicit list are normalized as well.
+        let mut schema = Schema::new(vec![
+            Field::with_name(DataType::Int32, "id"),
+            Field::with_name(DataType::Varchar, "v"),
+        ])
+        .with_primary_key(vec!["id".to_owned()], false)
+        .unwrap();
+        schema.enforce_key_not_null();
+        assert!(!schema.fields[0].nullable);
+        assert!(schema.fields[1].nullable);
+    }
+
+    #[test]
+    fn test_watermark_columns() {
+        let schema = Schema::new(vec![
+            Field::with_name(DataType::Timestamptz, "event_time"),
+            Field::with_name(DataType::Int32, "v"),
+        ]);
+
+        // Unknown names are rejected.
+        assert!(matches!(
+            schema
+                .clone()
+                .with_watermark_columns(vec!["unknown".to_owned()]),
+            Err(SchemaError::ColumnNotFound { name }) if name == "unknown"
+        ));
+
+        let schema = schema
+            .with_watermark_columns(vec!["event_time".to_owned()])
+            .unwrap();
+        assert_eq!(schema.watermark_column_indices(), vec![0]);
+
+        // Watermark columns are informational only: they survive neither serialization
+        // nor affect equality.
+        let round_tripped = Schema::new(schema.to_prost().iter().map(Field::from).collect());
+        assert_eq!(round_tripped, schema);
+        assert!(round_tripped.watermark_columns.is_empty());
+
+        // The builder keeps them consistent across renames and drops.
+        let derived = SchemaBuilder::from_schema(&schema)
+            .rename_field("event_time", "ts")
+            .drop_field("v")
+            .build();
+        assert_eq!(derived.watermark_columns, vec!["ts".to_owned()]);
+        assert_eq!(derived.watermark_column_indices(), vec![0]);
+    }
+
+    #[test]
+    fn test_parse_foreign_key() {
+        let unset = Field::with_name(DataType::Int32, "id");
+        assert_eq!(unset.parse_foreign_key().unwrap(), None);
+
+        let single = unset.clone().with_foreign_key("users(id)");
+        assert_eq!(
+            single.parse_foreign_key().unwrap(),
+            Some(ForeignKeyRef {
+                table: "users".to_owned(),
+                columns: vec!["id".to_owned()],
+            })
+        );
+
+        let multi = unset.clone().with_foreign_key("orders(id, seq)");
+        assert_eq!(
+            multi.parse_foreign_key().unwrap(),
+            Some(ForeignKeyRef {
+                table: "orders".to_owned(),
+                columns: vec!["id".to_owned(), "seq".to_owned()],
+            })
+        );
+
+        for malformed in ["users(id", "(id)", "users"] {
+            let field = unset.clone().with_foreign_key(malformed);
+            assert!(matches!(
+                field.parse_foreign_key(),
+                Err(SchemaError::InvalidForeignKey { .. })
+            ));
+        }
+    }
+
+    #[test]
+    fn test_schema_builder_from_schema() {
+        let orders = Schema::new(vec![
+            Field::with_name(DataType::Int64, "order_id"),
+            Field::with_name(DataType::Int32, "user_id"),
+            Field::with_name(DataType::Decimal, "amount"),
+        ])
+        .with_description("orders");
+
+        let derived = SchemaBuilder::from_schema(&orders)
+            .drop_field("user_id")
+            .rename_field("amount", "total")
+            .build();
+
+        assert_eq!(derived.names(), vec!["order_id", "total"]);
+        assert_eq!(
+            derived.data_types(),
+            vec![DataType::Int64, DataType::Decimal]
+        );
+        // Schema-level metadata is carried over.
+        assert_eq!(derived.description(), Some("orders"));
+    }
+}
@@ -0,0 +1,233 @@
+// Copyright 2025 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Conversions between [`Schema`] and Apache Iceberg schemas, for Iceberg sources and
+//! sinks that need to translate table metadata.
+
+use iceberg::spec::{
+    ListType, MapType, NestedField, NestedFieldRef, PrimitiveType, Schema as IcebergSchema,
+    StructType as IcebergStructType, Type,
+};
+
+use super::schema::{Field, Schema, SchemaError};
+use crate::types::{DataType, MapType as RwMapType, StructType};
+
+impl Schema {
+    /// Converts an Apache Iceberg schema into a [`Schema`].
+    ///
+    /// Iceberg required-ness maps to [`Field`] nullability and field docs are carried to
+    /// the field descriptions. Iceberg field IDs have no representation in [`Schema`] and
+    /// are dropped; [`Schema::to_iceberg_schema`] assigns fresh ones when converting
+    /// back.
+    pub fn from_iceberg_schema(iceberg_schema: &IcebergSchema) -> Result<Schema, SchemaError> {
+        let mut fields = Vec::with_capacity(iceberg_schema.as_struct().fields().len());
+        for nested in iceberg_schema.as_struct().fields() {
+            let mut field = Field::with_name(from_iceberg_type(&nested.field_type)?, &nested.name)
+                .with_nullable(!nested.required);
+            if let Some(doc) = &nested.doc {
+                field = field.with_description(doc.clone());
+            }
+            fields.push(field);
+        }
+        Ok(Schema::new(fields))
+    }
+
+    /// Converts the schema into an Apache Iceberg schema, the inverse of
+    /// [`Schema::from_iceberg_schema`].
+    ///
+    /// Field IDs are assigned sequentially in pre-order, like Iceberg does when creating
+    /// a fresh table. RisingWave decimals carry no precision or scale and convert to
+    /// `decimal(38, 18)`; types without an Iceberg counterpart (e.g. intervals) are
+    /// rejected.
+    pub fn to_iceberg_schema(&self) -> Result<IcebergSchema, SchemaError> {
+        let mut next_id = 0;
+        let fields = self
+            .fields
+            .iter()
+            .map(|field| to_iceberg_field(field, &mut next_id))
+            .collect::<Result<Vec<_>, _>>()?;
+        IcebergSchema::builder()
+            .with_fields(fields)
+            .build()
+            .map_err(|e| SchemaError::InvalidIcebergSchema {
+                reason: e.to_string(),
+            })
+    }
+}
+
+/// Converts a [`Field`] into an Iceberg field, allocating its ID before the IDs of any
+/// nested fields.
+fn to_iceberg_field(field: &Field, next_id: &mut i32) -> Result<NestedFieldRef, SchemaError> {
+    *next_id += 1;
+    let id = *next_id;
+    let field_type = to_iceberg_type(&field.data_type, next_id)?;
+    let mut nested = NestedField::new(id, &field.name, field_type, !field.nullable);
+    if let Some(doc) = &field.description {
+        nested = nested.with_doc(doc);
+    }
+    Ok(nested.into())
+}
+
+/// Maps an Iceberg type to the corresponding RisingWave data type.
+fn from_iceberg_type(field_type: &Type) -> Result<DataType, SchemaError> {
+    let data_type = match field_type {
+        Type::Primitive(primitive) => match primitive {
+            PrimitiveType::Boolean => DataType::Boolean,
+            PrimitiveType::Int => DataType::Int32,
+            PrimitiveType::Long => DataType::Int64,
+            PrimitiveType::Float => DataType::Float32,
+            PrimitiveType::Double => DataType::Float64,
+            PrimitiveType::Decimal { .. } => DataType::Decimal,
+            PrimitiveType::Date => DataType::Date,
+            PrimitiveType::Time => DataType::Time,
+            PrimitiveType::Timestamp => DataType::Timestamp,
+            PrimitiveType::Timestamptz => DataType::Timestamptz,
+            PrimitiveType::String | PrimitiveType::Uuid => DataType::Varchar,
+            PrimitiveType::Binary | PrimitiveType::Fixed(_) => DataType::Bytea,
+            other => {
+                return Err(SchemaError::InvalidIcebergSchema {
+                    reason: format!("unsupported type `{}`", other),
+                });
+            }
+        },
+        Type::List(list) => DataType::list(from_iceberg_type(&list.element_field.field_type)?),
+        Type::Map(map) => DataType::Map(RwMapType::from_kv(
+            from_iceberg_type(&map.key_field.field_type)?,
+            from_iceberg_type(&map.value_field.field_type)?,
+        )),
+        Type::Struct(struct_type) => DataType::Struct(StructType::new(
+            struct_type
+                .fields()
+                .iter()
+                .map(|f| Ok((f.name.clone(), from_iceberg_type(&f.field_type)?)))
+                .collect::<Result<Vec<_>, SchemaError>>()?,
+        )),
+    };
+    Ok(data_type)
+}
+
+/// Maps a RisingWave data type to the corresponding Iceberg type, allocating IDs for
+/// nested fields from `next_id`.
+///
+/// Iceberg tracks required-ness on every nested field while RisingWave only tracks
+/// nullability on top-level fields, so nested fields, list elements and map values are
+/// emitted as optional.
+fn to_iceberg_type(data_type: &DataType, next_id: &mut i32) -> Result<Type, SchemaError> {
+    let field_type = match data_type {
+        DataType::Boolean => Type::Primitive(PrimitiveType::Boolean),
+        DataType::Int16 | DataType::Int32 => Type::Primitive(PrimitiveType::Int),
+        DataType::Int64 | DataType::Serial => Type::Primitive(PrimitiveType::Long),
+        DataType::Float32 => Type::Primitive(PrimitiveType::Float),
+        DataType::Float64 => Type::Primitive(PrimitiveType::Double),
+        DataType::Decimal => Type::Primitive(PrimitiveType::Decimal {
+            precision: 38,
+            scale: 18,
+        }),
+        DataType::Date => Type::Primitive(PrimitiveType::Date),
+        DataType::Time => Type::Primitive(PrimitiveType::Time),
+        DataType::Timestamp => Type::Primitive(PrimitiveType::Timestamp),
+        DataType::Timestamptz => Type::Primitive(PrimitiveType::Timestamptz),
+        DataType::Varchar => Type::Primitive(PrimitiveType::String),
+        DataType::Bytea => Type::Primitive(PrimitiveType::Binary),
+        DataType::List(list) => {
+            *next_id += 1;
+            let element_id = *next_id;
+            let element_type = to_iceberg_type(list.elem(), next_id)?;
+            Type::List(ListType {
+                element_field: NestedField::list_element(element_id, element_type, false).into(),
+            })
+        }
+        DataType::Map(map) => {
+            *next_id += 1;
+            let key_id = *next_id;
+            *next_id += 1;
+            let value_id = *next_id;
+            let key_type = to_iceberg_type(map.key(), next_id)?;
+            let value_type = to_iceberg_type(map.value(), next_id)?;
+            Type::Map(MapType {
+                key_field: NestedField::map_key_element(key_id, key_type).into(),
+                value_field: NestedField::map_value_element(value_id, value_type, false).into(),
+            })
+        }
+        DataType::Struct(struct_type) => {
+            let fields = struct_type
+                .iter()
+                .map(|(name, data_type)| {
+                    *next_id += 1;
+                    let id = *next_id;
+                    let field_type = to_iceberg_type(data_type, next_id)?;
+                    Ok(NestedField::optional(id, name, field_type).into())
+                })
+                .collect::<Result<Vec<_>, SchemaError>>()?;
+            Type::Struct(IcebergStructType::new(fields))
+        }
+        other @ (DataType::Interval | DataType::Jsonb | DataType::Int256 | DataType::Vector(_)) => {
+            return Err(SchemaError::InvalidIcebergSchema {
+                reason: format!("unsupported type `{}`", other),
+            });
+        }
+    };
+    Ok(field_type)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_iceberg_schema_roundtrip() {
+        let schema = Schema::new(vec![
+            Field::with_name(DataType::Int64, "id")
+                .with_nullable(false)
+                .with_description("the order's id"),
+            Field::with_name(DataType::Varchar, "name"),
+            Field::with_name(
+                DataType::Struct(StructType::new(vec![
+                    ("street", DataType::Varchar),
+                    ("zip", DataType::Int32),
+                ])),
+                "address",
+            ),
+            Field::with_name(DataType::list(DataType::Float64), "scores"),
+            Field::with_name(
+                DataType::Map(RwMapType::from_kv(DataType::Varchar, DataType::Int64)),
+                "attrs",
+            ),
+        ]);
+
+        let iceberg_schema = schema.to_iceberg_schema().unwrap();
+
+        // Field IDs are assigned sequentially in pre-order, so nested fields claim the
+        // IDs between their parent and the next top-level field.
+        let top_level_ids = iceberg_schema
+            .as_struct()
+            .fields()
+            .iter()
+            .map(|f| f.id)
+            .collect::<Vec<_>>();
+        assert_eq!(top_level_ids, vec![1, 2, 3, 6, 8]);
+
+        let roundtrip = Schema::from_iceberg_schema(&iceberg_schema).unwrap();
+        assert_eq!(roundtrip, schema);
+    }
+
+    #[test]
+    fn test_iceberg_schema_unsupported_type() {
+        let schema = Schema::new(vec![Field::with_name(DataType::Interval, "i")]);
+        assert!(matches!(
+            schema.to_iceberg_schema(),
+            Err(SchemaError::InvalidIcebergSchema { .. })
+        ));
+    }
+}
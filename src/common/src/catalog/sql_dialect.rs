@@ -0,0 +1,222 @@
+// Copyright 2025 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! SQL dialects for rendering RisingWave types and schema artifacts for external systems,
+//! e.g. when generating DDL for a sink target.
+
+use crate::types::DataType;
+
+/// A SQL dialect, describing how schema artifacts like type names are rendered for an
+/// external system.
+///
+/// Dialects only affect rendering; they do not validate that the target system actually
+/// supports the rendered type.
+pub trait SqlDialect {
+    /// The name of the dialect, e.g. `postgres`.
+    fn name(&self) -> &'static str;
+
+    /// Renders a scalar (non-nested) type.
+    fn scalar_type_name(&self, data_type: &DataType) -> String;
+
+    /// Renders an array type from the rendered element type. Defaults to the
+    /// `ARRAY<element>` form used by most big-data dialects.
+    fn array_type_name(&self, elem: &str) -> String {
+        format!("ARRAY<{elem}>")
+    }
+
+    /// Renders a struct type from the field names and their rendered types. Defaults to
+    /// the `STRUCT<a INT, b TEXT>` form.
+    fn struct_type_name(&self, fields: &[(&str, String)]) -> String {
+        let inner: Vec<String> = fields
+            .iter()
+            .map(|(name, ty)| format!("{name} {ty}"))
+            .collect();
+        format!("STRUCT<{}>", inner.join(", "))
+    }
+
+    /// The byte width of a scalar type in this dialect, for fixed-width targets (e.g.
+    /// COBOL-style records). Returns `None` for variable-width and nested types.
+    ///
+    /// Defaults to the PostgreSQL binary width reported by [`DataType::type_len`].
+    fn scalar_byte_width(&self, data_type: &DataType) -> Option<usize> {
+        fixed_byte_width(data_type)
+    }
+
+    /// Quotes an identifier, escaping embedded quote characters. Defaults to the standard
+    /// double-quoted form.
+    fn quote_ident(&self, ident: &str) -> String {
+        format!("\"{}\"", ident.replace('"', "\"\""))
+    }
+
+    /// The maximum identifier length in characters, or `None` if the dialect does not
+    /// impose one. Longer identifiers are truncated by [`Field::coerced_name`].
+    ///
+    /// [`Field::coerced_name`]: crate::catalog::Field::coerced_name
+    fn max_identifier_length(&self) -> Option<usize> {
+        None
+    }
+
+    /// Whether the dialect folds unquoted identifiers to upper case, like Oracle does.
+    fn uppercase_identifiers(&self) -> bool {
+        false
+    }
+}
+
+/// Renders `s` as a SQL string literal, doubling embedded single quotes.
+pub fn sql_string_literal(s: &str) -> String {
+    format!("'{}'", s.replace('\'', "''"))
+}
+
+/// The PostgreSQL binary width of a fixed-width type, `None` for variable-width and
+/// nested types.
+fn fixed_byte_width(data_type: &DataType) -> Option<usize> {
+    match data_type.type_len() {
+        len if len > 0 => Some(len as usize),
+        _ => None,
+    }
+}
+
+/// Renders the name of `data_type` in the given SQL dialect.
+///
+/// Nested types are rendered recursively: arrays and structs delegate to
+/// [`SqlDialect::array_type_name`] and [`SqlDialect::struct_type_name`] with their
+/// element types already rendered.
+pub fn sql_type_name(data_type: &DataType, dialect: &dyn SqlDialect) -> String {
+    match data_type {
+        DataType::List(list) => dialect.array_type_name(&sql_type_name(list.elem(), dialect)),
+        DataType::Struct(st) => {
+            let fields: Vec<_> = st
+                .iter()
+                .map(|(name, ty)| (name, sql_type_name(ty, dialect)))
+                .collect();
+            dialect.struct_type_name(&fields)
+        }
+        _ => dialect.scalar_type_name(data_type),
+    }
+}
+
+/// The PostgreSQL dialect.
+///
+/// Scalar types render the same way RisingWave itself displays them, since [`DataType`]
+/// already uses the PostgreSQL names. Arrays render as `element[]`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PostgresDialect;
+
+impl SqlDialect for PostgresDialect {
+    fn name(&self) -> &'static str {
+        "postgres"
+    }
+
+    fn scalar_type_name(&self, data_type: &DataType) -> String {
+        data_type.to_string()
+    }
+
+    fn array_type_name(&self, elem: &str) -> String {
+        format!("{elem}[]")
+    }
+
+    fn struct_type_name(&self, fields: &[(&str, String)]) -> String {
+        let inner: Vec<String> = fields
+            .iter()
+            .map(|(name, ty)| format!("{name} {ty}"))
+            .collect();
+        format!("struct<{}>", inner.join(", "))
+    }
+}
+
+/// The MySQL dialect.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MySqlDialect;
+
+impl SqlDialect for MySqlDialect {
+    fn name(&self) -> &'static str {
+        "mysql"
+    }
+
+    fn scalar_type_name(&self, data_type: &DataType) -> String {
+        let name = match data_type {
+            DataType::Boolean => "BOOLEAN",
+            DataType::Int16 => "SMALLINT",
+            DataType::Int32 => "INT",
+            DataType::Int64 | DataType::Serial => "BIGINT",
+            // RisingWave decimals do not carry precision/scale, so render the widest
+            // `DECIMAL` MySQL supports.
+            DataType::Decimal => "DECIMAL(65, 30)",
+            DataType::Int256 => "DECIMAL(65, 0)",
+            DataType::Float32 => "FLOAT",
+            DataType::Float64 => "DOUBLE",
+            // RisingWave varchars are unbounded, so use `TEXT` rather than guessing a
+            // length for `VARCHAR`.
+            DataType::Varchar => "TEXT",
+            DataType::Date => "DATE",
+            DataType::Time => "TIME(6)",
+            DataType::Timestamp => "DATETIME(6)",
+            DataType::Timestamptz => "TIMESTAMP(6)",
+            // MySQL has no interval type; render the textual form.
+            DataType::Interval => "TEXT",
+            DataType::Bytea => "LONGBLOB",
+            DataType::Jsonb | DataType::Map(_) => "JSON",
+            // No sensible MySQL equivalent; fall back to the RisingWave name.
+            _ => return data_type.to_string(),
+        };
+        name.to_owned()
+    }
+
+    fn scalar_byte_width(&self, data_type: &DataType) -> Option<usize> {
+        match data_type {
+            // Rendered as `TEXT`, see `scalar_type_name`.
+            DataType::Interval => None,
+            _ => fixed_byte_width(data_type),
+        }
+    }
+
+    fn quote_ident(&self, ident: &str) -> String {
+        format!("`{}`", ident.replace('`', "``"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::StructType;
+
+    #[test]
+    fn test_sql_type_name_per_dialect() {
+        let cases = [
+            (DataType::Decimal, "numeric", "DECIMAL(65, 30)"),
+            (DataType::Varchar, "character varying", "TEXT"),
+            (DataType::list(DataType::Int32), "integer[]", "ARRAY<INT>"),
+        ];
+        for (ty, pg, mysql) in cases {
+            assert_eq!(sql_type_name(&ty, &PostgresDialect), pg);
+            assert_eq!(sql_type_name(&ty, &MySqlDialect), mysql);
+        }
+    }
+
+    #[test]
+    fn test_sql_type_name_nested() {
+        let ty = DataType::Struct(StructType::new(vec![
+            ("a", DataType::Int32),
+            ("b", DataType::list(DataType::Varchar)),
+        ]));
+        assert_eq!(
+            sql_type_name(&ty, &PostgresDialect),
+            "struct<a integer, b character varying[]>"
+        );
+        assert_eq!(
+            sql_type_name(&ty, &MySqlDialect),
+            "STRUCT<a INT, b ARRAY<TEXT>>"
+        );
+    }
+}
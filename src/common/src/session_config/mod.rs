@@ -296,6 +296,16 @@ pub struct SessionConfig {
     #[parameter(default = "", rename = "intervalstyle")]
     interval_style: String,
 
+    /// Render `decimal` values as JSON strings instead of numbers in `to_jsonb` and friends,
+    /// to avoid losing precision through the conversion to IEEE 754 double.
+    #[parameter(default = false)]
+    jsonb_decimal_as_string: bool,
+
+    /// Match object keys against struct field names case-insensitively when casting `jsonb`
+    /// values to struct types. An exact match always takes precedence.
+    #[parameter(default = false)]
+    jsonb_cast_case_insensitive: bool,
+
     /// If `BATCH_PARALLELISM` is non-zero, batch queries will use this parallelism.
     #[parameter(default = ConfigNonZeroU64::default())]
     batch_parallelism: ConfigNonZeroU64,
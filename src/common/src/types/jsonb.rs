@@ -419,16 +419,38 @@ impl<'a> JsonbRef<'a> {
 
     /// Convert the jsonb value to a datum.
     pub fn to_datum(self, ty: &DataType) -> Result<Datum, String> {
+        self.to_datum_with(ty, false)
+    }
+
+    /// Convert the jsonb value to a datum.
+    ///
+    /// If `case_insensitive` is true, object keys are matched against struct field names
+    /// case-insensitively. An exact match always takes precedence.
+    ///
+    /// Conversion errors report the JSON path of the offending element, e.g. `$.a[2].b`.
+    pub fn to_datum_with(self, ty: &DataType, case_insensitive: bool) -> Result<Datum, String> {
+        self.to_datum_at(ty, case_insensitive, &mut JsonPath::default())
+    }
+
+    fn to_datum_at<'p>(
+        self,
+        ty: &'p DataType,
+        case_insensitive: bool,
+        path: &mut JsonPath<'p>,
+    ) -> Result<Datum, String> {
         if self.0.as_null().is_some() {
             return Ok(None);
         }
         let datum = match ty {
             DataType::Jsonb => ScalarImpl::Jsonb(self.into()),
-            DataType::List(l) => ScalarImpl::List(self.to_list(l)?),
-            DataType::Struct(s) => ScalarImpl::Struct(self.to_struct(s)?),
+            DataType::List(l) => ScalarImpl::List(self.to_list_at(l, case_insensitive, path)?),
+            DataType::Struct(s) => {
+                ScalarImpl::Struct(self.to_struct_at(s, case_insensitive, path)?)
+            }
             _ => {
                 let s = self.force_string();
-                ScalarImpl::from_text(&s, ty).map_err(|e| format!("{}", e.as_report()))?
+                ScalarImpl::from_text(&s, ty)
+                    .map_err(|e| format!("{} at path {}", e.as_report(), path))?
             }
         };
         Ok(Some(datum))
@@ -436,30 +458,67 @@ impl<'a> JsonbRef<'a> {
 
     /// Convert the jsonb value to a list value.
     pub fn to_list(self, ty: &ListType) -> Result<ListValue, String> {
+        self.to_list_at(ty, false, &mut JsonPath::default())
+    }
+
+    fn to_list_at<'p>(
+        self,
+        ty: &'p ListType,
+        case_insensitive: bool,
+        path: &mut JsonPath<'p>,
+    ) -> Result<ListValue, String> {
         let elem_type = ty.elem();
         let array = self
             .0
             .as_array()
-            .ok_or_else(|| format!("expected JSON array, but found {self}"))?;
+            .ok_or_else(|| format!("expected JSON array, but found {self} at path {path}"))?;
         let mut builder = elem_type.create_array_builder(array.len());
-        for v in array.iter() {
-            builder.append(Self(v).to_datum(elem_type)?);
+        for (i, v) in array.iter().enumerate() {
+            path.0.push(JsonPathElem::Index(i));
+            builder.append(Self(v).to_datum_at(elem_type, case_insensitive, path)?);
+            path.0.pop();
         }
         Ok(ListValue::new(builder.finish()))
     }
 
     /// Convert the jsonb value to a struct value.
+    ///
+    /// Object keys are matched against the struct field names. Fields without a matching
+    /// key become `NULL`, and keys without a matching field are ignored.
     pub fn to_struct(self, ty: &StructType) -> Result<StructValue, String> {
+        self.to_struct_at(ty, false, &mut JsonPath::default())
+    }
+
+    fn to_struct_at<'p>(
+        self,
+        ty: &'p StructType,
+        case_insensitive: bool,
+        path: &mut JsonPath<'p>,
+    ) -> Result<StructValue, String> {
         let object = self.0.as_object().ok_or_else(|| {
             format!(
-                "cannot call populate_composite on a jsonb {}",
-                self.type_name()
+                "cannot call populate_composite on a jsonb {} at path {}",
+                self.type_name(),
+                path
             )
         })?;
         let mut fields = Vec::with_capacity(ty.len());
         for (name, ty) in ty.iter() {
-            let datum = match object.get(name) {
-                Some(v) => Self(v).to_datum(ty)?,
+            let value = match object.get(name) {
+                Some(v) => Some(v),
+                None if case_insensitive => object
+                    .iter()
+                    .find(|(k, _)| k.eq_ignore_ascii_case(name))
+                    .map(|(_, v)| v),
+                None => None,
+            };
+            let datum = match value {
+                Some(v) => {
+                    path.0.push(JsonPathElem::Key(name));
+                    let datum = Self(v).to_datum_at(ty, case_insensitive, path)?;
+                    path.0.pop();
+                    datum
+                }
                 None => None,
             };
             fields.push(datum);
@@ -529,6 +588,28 @@ impl<'a> JsonbRef<'a> {
     }
 }
 
+/// A path to an element in a JSON value, used to report the location of conversion errors.
+#[derive(Default)]
+struct JsonPath<'p>(Vec<JsonPathElem<'p>>);
+
+enum JsonPathElem<'p> {
+    Key(&'p str),
+    Index(usize),
+}
+
+impl std::fmt::Display for JsonPath<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "$")?;
+        for elem in &self.0 {
+            match elem {
+                JsonPathElem::Key(key) => write!(f, ".{}", key)?,
+                JsonPathElem::Index(index) => write!(f, "[{}]", index)?,
+            }
+        }
+        Ok(())
+    }
+}
+
 /// A custom implementation for [`serde_json::ser::Formatter`] to match PostgreSQL, which adds extra
 /// space after `,` and `:` in array and object.
 struct ToTextFormatter;
@@ -656,4 +737,57 @@ mod tests {
         let actual = state.finish();
         expected.assert_eq(&actual.to_string());
     }
+
+    #[test]
+    fn test_to_datum_with() {
+        let elem_ty = DataType::Struct(StructType::new(vec![("d", DataType::Int32)]));
+        let ty = DataType::Struct(StructType::new(vec![
+            ("a", DataType::Int32),
+            (
+                "b",
+                DataType::Struct(StructType::new(vec![(
+                    "c",
+                    DataType::list(elem_ty.clone()),
+                )])),
+            ),
+        ]));
+
+        let j: JsonbVal = r#"{"a": 1, "B": {"c": [{"d": 2}, {"d": null}]}, "x": "ignored"}"#
+            .parse()
+            .unwrap();
+
+        // Case-sensitive: key `B` does not match field `b`, so the field is `NULL`.
+        assert_eq!(
+            j.as_scalar_ref().to_datum_with(&ty, false).unwrap(),
+            Some(ScalarImpl::Struct(StructValue::new(vec![
+                Some(ScalarImpl::Int32(1)),
+                None,
+            ])))
+        );
+
+        // Case-insensitive: `B` matches `b`; extra keys are still ignored.
+        let list = ListValue::from_datum_iter(
+            &elem_ty,
+            [
+                Some(ScalarImpl::Struct(StructValue::new(vec![Some(
+                    ScalarImpl::Int32(2),
+                )]))),
+                Some(ScalarImpl::Struct(StructValue::new(vec![None]))),
+            ],
+        );
+        assert_eq!(
+            j.as_scalar_ref().to_datum_with(&ty, true).unwrap(),
+            Some(ScalarImpl::Struct(StructValue::new(vec![
+                Some(ScalarImpl::Int32(1)),
+                Some(ScalarImpl::Struct(StructValue::new(vec![Some(
+                    ScalarImpl::List(list),
+                )]))),
+            ])))
+        );
+
+        // Errors report the JSON path of the offending element.
+        let j: JsonbVal = r#"{"b": {"c": [{"d": 2}, {"d": "oops"}]}}"#.parse().unwrap();
+        let err = j.as_scalar_ref().to_datum_with(&ty, false).unwrap_err();
+        assert!(err.contains("$.b.c[1].d"), "unexpected error: {err}");
+    }
 }
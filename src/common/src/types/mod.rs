@@ -418,6 +418,15 @@ impl DataType {
         DataTypeName::from(self)
     }
 
+    /// Returns whether `self` and `other` are of the same kind of type, ignoring any type
+    /// parameters such as the element type of a list or the fields of a struct.
+    ///
+    /// Note that decimals in RisingWave do not carry precision or scale, so all decimals
+    /// are trivially of the same kind.
+    pub fn same_logical_kind(&self, other: &DataType) -> bool {
+        self.type_name() == other.type_name()
+    }
+
     pub fn prost_type_name(&self) -> PbTypeName {
         self.type_name().into()
     }
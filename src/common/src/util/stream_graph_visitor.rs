@@ -144,6 +144,10 @@ pub fn visit_stream_node_tables_inner<F>(
                 always!(node.left_table, "DynamicFilterLeft");
                 always!(node.right_table, "DynamicFilterRight");
             }
+            NodeBody::BandedDynamicFilter(node) => {
+                always!(node.left_table, "BandedDynamicFilterLeft");
+                always!(node.right_table, "BandedDynamicFilterRight");
+            }
 
             // Aggregation
             NodeBody::HashAgg(node) => {
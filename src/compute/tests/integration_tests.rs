@@ -103,12 +103,10 @@ async fn test_table_materialize() -> StreamResult<()> {
     let memory_state_store = MemoryStateStore::new();
     let dml_manager = Arc::new(DmlManager::for_test());
     let table_id = TableId::default();
-    let schema = Schema {
-        fields: vec![
-            Field::unnamed(DataType::Serial),
-            Field::unnamed(DataType::Float64),
-        ],
-    };
+    let schema = Schema::new(vec![
+        Field::unnamed(DataType::Serial),
+        Field::unnamed(DataType::Float64),
+    ]);
     let source_info = StreamSourceInfo {
         row_format: PbRowFormatType::Json as i32,
         ..Default::default()
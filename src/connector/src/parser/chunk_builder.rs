@@ -283,6 +283,11 @@ impl SourceStreamChunkRowWriter<'_> {
 
                 // Throw error for failed access to primary key columns.
                 Err(e) if desc.is_pk => Err(e),
+                // If the column is missing from the payload entirely and has a default value
+                // evaluated at DDL time, fill it in instead of `NULL`.
+                Err(AccessError::Undefined { .. }) if desc.default_value.is_some() => {
+                    Ok(A::output_for(desc.default_value.clone()))
+                }
                 // Ignore error for other columns and fill in `NULL` instead.
                 Err(error) => {
                     // TODO: figure out a way to fill in not-null default value if user specifies one
@@ -645,6 +645,7 @@ mod tests {
                     is_pk: false,
                     is_hidden_addition_col: false,
                     additional_column: AdditionalColumn { column_type: None },
+                    default_value: None,
                 },
                 SourceColumnDesc::simple("o_enum", DataType::Varchar, ColumnId::from(8)),
                 SourceColumnDesc::simple("o_char", DataType::Varchar, ColumnId::from(9)),
@@ -480,6 +480,7 @@ mod tests {
             additional_column: AdditionalColumn {
                 column_type: Some(AdditionalColumnType::Key(AdditionalColumnKey {})),
             },
+            default_value: None,
         };
         let descs = vec![
             SourceColumnDesc::simple("a", DataType::Int32, 0.into()),
@@ -577,6 +577,7 @@ mod tests {
                                         version: Pr13707,
                                         system_column: None,
                                         nullable: true,
+                                        foreign_key: None,
                                     },
                                     is_hidden: false,
                                 },
@@ -593,6 +594,7 @@ mod tests {
                                         version: Pr13707,
                                         system_column: None,
                                         nullable: true,
+                                        foreign_key: None,
                                     },
                                     is_hidden: false,
                                 },
@@ -609,6 +611,7 @@ mod tests {
                                         version: Pr13707,
                                         system_column: None,
                                         nullable: true,
+                                        foreign_key: None,
                                     },
                                     is_hidden: false,
                                 },
@@ -1011,25 +1011,23 @@ mod test {
 
     #[tokio::test]
     async fn test_schema_check() {
-        let schema = Schema {
-            fields: vec![
-                Field::with_name(DataType::Int64, "v1"),
-                Field::with_name(DataType::Float64, "v2"),
-                Field::with_name(
-                    DataType::list(DataType::Struct(StructType::new(vec![
-                        ("v1".to_owned(), DataType::Int64.list()),
-                        (
-                            "v3".to_owned(),
-                            DataType::Struct(StructType::new(vec![
-                                ("v1".to_owned(), DataType::Int64),
-                                ("v2".to_owned(), DataType::Int64),
-                            ])),
-                        ),
-                    ]))),
-                    "v3",
-                ),
-            ],
-        };
+        let schema = Schema::new(vec![
+            Field::with_name(DataType::Int64, "v1"),
+            Field::with_name(DataType::Float64, "v2"),
+            Field::with_name(
+                DataType::list(DataType::Struct(StructType::new(vec![
+                    ("v1".to_owned(), DataType::Int64.list()),
+                    (
+                        "v3".to_owned(),
+                        DataType::Struct(StructType::new(vec![
+                            ("v1".to_owned(), DataType::Int64),
+                            ("v2".to_owned(), DataType::Int64),
+                        ])),
+                    ),
+                ]))),
+                "v3",
+            ),
+        ]);
         let fields = schema
             .fields()
             .iter()
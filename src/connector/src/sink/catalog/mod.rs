@@ -426,7 +426,7 @@ impl SinkCatalog {
             .iter()
             .map(|column| Field::from(column.column_desc.clone()))
             .collect_vec();
-        Schema { fields }
+        Schema::new(fields)
     }
 
     pub fn visible_schema(&self) -> Schema {
@@ -434,7 +434,7 @@ impl SinkCatalog {
             .visible_columns()
             .map(|column| Field::from(column.column_desc.clone()))
             .collect_vec();
-        Schema { fields }
+        Schema::new(fields)
     }
 
     pub fn unique_identity(&self) -> String {
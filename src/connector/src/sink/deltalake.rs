@@ -783,14 +783,8 @@ mod tests {
         };
 
         let schema = Schema::new(vec![
-            Field {
-                data_type: DataType::Int32,
-                name: "id".into(),
-            },
-            Field {
-                data_type: DataType::Varchar,
-                name: "name".into(),
-            },
+            Field::with_name(DataType::Int32, "id".into()),
+            Field::with_name(DataType::Varchar, "name".into()),
         ]);
 
         let deltalake_config = DeltaLakeConfig::from_btreemap(properties).unwrap();
@@ -854,14 +848,8 @@ mod tests {
         };
 
         let schema = Schema::new(vec![
-            Field {
-                data_type: DataType::Int32,
-                name: "id".into(),
-            },
-            Field {
-                data_type: DataType::Varchar,
-                name: "name".into(),
-            },
+            Field::with_name(DataType::Int32, "id".into()),
+            Field::with_name(DataType::Varchar, "name".into()),
         ]);
 
         let deltalake_config = DeltaLakeConfig::from_btreemap(properties).unwrap();
@@ -458,10 +458,7 @@ mod tests {
 
     #[test]
     fn test_to_json_basic_type() {
-        let mock_field = Field {
-            data_type: DataType::Boolean,
-            name: Default::default(),
-        };
+        let mock_field = Field::with_name(DataType::Boolean, Default::default());
 
         let config = JsonEncoderConfig {
             time_handling_mode: TimeHandlingMode::Milli,
@@ -632,10 +629,7 @@ mod tests {
             jsonb_handling_mode: JsonbHandlingMode::String,
         };
         let decimal = datum_to_json_object(
-            &Field {
-                data_type: DataType::Decimal,
-                name: "aaa".to_owned(),
-            },
+            &Field::with_name(DataType::Decimal, "aaa".to_owned()),
             Some(ScalarImpl::Decimal(Decimal::try_from(1.1111111).unwrap()).as_scalar_ref_impl()),
             &doris_config,
         )
@@ -735,44 +729,17 @@ mod tests {
     #[test]
     fn test_generate_json_converter_schema() {
         let fields = vec![
-            Field {
-                data_type: DataType::Boolean,
-                name: "v1".into(),
-            },
-            Field {
-                data_type: DataType::Int16,
-                name: "v2".into(),
-            },
-            Field {
-                data_type: DataType::Int32,
-                name: "v3".into(),
-            },
-            Field {
-                data_type: DataType::Float32,
-                name: "v4".into(),
-            },
-            Field {
-                data_type: DataType::Decimal,
-                name: "v5".into(),
-            },
-            Field {
-                data_type: DataType::Date,
-                name: "v6".into(),
-            },
-            Field {
-                data_type: DataType::Varchar,
-                name: "v7".into(),
-            },
-            Field {
-                data_type: DataType::Time,
-                name: "v8".into(),
-            },
-            Field {
-                data_type: DataType::Interval,
-                name: "v9".into(),
-            },
-            Field {
-                data_type: DataType::Struct(StructType::new(vec![
+            Field::with_name(DataType::Boolean, "v1".into()),
+            Field::with_name(DataType::Int16, "v2".into()),
+            Field::with_name(DataType::Int32, "v3".into()),
+            Field::with_name(DataType::Float32, "v4".into()),
+            Field::with_name(DataType::Decimal, "v5".into()),
+            Field::with_name(DataType::Date, "v6".into()),
+            Field::with_name(DataType::Varchar, "v7".into()),
+            Field::with_name(DataType::Time, "v8".into()),
+            Field::with_name(DataType::Interval, "v9".into()),
+            Field::with_name(
+                DataType::Struct(StructType::new(vec![
                     ("a", DataType::Timestamp),
                     ("b", DataType::Timestamptz),
                     (
@@ -783,27 +750,18 @@ mod tests {
                         ])),
                     ),
                 ])),
-                name: "v10".into(),
-            },
-            Field {
-                data_type: DataType::list(DataType::list(DataType::Struct(StructType::new(vec![
+                "v10".into(),
+            ),
+            Field::with_name(
+                DataType::list(DataType::list(DataType::Struct(StructType::new(vec![
                     ("aa", DataType::Int64),
                     ("bb", DataType::Float64),
                 ])))),
-                name: "v11".into(),
-            },
-            Field {
-                data_type: DataType::Jsonb,
-                name: "12".into(),
-            },
-            Field {
-                data_type: DataType::Serial,
-                name: "13".into(),
-            },
-            Field {
-                data_type: DataType::Int256,
-                name: "14".into(),
-            },
+                "v11".into(),
+            ),
+            Field::with_name(DataType::Jsonb, "12".into()),
+            Field::with_name(DataType::Serial, "13".into()),
+            Field::with_name(DataType::Int256, "14".into()),
         ];
         let schema =
             json_converter_with_schema(json!({}), "test".to_owned(), fields.iter())["schema"]
@@ -515,18 +515,9 @@ mod tests {
     fn test_template_format_validation() {
         // Create a schema with test columns
         let schema = Schema::new(vec![
-            Field {
-                data_type: DataType::Int32,
-                name: "id".to_owned(),
-            },
-            Field {
-                data_type: DataType::Varchar,
-                name: "name".to_owned(),
-            },
-            Field {
-                data_type: DataType::Varchar,
-                name: "email".to_owned(),
-            },
+            Field::with_name(DataType::Int32, "id".to_owned()),
+            Field::with_name(DataType::Varchar, "name".to_owned()),
+            Field::with_name(DataType::Varchar, "email".to_owned()),
         ]);
 
         // Create a map of column names to their data types
@@ -588,18 +579,9 @@ mod tests {
     fn test_template_encoding() {
         // Create a schema with test columns
         let schema = Schema::new(vec![
-            Field {
-                data_type: DataType::Int32,
-                name: "id".to_owned(),
-            },
-            Field {
-                data_type: DataType::Varchar,
-                name: "name".to_owned(),
-            },
-            Field {
-                data_type: DataType::Varchar,
-                name: "email".to_owned(),
-            },
+            Field::with_name(DataType::Int32, "id".to_owned()),
+            Field::with_name(DataType::Varchar, "name".to_owned()),
+            Field::with_name(DataType::Varchar, "email".to_owned()),
         ]);
 
         // Test cases with different template formats
@@ -662,18 +644,9 @@ mod tests {
     fn test_complex_nested_template() {
         // Create a schema with test columns
         let schema = Schema::new(vec![
-            Field {
-                data_type: DataType::Int32,
-                name: "id".to_owned(),
-            },
-            Field {
-                data_type: DataType::Varchar,
-                name: "name".to_owned(),
-            },
-            Field {
-                data_type: DataType::Varchar,
-                name: "email".to_owned(),
-            },
+            Field::with_name(DataType::Int32, "id".to_owned()),
+            Field::with_name(DataType::Varchar, "name".to_owned()),
+            Field::with_name(DataType::Varchar, "email".to_owned()),
         ]);
 
         // Create a map of column names to their data types
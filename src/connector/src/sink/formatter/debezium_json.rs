@@ -361,22 +361,12 @@ mod tests {
         );
 
         let schema = Schema::new(vec![
-            Field {
-                data_type: DataType::Int32,
-                name: "v1".into(),
-            },
-            Field {
-                data_type: DataType::Float32,
-                name: "v2".into(),
-            },
-            Field {
-                data_type: StructType::new(vec![
-                    ("v4", DataType::Int32),
-                    ("v5", DataType::Float32),
-                ])
-                .into(),
-                name: "v3".into(),
-            },
+            Field::with_name(DataType::Int32, "v1".into()),
+            Field::with_name(DataType::Float32, "v2".into()),
+            Field::with_name(
+                StructType::new(vec![("v4", DataType::Int32), ("v5", DataType::Float32)]).into(),
+                "v3".into(),
+            ),
         ]);
 
         let encoder = JsonEncoder::new(
@@ -794,14 +794,8 @@ mod test {
 
         // Create a table with two columns (| id : INT32 | v2 : VARCHAR |) here
         let schema = Schema::new(vec![
-            Field {
-                data_type: DataType::Int32,
-                name: "id".into(),
-            },
-            Field {
-                data_type: DataType::Varchar,
-                name: "v2".into(),
-            },
+            Field::with_name(DataType::Int32, "id".into()),
+            Field::with_name(DataType::Varchar, "v2".into()),
         ]);
 
         let kafka_config = KafkaConfig::from_btreemap(properties)?;
@@ -552,6 +552,20 @@ impl<R: LogReader> RateLimitedLogReaderCore<R> {
                     Ok(self.consume_single_upstream_item(epoch, item))
                 }
             }
+            // The adaptive policy's effective rate changes over time, so chunks are not
+            // split; just wait for the whole chunk's permits.
+            RateLimit::Adaptive => {
+                if let Some((epoch, chunk, chunk_id)) = self.consume_next_pending_chunk() {
+                    self.rate_limiter.wait_chunk(&chunk).await;
+                    Ok((epoch, LogStoreReadItem::StreamChunk { chunk, chunk_id }))
+                } else {
+                    let (epoch, item) = self.inner.next_item().await?;
+                    if let LogStoreReadItem::StreamChunk { chunk, .. } = &item {
+                        self.rate_limiter.wait_chunk(chunk).await;
+                    }
+                    Ok(self.consume_single_upstream_item(epoch, item))
+                }
+            }
             RateLimit::Fixed(limit) => {
                 if self.peek_next_pending_chunk().is_none() {
                     let (epoch, item) = self.inner.next_item().await?;
@@ -324,9 +324,7 @@ impl SinkParam {
     }
 
     pub fn schema(&self) -> Schema {
-        Schema {
-            fields: self.columns.iter().map(Field::from).collect(),
-        }
+        Schema::new(self.columns.iter().map(Field::from).collect())
     }
 
     /// Get the downstream primary key indices specified by the user. If not specified, return
@@ -654,14 +654,8 @@ mod tests {
     #[test]
     fn test_create_insert_sql() {
         let schema = Schema::new(vec![
-            Field {
-                data_type: DataType::Int32,
-                name: "a".to_owned(),
-            },
-            Field {
-                data_type: DataType::Int32,
-                name: "b".to_owned(),
-            },
+            Field::with_name(DataType::Int32, "a".to_owned()),
+            Field::with_name(DataType::Int32, "b".to_owned()),
         ]);
         let schema_name = "test_schema";
         let table_name = "test_table";
@@ -675,14 +669,8 @@ mod tests {
     #[test]
     fn test_create_delete_sql() {
         let schema = Schema::new(vec![
-            Field {
-                data_type: DataType::Int32,
-                name: "a".to_owned(),
-            },
-            Field {
-                data_type: DataType::Int32,
-                name: "b".to_owned(),
-            },
+            Field::with_name(DataType::Int32, "a".to_owned()),
+            Field::with_name(DataType::Int32, "b".to_owned()),
         ]);
         let schema_name = "test_schema";
         let table_name = "test_table";
@@ -702,14 +690,8 @@ mod tests {
     #[test]
     fn test_create_upsert_sql() {
         let schema = Schema::new(vec![
-            Field {
-                data_type: DataType::Int32,
-                name: "a".to_owned(),
-            },
-            Field {
-                data_type: DataType::Int32,
-                name: "b".to_owned(),
-            },
+            Field::with_name(DataType::Int32, "a".to_owned()),
+            Field::with_name(DataType::Int32, "b".to_owned()),
         ]);
         let schema_name = "test_schema";
         let table_name = "test_table";
@@ -726,14 +708,8 @@ mod tests {
     #[test]
     fn test_create_upsert_sql_all_columns_are_primary_keys() {
         let schema = Schema::new(vec![
-            Field {
-                data_type: DataType::Int32,
-                name: "user_id".to_owned(),
-            },
-            Field {
-                data_type: DataType::Int32,
-                name: "client_id".to_owned(),
-            },
+            Field::with_name(DataType::Int32, "user_id".to_owned()),
+            Field::with_name(DataType::Int32, "client_id".to_owned()),
         ]);
         let schema_name = "test_schema";
         let table_name = "test_table";
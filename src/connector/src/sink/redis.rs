@@ -629,14 +629,8 @@ mod test {
     #[tokio::test]
     async fn test_write() {
         let schema = Schema::new(vec![
-            Field {
-                data_type: DataType::Int32,
-                name: "id".to_owned(),
-            },
-            Field {
-                data_type: DataType::Varchar,
-                name: "name".to_owned(),
-            },
+            Field::with_name(DataType::Int32, "id".to_owned()),
+            Field::with_name(DataType::Varchar, "name".to_owned()),
         ]);
 
         let format_desc = SinkFormatDesc {
@@ -698,14 +692,8 @@ mod test {
     #[tokio::test]
     async fn test_format_write() {
         let schema = Schema::new(vec![
-            Field {
-                data_type: DataType::Int32,
-                name: "id".to_owned(),
-            },
-            Field {
-                data_type: DataType::Varchar,
-                name: "name".to_owned(),
-            },
+            Field::with_name(DataType::Int32, "id".to_owned()),
+            Field::with_name(DataType::Varchar, "name".to_owned()),
         ]);
 
         let mut btree_map = BTreeMap::default();
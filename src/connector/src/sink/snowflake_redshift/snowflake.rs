@@ -1234,7 +1234,7 @@ mod tests {
             all_column_names: Some(vec!["v1".to_owned(), "v2".to_owned()]),
             database: "test_db".to_owned(),
             schema_name: "test_schema".to_owned(),
-            schema: Schema { fields: vec![] },
+            schema: Schema::new(vec![]),
             stage: None,
             pipe_name: None,
         };
@@ -1282,7 +1282,7 @@ END;"#;
             all_column_names: Some(vec!["id1".to_owned(), "id2".to_owned(), "val".to_owned()]),
             database: "test_db".to_owned(),
             schema_name: "test_schema".to_owned(),
-            schema: Schema { fields: vec![] },
+            schema: Schema::new(vec![]),
             stage: None,
             pipe_name: None,
         };
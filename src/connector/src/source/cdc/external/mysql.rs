@@ -868,9 +868,7 @@ mod tests {
             ColumnDesc::named("v3", ColumnId::new(3), DataType::Varchar),
             ColumnDesc::named("v4", ColumnId::new(4), DataType::Date),
         ];
-        let rw_schema = Schema {
-            fields: columns.iter().map(Field::from).collect(),
-        };
+        let rw_schema = Schema::new(columns.iter().map(Field::from).collect());
         let props: HashMap<String, String> = convert_args!(hashmap!(
                 "hostname" => "localhost",
                 "port" => "8306",
@@ -1205,9 +1205,7 @@ mod tests {
             ColumnDesc::named("v3", ColumnId::new(3), DataType::Decimal),
             ColumnDesc::named("v4", ColumnId::new(4), DataType::Date),
         ];
-        let rw_schema = Schema {
-            fields: columns.iter().map(Field::from).collect(),
-        };
+        let rw_schema = Schema::new(columns.iter().map(Field::from).collect());
 
         let props: HashMap<String, String> = convert_args!(hashmap!(
                 "hostname" => "localhost",
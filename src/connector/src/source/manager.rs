@@ -18,7 +18,8 @@ use risingwave_common::catalog::{
     CDC_OFFSET_COLUMN_NAME, CDC_TABLE_NAME_COLUMN_NAME, ColumnDesc, ColumnId,
     KAFKA_TIMESTAMP_COLUMN_NAME, ROW_ID_COLUMN_ID, ROW_ID_COLUMN_NAME,
 };
-use risingwave_common::types::DataType;
+use risingwave_common::types::{DataType, Datum};
+use risingwave_common::util::value_encoding::DatumFromProtoExt;
 use risingwave_pb::plan_common::column_desc::GeneratedOrDefaultColumn;
 use risingwave_pb::plan_common::{AdditionalColumn, ColumnDescVersion};
 
@@ -43,6 +44,9 @@ pub struct SourceColumnDesc {
     pub is_pk: bool,
     /// `is_hidden_addition_col` is used to indicate whether the column is a hidden addition column.
     pub is_hidden_addition_col: bool,
+    /// The default value evaluated at DDL time, used to fill the column when it is missing
+    /// from the source payload. `None` if the column has no default.
+    pub default_value: Datum,
 }
 
 /// `SourceColumnType` is used to indicate the type of a column emitted by the Source.
@@ -90,6 +94,7 @@ impl SourceColumnDesc {
             is_pk: false,
             is_hidden_addition_col: false,
             additional_column: AdditionalColumn { column_type: None },
+            default_value: None,
         }
     }
 
@@ -131,6 +136,7 @@ impl From<&ColumnDesc> for SourceColumnDesc {
             version: _,
             system_column: _,
             nullable: _,
+            foreign_key: _,
         }: &ColumnDesc,
     ) -> Self {
         if let Some(option) = generated_or_default_column {
@@ -141,6 +147,20 @@ impl From<&ColumnDesc> for SourceColumnDesc {
             )
         }
 
+        // Use the default value evaluated at DDL time to fill the column when it is missing
+        // from the payload. A decode failure is treated as no default for robustness against
+        // corrupted catalogs.
+        let default_value = if let Some(GeneratedOrDefaultColumn::DefaultColumn(default)) =
+            generated_or_default_column
+            && let Some(snapshot_value) = &default.snapshot_value
+        {
+            Datum::from_protobuf(snapshot_value, data_type)
+                .ok()
+                .flatten()
+        } else {
+            None
+        };
+
         let column_type = SourceColumnType::from_name(name);
         if column_type == SourceColumnType::RowId {
             debug_assert_eq!(name, ROW_ID_COLUMN_NAME);
@@ -156,6 +176,7 @@ impl From<&ColumnDesc> for SourceColumnDesc {
             column_type,
             is_pk: false,
             is_hidden_addition_col: false,
+            default_value,
         }
     }
 }
@@ -171,6 +192,7 @@ impl From<&SourceColumnDesc> for ColumnDesc {
             column_type: _,
             is_pk: _,
             is_hidden_addition_col: _,
+            default_value: _,
         }: &SourceColumnDesc,
     ) -> Self {
         ColumnDesc {
@@ -184,6 +206,7 @@ impl From<&SourceColumnDesc> for ColumnDesc {
             version: ColumnDescVersion::LATEST,
             system_column: None,
             nullable: true,
+            foreign_key: None,
         }
     }
 }
@@ -24,14 +24,21 @@ define_context! {
     pub FRAGMENT_ID: FragmentId,
     pub VNODE_COUNT: usize,
     pub STRICT_MODE: bool,
+    pub JSONB_DECIMAL_AS_STRING: bool,
+    pub JSONB_CAST_CASE_INSENSITIVE: bool,
 }
 
 pub fn capture_expr_context() -> ExprResult<ExprContext> {
     let time_zone = TIME_ZONE::try_with(ToOwned::to_owned)?;
     let strict_mode = STRICT_MODE::try_with(|v| *v)?;
+    let jsonb_decimal_as_string = JSONB_DECIMAL_AS_STRING::try_with(|v| *v).unwrap_or(false);
+    let jsonb_cast_case_insensitive =
+        JSONB_CAST_CASE_INSENSITIVE::try_with(|v| *v).unwrap_or(false);
     Ok(ExprContext {
         time_zone,
         strict_mode,
+        jsonb_decimal_as_string,
+        jsonb_cast_case_insensitive,
     })
 }
 
@@ -52,13 +59,38 @@ pub fn strict_mode() -> ExprResult<bool> {
     STRICT_MODE::try_with(|&v| v)
 }
 
+/// Get whether decimals should be rendered as JSON strings in `to_jsonb` and friends.
+///
+/// Falls back to the default (`false`) outside an expression context, e.g. during
+/// constant folding in the frontend.
+pub fn jsonb_decimal_as_string() -> bool {
+    JSONB_DECIMAL_AS_STRING::try_with(|&v| v).unwrap_or(false)
+}
+
+/// Get whether casting jsonb objects to structs matches keys case-insensitively.
+///
+/// Falls back to the default (`false`) outside an expression context, e.g. during
+/// constant folding in the frontend.
+pub fn jsonb_cast_case_insensitive() -> bool {
+    JSONB_CAST_CASE_INSENSITIVE::try_with(|&v| v).unwrap_or(false)
+}
+
 pub async fn expr_context_scope<Fut>(expr_context: ExprContext, future: Fut) -> Fut::Output
 where
     Fut: Future,
 {
     TIME_ZONE::scope(
         expr_context.time_zone.clone(),
-        STRICT_MODE::scope(expr_context.strict_mode, future),
+        STRICT_MODE::scope(
+            expr_context.strict_mode,
+            JSONB_DECIMAL_AS_STRING::scope(
+                expr_context.jsonb_decimal_as_string,
+                JSONB_CAST_CASE_INSENSITIVE::scope(
+                    expr_context.jsonb_cast_case_insensitive,
+                    future,
+                ),
+            ),
+        ),
     )
     .await
 }
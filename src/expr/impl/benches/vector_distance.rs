@@ -0,0 +1,52 @@
+// Copyright 2025 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Micro-benchmarks for the distance kernels backing the `l2_distance`,
+//! `cosine_distance` and `inner_product` scalar functions, at dimensions typical
+//! for embedding models.
+
+use criterion::{Criterion, black_box, criterion_group, criterion_main};
+use risingwave_common::types::{F32, VectorRef};
+use risingwave_common::vector::MeasureDistanceBuilder;
+use risingwave_common::vector::distance::{
+    L1Distance, L2SqrDistance, cosine_distance, inner_product_faiss,
+};
+
+criterion_group!(benches, bench_vector_distance);
+criterion_main!(benches);
+
+/// Dimensions of some widely used embedding models.
+const DIMS: &[usize] = &[128, 768, 1536];
+
+fn bench_vector_distance(c: &mut Criterion) {
+    for &dim in DIMS {
+        let lhs: Vec<F32> = (0..dim).map(|i| F32::from((i as f32).sin())).collect();
+        let rhs: Vec<F32> = (0..dim).map(|i| F32::from((i as f32).cos())).collect();
+        let lhs = VectorRef::from_slice_unchecked(&lhs);
+        let rhs = VectorRef::from_slice_unchecked(&rhs);
+
+        c.bench_function(&format!("vector_distance/l2_sqr/{dim}"), |bencher| {
+            bencher.iter(|| L2SqrDistance::distance(black_box(lhs), black_box(rhs)))
+        });
+        c.bench_function(&format!("vector_distance/l1/{dim}"), |bencher| {
+            bencher.iter(|| L1Distance::distance(black_box(lhs), black_box(rhs)))
+        });
+        c.bench_function(&format!("vector_distance/cosine/{dim}"), |bencher| {
+            bencher.iter(|| cosine_distance(black_box(lhs), black_box(rhs)))
+        });
+        c.bench_function(&format!("vector_distance/inner_product/{dim}"), |bencher| {
+            bencher.iter(|| inner_product_faiss(black_box(lhs), black_box(rhs)))
+        });
+    }
+}
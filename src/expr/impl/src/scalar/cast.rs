@@ -21,15 +21,19 @@ use risingwave_common::array::{DataChunk, ListRef, ListValue, StructRef, StructV
 use risingwave_common::cast;
 use risingwave_common::row::OwnedRow;
 use risingwave_common::types::{
-    DataType, F64, Int256, JsonbRef, MapRef, MapValue, ScalarRef as _, Serial, Timestamptz, ToText,
+    DataType, F64, Int256, JsonbRef, MapRef, MapValue, ScalarImpl, ScalarRef as _, Serial,
+    Timestamptz, ToText,
 };
 use risingwave_common::util::iter_util::ZipEqFast;
 use risingwave_common::util::row_id::row_id_to_unix_millis;
 use risingwave_expr::expr::{Context, ExpressionBoxExt, InputRefExpression, build_func};
+use risingwave_expr::expr_context::jsonb_cast_case_insensitive;
 use risingwave_expr::{ExprError, Result, function};
 use risingwave_pb::expr::expr_node::PbType;
 use thiserror_ext::AsReport;
 
+use super::to_jsonb::ToJsonb;
+
 #[function("cast(varchar) -> *int")]
 #[function("cast(varchar) -> decimal")]
 #[function("cast(varchar) -> *float")]
@@ -96,6 +100,46 @@ pub fn jsonb_to_number<T: TryFrom<F64>>(v: JsonbRef<'_>) -> Result<Option<T>> {
     }
 }
 
+/// Converts a jsonb object to a struct, matching object keys to field names. Fields without
+/// a matching key become `NULL`, extra keys are ignored, and nested structs and arrays are
+/// converted recursively. Set the `jsonb_cast_case_insensitive` session variable to match
+/// keys case-insensitively. Errors report the JSON path of the offending element.
+#[function("cast(jsonb) -> struct", type_infer = "unreachable")]
+fn jsonb_to_struct(v: JsonbRef<'_>, ctx: &Context) -> Result<Option<StructValue>> {
+    Ok(
+        v.to_datum_with(&ctx.return_type, jsonb_cast_case_insensitive())
+            .map_err(|e| ExprError::Parse(e.into()))?
+            .map(ScalarImpl::into_struct),
+    )
+}
+
+/// Converts a jsonb array to a list, converting each element recursively.
+#[function("cast(jsonb) -> anyarray", type_infer = "unreachable")]
+fn jsonb_to_list(v: JsonbRef<'_>, ctx: &Context) -> Result<Option<ListValue>> {
+    Ok(
+        v.to_datum_with(&ctx.return_type, jsonb_cast_case_insensitive())
+            .map_err(|e| ExprError::Parse(e.into()))?
+            .map(ScalarImpl::into_list),
+    )
+}
+
+/// Converts a struct to a jsonb object, using the field names as keys. The counterpart of
+/// `cast(jsonb) -> struct`; equivalent to `to_jsonb`.
+#[function("cast(struct) -> jsonb")]
+fn struct_to_jsonb(
+    input: StructRef<'_>,
+    ctx: &Context,
+    writer: &mut jsonbb::Builder,
+) -> Result<()> {
+    input.add_to(&ctx.arg_types[0], writer)
+}
+
+/// Converts a list to a jsonb array, converting each element recursively.
+#[function("cast(anyarray) -> jsonb")]
+fn list_to_jsonb(input: ListRef<'_>, ctx: &Context, writer: &mut jsonbb::Builder) -> Result<()> {
+    input.add_to(&ctx.arg_types[0], writer)
+}
+
 #[function("cast(int4) -> int2")]
 #[function("cast(int8) -> int2")]
 #[function("cast(int8) -> int4")]
@@ -449,6 +493,31 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_jsonb_to_struct() {
+        let ctx = Context {
+            arg_types: vec![DataType::Jsonb],
+            return_type: DataType::Struct(StructType::new(vec![
+                ("a", DataType::Int32),
+                ("b", DataType::Varchar),
+            ])),
+            variadic: false,
+        };
+        // Keys are matched by name: order does not matter, missing keys become `NULL` and
+        // extra keys are ignored.
+        let json: JsonbVal = r#"{"b": "foo", "x": 2, "a": 1}"#.parse().unwrap();
+        assert_eq!(
+            jsonb_to_struct(json.as_scalar_ref(), &ctx).unwrap(),
+            Some(StructValue::new(vec![
+                Some(1i32.to_scalar_value()),
+                Some("foo".into()),
+            ]))
+        );
+        // A jsonb `null` becomes a `NULL` struct.
+        let json: JsonbVal = "null".parse().unwrap();
+        assert_eq!(jsonb_to_struct(json.as_scalar_ref(), &ctx).unwrap(), None);
+    }
+
     #[test]
     fn test_timestamp() {
         assert_eq!(
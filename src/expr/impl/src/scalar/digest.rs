@@ -0,0 +1,105 @@
+// Copyright 2025 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use risingwave_expr::{ExprError, Result, function};
+use sha1::{Digest, Sha1};
+use sha2::{Sha224, Sha256, Sha384, Sha512};
+
+/// Computes a hash of the given data using the named algorithm, like pgcrypto's `digest`.
+///
+/// Supported algorithms are `md5`, `sha1`, `sha224`, `sha256`, `sha384` and `sha512`.
+#[function("digest(bytea, varchar) -> bytea")]
+pub fn digest(data: &[u8], algorithm: &str) -> Result<Box<[u8]>> {
+    match algorithm {
+        "md5" => Ok(md5::Md5::digest(data).as_slice().into()),
+        "sha1" => Ok(Sha1::digest(data).as_slice().into()),
+        "sha224" => Ok(Sha224::digest(data).as_slice().into()),
+        "sha256" => Ok(Sha256::digest(data).as_slice().into()),
+        "sha384" => Ok(Sha384::digest(data).as_slice().into()),
+        "sha512" => Ok(Sha512::digest(data).as_slice().into()),
+        _ => Err(ExprError::InvalidParam {
+            name: "algorithm",
+            reason: format!("Cannot use \"{}\": No such hash algorithm", algorithm).into(),
+        }),
+    }
+}
+
+/// Text variant of [`digest`], hashing the UTF-8 bytes of the string.
+#[function("digest(varchar, varchar) -> bytea")]
+pub fn digest_str(data: &str, algorithm: &str) -> Result<Box<[u8]>> {
+    digest(data.as_bytes(), algorithm)
+}
+
+/// Generates a version 4 (random) UUID, like pgcrypto's `gen_random_uuid`.
+///
+/// Returns the textual form since RisingWave has no dedicated uuid type.
+#[function("gen_random_uuid() -> varchar", volatile)]
+fn gen_random_uuid(writer: &mut impl std::fmt::Write) {
+    let mut bytes: [u8; 16] = rand::random();
+    // Set the version (4) and variant (RFC 4122) bits.
+    bytes[6] = (bytes[6] & 0x0f) | 0x40;
+    bytes[8] = (bytes[8] & 0x3f) | 0x80;
+    write!(
+        writer,
+        "{}-{}-{}-{}-{}",
+        hex::encode(&bytes[0..4]),
+        hex::encode(&bytes[4..6]),
+        hex::encode(&bytes[6..8]),
+        hex::encode(&bytes[8..10]),
+        hex::encode(&bytes[10..16]),
+    )
+    .unwrap();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_digest() {
+        // Known-answer vectors for the empty string and "abc".
+        let cases = [
+            ("md5", &b""[..], "d41d8cd98f00b204e9800998ecf8427e"),
+            ("sha1", b"abc", "a9993e364706816aba3e25717850c26c9cd0d89d"),
+            (
+                "sha256",
+                b"abc",
+                "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad",
+            ),
+            (
+                "sha512",
+                b"abc",
+                "ddaf35a193617abacc417349ae20413112e6fa4e89a97ea20a9eeee64b55d39a\
+                 2192992a274fc1a836ba3c23a3feebbd454d4423643ce80e2a9ac94fa54ca49f",
+            ),
+        ];
+        for (algorithm, data, expected) in cases {
+            assert_eq!(hex::encode(digest(data, algorithm).unwrap()), expected);
+        }
+        assert!(digest(b"abc", "sha3-256").is_err());
+    }
+
+    #[test]
+    fn test_gen_random_uuid() {
+        let mut uuid = String::new();
+        gen_random_uuid(&mut uuid);
+        assert_eq!(uuid.len(), 36);
+        // Version and variant nibbles.
+        assert_eq!(uuid.as_bytes()[14], b'4');
+        assert!(matches!(uuid.as_bytes()[19], b'8' | b'9' | b'a' | b'b'));
+        let mut another = String::new();
+        gen_random_uuid(&mut another);
+        assert_ne!(uuid, another);
+    }
+}
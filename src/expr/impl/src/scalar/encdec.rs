@@ -20,6 +20,8 @@ const PARSE_BASE64_INVALID_END: &str = "invalid base64 end sequence";
 const PARSE_BASE64_INVALID_PADDING: &str = "unexpected \"=\" while decoding base64 sequence";
 const PARSE_BASE64_ALPHABET: &[u8] =
     b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+const PARSE_BASE64URL_ALPHABET: &[u8] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
 const PARSE_BASE64_IGNORE_BYTES: [u8; 4] = [0x0D, 0x0A, 0x20, 0x09];
 // such as  'A'/0x41 -> 0  'B'/0x42 -> 1
 const PARSE_BASE64_ALPHABET_DECODE_TABLE: [u8; 123] = [
@@ -39,6 +41,9 @@ pub fn encode(data: &[u8], format: &str, writer: &mut impl std::fmt::Write) -> R
         "base64" => {
             encode_bytes_base64(data, writer)?;
         }
+        "base64url" => {
+            encode_bytes_base64url(data, writer)?;
+        }
         "hex" => {
             writer.write_str(&hex::encode(data)).unwrap();
         }
@@ -59,6 +64,7 @@ pub fn encode(data: &[u8], format: &str, writer: &mut impl std::fmt::Write) -> R
 pub fn decode(data: &str, format: &str, writer: &mut impl std::io::Write) -> Result<()> {
     match format {
         "base64" => parse_bytes_base64(data, writer),
+        "base64url" => parse_bytes_base64url(data, writer),
         "hex" => parse_bytes_hex(data, writer).map_err(|err| ExprError::Parse(err.into())),
         "escape" => {
             parse_bytes_traditional(data, writer).map_err(|err| ExprError::Parse(err.into()))
@@ -72,12 +78,14 @@ pub fn decode(data: &str, format: &str, writer: &mut impl std::io::Write) -> Res
 
 enum CharacterSet {
     Utf8,
+    Latin1,
 }
 
 impl CharacterSet {
     fn recognize(encoding: &str) -> Result<Self> {
         match encoding.to_uppercase().as_str() {
             "UTF8" | "UTF-8" => Ok(Self::Utf8),
+            "LATIN1" | "ISO88591" | "ISO-8859-1" => Ok(Self::Latin1),
             _ => Err(ExprError::InvalidParam {
                 name: "encoding",
                 reason: format!("unrecognized encoding: \"{}\"", encoding).into(),
@@ -101,6 +109,13 @@ pub fn convert_from(
             writer.write_str(&text).unwrap();
             Ok(())
         }
+        CharacterSet::Latin1 => {
+            // Every byte is a valid LATIN1 character whose code point equals the byte value.
+            for b in data {
+                writer.write_char(char::from(*b)).unwrap();
+            }
+            Ok(())
+        }
     }
 }
 
@@ -115,6 +130,23 @@ pub fn convert_to(
             writer.write_all(string.as_bytes()).unwrap();
             Ok(())
         }
+        CharacterSet::Latin1 => {
+            for c in string.chars() {
+                let code = u32::from(c);
+                if code > 0xff {
+                    return Err(ExprError::InvalidParam {
+                        name: "string",
+                        reason: format!(
+                            "character with code point U+{:04X} has no equivalent in encoding \"LATIN1\"",
+                            code
+                        )
+                        .into(),
+                    });
+                }
+                writer.write_all(&[code as u8]).unwrap();
+            }
+            Ok(())
+        }
     }
 }
 
@@ -178,6 +210,113 @@ fn encode_bytes_base64(data: &[u8], writer: &mut impl std::fmt::Write) -> Result
     Ok(())
 }
 
+// The `base64url` variant (RFC 4648 §5, as used by JWTs) uses `-`/`_` instead of `+`/`/`,
+// emits no padding, and never wraps lines.
+fn encode_bytes_base64url(data: &[u8], writer: &mut impl std::fmt::Write) -> Result<()> {
+    for chunk in data.chunks(3) {
+        let i1 = (chunk[0] >> 2) & 0b00111111;
+        writer
+            .write_char(PARSE_BASE64URL_ALPHABET[usize::from(i1)].into())
+            .unwrap();
+        match chunk.len() {
+            3 => {
+                let i2 = ((chunk[0] & 0b00000011) << 4) | ((chunk[1] >> 4) & 0b00001111);
+                let i3 = ((chunk[1] & 0b00001111) << 2) | ((chunk[2] >> 6) & 0b00000011);
+                let i4 = chunk[2] & 0b00111111;
+                writer
+                    .write_char(PARSE_BASE64URL_ALPHABET[usize::from(i2)].into())
+                    .unwrap();
+                writer
+                    .write_char(PARSE_BASE64URL_ALPHABET[usize::from(i3)].into())
+                    .unwrap();
+                writer
+                    .write_char(PARSE_BASE64URL_ALPHABET[usize::from(i4)].into())
+                    .unwrap();
+            }
+            2 => {
+                let i2 = ((chunk[0] & 0b00000011) << 4) | ((chunk[1] >> 4) & 0b00001111);
+                let i3 = (chunk[1] & 0b00001111) << 2;
+                writer
+                    .write_char(PARSE_BASE64URL_ALPHABET[usize::from(i2)].into())
+                    .unwrap();
+                writer
+                    .write_char(PARSE_BASE64URL_ALPHABET[usize::from(i3)].into())
+                    .unwrap();
+            }
+            1 => {
+                let i2 = (chunk[0] & 0b00000011) << 4;
+                writer
+                    .write_char(PARSE_BASE64URL_ALPHABET[usize::from(i2)].into())
+                    .unwrap();
+            }
+            _ => unreachable!(),
+        }
+    }
+    Ok(())
+}
+
+// Decodes the url-safe alphabet, accepting both padded and padding-less input. Whitespace is
+// not ignored since base64url payloads (e.g. JWT segments) never contain it.
+fn parse_bytes_base64url(data: &str, writer: &mut impl std::io::Write) -> Result<()> {
+    let data = data
+        .strip_suffix("==")
+        .or_else(|| data.strip_suffix('='))
+        .unwrap_or(data);
+    let mut chunks = data.as_bytes().chunks_exact(4);
+    for chunk in chunks.by_ref() {
+        let s1 = alphabet_decode_url(chunk[0])?;
+        let s2 = alphabet_decode_url(chunk[1])?;
+        let s3 = alphabet_decode_url(chunk[2])?;
+        let s4 = alphabet_decode_url(chunk[3])?;
+        writer
+            .write_all(&[s1 << 2 | s2 >> 4, s2 << 4 | s3 >> 2, s3 << 6 | s4])
+            .unwrap();
+    }
+    match chunks.remainder() {
+        [] => Ok(()),
+        [d1, d2] => {
+            let s1 = alphabet_decode_url(*d1)?;
+            let s2 = alphabet_decode_url(*d2)?;
+            writer.write_all(&[s1 << 2 | s2 >> 4]).unwrap();
+            Ok(())
+        }
+        [d1, d2, d3] => {
+            let s1 = alphabet_decode_url(*d1)?;
+            let s2 = alphabet_decode_url(*d2)?;
+            let s3 = alphabet_decode_url(*d3)?;
+            writer
+                .write_all(&[s1 << 2 | s2 >> 4, s2 << 4 | s3 >> 2])
+                .unwrap();
+            Ok(())
+        }
+        _ => Err(ExprError::Parse(PARSE_BASE64_INVALID_END.into())),
+    }
+}
+
+#[inline]
+fn alphabet_decode_url(d: u8) -> Result<u8> {
+    match d {
+        b'-' => Ok(0x3E),
+        b'_' => Ok(0x3F),
+        b'+' | b'/' => Err(ExprError::Parse(
+            format!(
+                "invalid symbol \"{}\" while decoding base64url sequence",
+                char::from(d)
+            )
+            .into(),
+        )),
+        _ => alphabet_decode(d).map_err(|_| {
+            ExprError::Parse(
+                format!(
+                    "invalid symbol \"{}\" while decoding base64url sequence",
+                    char::from(d)
+                )
+                .into(),
+            )
+        }),
+    }
+}
+
 // According to https://www.postgresql.org/docs/current/functions-binarystring.html#ENCODE-FORMAT-BASE64
 // parse_bytes_base64 need ignores carriage-return[0x0D], newline[0x0A], space[0x20], and tab[0x09].
 // When decode is supplied invalid base64 data, including incorrect trailing padding, return error.
@@ -308,7 +447,7 @@ fn encode_bytes_escape(data: &[u8], writer: &mut impl std::fmt::Write) -> std::f
 
 #[cfg(test)]
 mod tests {
-    use super::{decode, encode};
+    use super::{convert_from, convert_to, decode, encode};
 
     #[test]
     fn test_encdec() {
@@ -339,4 +478,55 @@ mod tests {
             assert_eq!(ori, &res);
         }
     }
+
+    #[test]
+    fn test_base64url() {
+        // `>>>???` exercises the bytes that differ between the two alphabets.
+        let cases = [
+            (&b"ABCDE"[..], "QUJDREU"),
+            (&b"\xfb\xef\xbe"[..], "----"),
+            (&b"\xff\xff\xff"[..], "____"),
+            (&b"a"[..], "YQ"),
+            (&b"ab"[..], "YWI"),
+        ];
+        for (ori, encoded) in cases {
+            let mut w = String::new();
+            encode(ori, "base64url", &mut w).unwrap();
+            assert_eq!(w, encoded);
+            // Padding-less round trip.
+            let mut res = Vec::new();
+            decode(&w, "base64url", &mut res).unwrap();
+            assert_eq!(ori, res);
+        }
+        // Padded input is accepted as well.
+        let mut res = Vec::new();
+        decode("YQ==", "base64url", &mut res).unwrap();
+        assert_eq!(res, b"a");
+        // The standard alphabet is rejected.
+        assert!(decode("+/==", "base64url", &mut Vec::new()).is_err());
+        // A single leftover symbol can never be a valid final quantum.
+        assert!(decode("YQZZA", "base64url", &mut Vec::new()).is_err());
+    }
+
+    #[test]
+    fn test_decode_invalid_hex() {
+        assert!(decode("61g2", "hex", &mut Vec::new()).is_err());
+        assert!(decode("616", "hex", &mut Vec::new()).is_err());
+    }
+
+    #[test]
+    fn test_convert_latin1() {
+        let mut w = String::new();
+        convert_from(b"caf\xe9", "LATIN1", &mut w).unwrap();
+        assert_eq!(w, "café");
+
+        let mut res = Vec::new();
+        convert_to("café", "LATIN1", &mut res).unwrap();
+        assert_eq!(res, b"caf\xe9");
+
+        // Not valid UTF-8.
+        assert!(convert_from(b"caf\xe9", "UTF8", &mut String::new()).is_err());
+        // No LATIN1 representation.
+        assert!(convert_to("€", "LATIN1", &mut Vec::new()).is_err());
+    }
 }
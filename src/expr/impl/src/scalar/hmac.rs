@@ -15,38 +15,46 @@
 use hmac::{Hmac, Mac};
 use risingwave_expr::{ExprError, Result, function};
 use sha1::Sha1;
-use sha2::Sha256;
+use sha2::{Sha224, Sha256, Sha384, Sha512};
 
 #[function("hmac(varchar, bytea, varchar) -> bytea")]
 pub fn hmac(secret: &str, payload: &[u8], sha_type: &str) -> Result<Box<[u8]>> {
-    if sha_type == "sha1" {
-        Ok(hmac_sha1(secret, payload))
-    } else if sha_type == "sha256" {
-        Ok(hmac_sha256(secret, payload))
-    } else {
-        Err(ExprError::InvalidParam {
+    hmac_bytea(secret.as_bytes(), payload, sha_type)
+}
+
+macro_rules! hmac_hash {
+    ($hash:ty, $secret:expr, $payload:expr) => {{
+        let mut mac =
+            Hmac::<$hash>::new_from_slice($secret).expect("HMAC can take key of any size");
+        mac.update($payload);
+
+        let code_bytes = mac.finalize().into_bytes();
+        Box::<[u8]>::from(code_bytes.as_slice())
+    }};
+}
+
+/// Variant taking the secret as bytea, for keys that are not valid UTF-8.
+#[function("hmac(bytea, bytea, varchar) -> bytea")]
+pub fn hmac_bytea(secret: &[u8], payload: &[u8], sha_type: &str) -> Result<Box<[u8]>> {
+    match sha_type {
+        "sha1" => Ok(hmac_hash!(Sha1, secret, payload)),
+        "sha224" => Ok(hmac_hash!(Sha224, secret, payload)),
+        "sha256" => Ok(hmac_hash!(Sha256, secret, payload)),
+        "sha384" => Ok(hmac_hash!(Sha384, secret, payload)),
+        "sha512" => Ok(hmac_hash!(Sha512, secret, payload)),
+        _ => Err(ExprError::InvalidParam {
             name: "sha_type",
             reason: format!("Unsupported SHA type: {}", sha_type).into(),
-        })
+        }),
     }
 }
 
 fn hmac_sha256(secret: &str, payload: &[u8]) -> Box<[u8]> {
-    let mut mac =
-        Hmac::<Sha256>::new_from_slice(secret.as_bytes()).expect("HMAC can take key of any size");
-    mac.update(payload);
-
-    let code_bytes = mac.finalize().into_bytes();
-    code_bytes.as_slice().into()
+    hmac_hash!(Sha256, secret.as_bytes(), payload)
 }
 
 fn hmac_sha1(secret: &str, payload: &[u8]) -> Box<[u8]> {
-    let mut mac =
-        Hmac::<Sha1>::new_from_slice(secret.as_bytes()).expect("HMAC can take key of any size");
-    mac.update(payload);
-
-    let code_bytes = mac.finalize().into_bytes();
-    code_bytes.as_slice().into()
+    hmac_hash!(Sha1, secret.as_bytes(), payload)
 }
 
 #[cfg(test)]
@@ -70,4 +78,30 @@ mod tests {
         let signature = "65cb920a4b8c6ab8e2eab861a096a7bc2c05d8ba";
         assert!(encode(hmac_sha1(secret, payload)) == signature);
     }
+
+    #[test]
+    fn test_hmac_rfc4231_vectors() {
+        // RFC 4231 test case 1: key = 0x0b * 20, data = "Hi There".
+        let secret = [0x0b; 20];
+        let payload = b"Hi There";
+        assert_eq!(
+            encode(hmac_bytea(&secret, payload, "sha224").unwrap()),
+            "896fb1128abbdf196832107cd49df33f47b4b1169912ba4f53684b22"
+        );
+        assert_eq!(
+            encode(hmac_bytea(&secret, payload, "sha256").unwrap()),
+            "b0344c61d8db38535ca8afceaf0bf12b881dc200c9833da726e9376c2e32cff7"
+        );
+        assert_eq!(
+            encode(hmac_bytea(&secret, payload, "sha384").unwrap()),
+            "afd03944d84895626b0825f4ab46907f15f9dadbe4101ec682aa034c7cebc59c\
+             faea9ea9076ede7f4af152e8b2fa9cb6"
+        );
+        assert_eq!(
+            encode(hmac_bytea(&secret, payload, "sha512").unwrap()),
+            "87aa7cdea5ef619d4ff0b4241a1d6cb02379f4e2ce4ec2787ad0b30545e17cde\
+             daa833b7d6b8a702038b274eaea3f4e4be9d914eeb61f1702e696c203a126854"
+        );
+        assert!(hmac_bytea(&secret, payload, "sha3-256").is_err());
+    }
 }
@@ -13,7 +13,7 @@
 // limitations under the License.
 
 use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
-use risingwave_common::types::{Date, F64, FloatExt, Time, Timestamp, Timestamptz};
+use risingwave_common::types::{Date, F64, FloatExt, Interval, Time, Timestamp, Timestamptz};
 use risingwave_expr::expr_context::TIME_ZONE;
 use risingwave_expr::{ExprError, Result, capture_context, function};
 
@@ -107,6 +107,47 @@ pub fn make_timestamptz_with_time_zone(
     make_timestamptz_impl(time_zone, year, month, day, hour, min, sec)
 }
 
+// years int, months int, weeks int, days int, hours int, mins int, secs double precision
+//
+// The binder supports named arguments with defaults (e.g. `make_interval(days => 3)`) by
+// expanding the call to this full positional form.
+#[function("make_interval(int4, int4, int4, int4, int4, int4, float8) -> interval")]
+pub fn make_interval(
+    years: i32,
+    months: i32,
+    weeks: i32,
+    days: i32,
+    hours: i32,
+    mins: i32,
+    secs: F64,
+) -> Result<Interval> {
+    if !secs.is_finite() {
+        return Err(ExprError::InvalidParam {
+            name: "secs",
+            reason: format!("invalid sec: {}", secs).into(),
+        });
+    }
+    let months = years
+        .checked_mul(12)
+        .and_then(|m| m.checked_add(months))
+        .ok_or(ExprError::NumericOutOfRange)?;
+    let days = weeks
+        .checked_mul(7)
+        .and_then(|d| d.checked_add(days))
+        .ok_or(ExprError::NumericOutOfRange)?;
+    let secs_usecs = secs.0 * 1_000_000.0;
+    if !((i64::MIN as f64)..=(i64::MAX as f64)).contains(&secs_usecs) {
+        return Err(ExprError::NumericOutOfRange);
+    }
+    let usecs = (hours as i64)
+        .checked_mul(3600)
+        .and_then(|h| (mins as i64).checked_mul(60).and_then(|m| h.checked_add(m)))
+        .and_then(|s| s.checked_mul(Interval::USECS_PER_SEC))
+        .and_then(|u| u.checked_add(secs_usecs.round_ties_even() as i64))
+        .ok_or(ExprError::NumericOutOfRange)?;
+    Ok(Interval::from_month_day_usec(months, days, usecs))
+}
+
 #[capture_context(TIME_ZONE)]
 fn make_timestamptz_impl(
     time_zone: &str,
@@ -129,6 +170,8 @@ mod tests {
     use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
     use risingwave_common::types::{Date, Timestamp};
 
+    use super::make_interval;
+
     #[test]
     fn test_naive_date_and_time() {
         let year = -1973;
@@ -155,4 +198,20 @@ mod tests {
             String::from("1974-02-02 12:34:56.789 BC")
         );
     }
+
+    #[test]
+    fn test_make_interval() {
+        // Matches `SELECT make_interval(2, 13, 1, 1, 12, 30, 4.5)` in PostgreSQL.
+        let interval = make_interval(2, 13, 1, 1, 12, 30, 4.5.into()).unwrap();
+        assert_eq!(
+            interval.to_string(),
+            String::from("3 years 1 mon 8 days 12:30:04.5")
+        );
+        // Weeks are folded into days, and negative parts are allowed.
+        let interval = make_interval(0, 0, -1, 3, 0, 0, 0.0.into()).unwrap();
+        assert_eq!(interval.to_string(), String::from("-4 days"));
+        // Overflows and non-finite seconds are rejected.
+        assert!(make_interval(i32::MAX, 1, 0, 0, 0, 0, 0.0.into()).is_err());
+        assert!(make_interval(0, 0, 0, 0, 0, 0, f64::INFINITY.into()).is_err());
+    }
 }
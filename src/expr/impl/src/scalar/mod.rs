@@ -47,12 +47,14 @@ mod crc;
 mod date_bin;
 mod date_trunc;
 mod delay;
+mod digest;
 mod encdec;
 mod exp;
 mod extract;
 mod field;
 mod format;
 mod format_type;
+mod geospatial;
 mod hmac;
 mod in_;
 mod int256;
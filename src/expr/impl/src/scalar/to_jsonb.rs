@@ -21,6 +21,7 @@ use risingwave_common::types::{
 };
 use risingwave_common::util::iter_util::ZipEqDebug;
 use risingwave_expr::expr::Context;
+use risingwave_expr::expr_context::jsonb_decimal_as_string;
 use risingwave_expr::{ExprError, Result, function};
 
 #[function("to_jsonb(*) -> jsonb")]
@@ -33,6 +34,31 @@ fn to_jsonb(
     Ok(())
 }
 
+/// Converts a row (struct) to a JSON object, preserving the declared field names as keys
+/// in declaration order. Equivalent to `to_jsonb` restricted to row inputs.
+#[function("row_to_json(struct) -> jsonb")]
+fn row_to_json(
+    input: Option<StructRef<'_>>,
+    ctx: &Context,
+    writer: &mut jsonbb::Builder,
+) -> Result<()> {
+    input.add_to(&ctx.arg_types[0], writer)?;
+    Ok(())
+}
+
+/// `row_to_json` with the `pretty` flag of PostgreSQL. The flag is accepted for
+/// compatibility but has no effect, since `jsonb` does not preserve whitespace.
+#[function("row_to_json(struct, boolean) -> jsonb")]
+fn row_to_json_pretty(
+    input: Option<StructRef<'_>>,
+    _pretty: bool,
+    ctx: &Context,
+    writer: &mut jsonbb::Builder,
+) -> Result<()> {
+    input.add_to(&ctx.arg_types[0], writer)?;
+    Ok(())
+}
+
 /// Values that can be converted to JSONB.
 ///
 /// This trait is implemented for all scalar reference types.
@@ -141,6 +167,12 @@ impl ToJsonb for F64 {
 
 impl ToJsonb for Decimal {
     fn add_to(self, t: &DataType, builder: &mut Builder) -> Result<()> {
+        // With the `jsonb_decimal_as_string` session knob on, render the decimal as a
+        // string to avoid losing precision through the conversion to double.
+        if jsonb_decimal_as_string() {
+            builder.display(ToTextDisplay(self));
+            return Ok(());
+        }
         let res: F64 = self
             .try_into()
             .map_err(|_| ExprError::CastOutOfRange("IEEE 754 double"))?;
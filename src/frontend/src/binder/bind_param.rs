@@ -227,4 +227,56 @@ mod test {
             ),
         );
     }
+
+    /// Binds `sql` without any client-specified parameter types and returns the types that
+    /// would be reported in the `ParameterDescription` message.
+    fn infer_param_types(sql: &str) -> Vec<DataType> {
+        let mut binder = mock_binder();
+        let stmt = parse_sql_statements(sql).unwrap().remove(0);
+        binder.bind(stmt).unwrap();
+        binder.export_param_types().unwrap()
+    }
+
+    #[tokio::test]
+    async fn infer_compare() {
+        assert_eq!(
+            infer_param_types("select $1 > '2021-01-01 00:00:00+00:00'::timestamptz"),
+            vec![DataType::Timestamptz]
+        );
+    }
+
+    #[tokio::test]
+    async fn infer_in_list() {
+        assert_eq!(
+            infer_param_types("select $1 in (1, 2)"),
+            vec![DataType::Int32]
+        );
+    }
+
+    #[tokio::test]
+    async fn infer_coalesce() {
+        assert_eq!(
+            infer_param_types("select coalesce($1, 1::bigint)"),
+            vec![DataType::Int64]
+        );
+    }
+
+    #[tokio::test]
+    async fn infer_case() {
+        assert_eq!(
+            infer_param_types("select case when $1 then $2 else 1.5::double precision end"),
+            vec![DataType::Boolean, DataType::Float64]
+        );
+    }
+
+    #[tokio::test]
+    async fn infer_fallback_to_varchar() {
+        // Parameters with no context to infer from default to varchar, following the `text`
+        // fallback of PostgreSQL. `$2 = $3` resolves to the varchar comparison as both sides
+        // are unknown.
+        assert_eq!(
+            infer_param_types("select $1, $2 = $3"),
+            vec![DataType::Varchar; 3]
+        );
+    }
 }
@@ -109,7 +109,7 @@ impl Binder {
                 .transpose()?,
             returning_list,
             returning_schema: if returning {
-                Some(Schema { fields })
+                Some(Schema::new(fields))
             } else {
                 None
             },
@@ -222,6 +222,7 @@ impl Binder {
                 ("make_time", raw_call(ExprType::MakeTime)),
                 ("make_timestamp", raw_call(ExprType::MakeTimestamp)),
                 ("make_timestamptz", raw_call(ExprType::MakeTimestamptz)),
+                ("make_interval", raw_call(ExprType::MakeInterval)),
                 ("timezone", guard_by_len(|_binder, [arg0, arg1]| {
                     // swap the first and second argument
                     Ok(FunctionCall::new(ExprType::AtTimeZone, vec![arg1, arg0])?.into())
@@ -302,6 +303,14 @@ impl Binder {
                 ("encrypt", raw_call(ExprType::Encrypt)),
                 ("decrypt", raw_call(ExprType::Decrypt)),
                 ("hmac", raw_call(ExprType::Hmac)),
+                ("digest", raw_call(ExprType::Digest)),
+                // geospatial
+                ("st_point", raw_call(ExprType::StPoint)),
+                ("st_x", raw_call(ExprType::StX)),
+                ("st_y", raw_call(ExprType::StY)),
+                ("st_distance", raw_call(ExprType::StDistance)),
+                ("st_dwithin", raw_call(ExprType::StDwithin)),
+                ("gen_random_uuid", raw_call(ExprType::GenRandomUuid)),
                 ("crc32", raw_call(ExprType::Crc32)),
                 ("crc32c", raw_call(ExprType::Crc32c)),
                 ("secure_compare", raw_call(ExprType::SecureCompare)),
@@ -413,6 +422,7 @@ impl Binder {
                 ("jsonb_delete_path", raw_call(ExprType::JsonbDeletePath)),
                 ("jsonb_strip_nulls", raw_call(ExprType::JsonbStripNulls)),
                 ("to_jsonb", raw_call(ExprType::ToJsonb)),
+                ("row_to_json", raw_call(ExprType::RowToJson)),
                 ("jsonb_build_array", raw_call(ExprType::JsonbBuildArray)),
                 ("jsonb_build_object", raw_call(ExprType::JsonbBuildObject)),
                 ("jsonb_populate_record", raw_call(ExprType::JsonbPopulateRecord)),
@@ -140,8 +140,11 @@ impl Binder {
             return Ok(ExprImpl::literal_varchar("".to_owned()));
         }
 
-        // special binding logic for `array_transform` and `map_filter`
-        if func_name == "array_transform" || func_name == "map_filter" {
+        // special binding logic for `array_transform`, `map_filter` and `make_interval`
+        if func_name == "array_transform"
+            || func_name == "map_filter"
+            || func_name == "make_interval"
+        {
             return self.validate_and_bind_special_function_params(
                 &func_name,
                 *scalar_as_agg,
@@ -191,17 +194,51 @@ impl Binder {
         } else {
             Self::bind_function_arg
         };
-        let mut args: Vec<ExprImpl> = arg_list
-            .args
-            .iter()
-            .map(|arg| bind_arg(self, arg))
-            .flatten_ok()
-            .try_collect()?;
+        let mut args: Vec<ExprImpl> = Vec::with_capacity(arg_list.args.len());
+        // Arguments given in named notation (`name => expr`), only supported for UDF calls.
+        let mut named_args: Vec<(String, ExprImpl)> = Vec::new();
+        for arg in &arg_list.args {
+            match arg {
+                FunctionArg::Named {
+                    name,
+                    arg: FunctionArgExpr::Expr(expr),
+                } => {
+                    named_args.push((name.real_value(), self.bind_expr_inner(expr)?));
+                }
+                FunctionArg::Named { arg, .. } => {
+                    return Err(ErrorCode::InvalidInputSyntax(format!(
+                        "unexpected wildcard {} in named argument",
+                        arg
+                    ))
+                    .into());
+                }
+                FunctionArg::Unnamed(_) => {
+                    reject_syntax!(
+                        !named_args.is_empty(),
+                        "positional argument cannot follow named argument"
+                    );
+                    args.extend(bind_arg(self, arg)?);
+                }
+            }
+        }
+        for (i, (name, _)) in named_args.iter().enumerate() {
+            if named_args[..i].iter().any(|(n, _)| n == name) {
+                return Err(ErrorCode::InvalidInputSyntax(format!(
+                    "argument name \"{}\" used more than once",
+                    name
+                ))
+                .into());
+            }
+        }
 
         let mut referred_udfs = HashSet::new();
         let mut is_udf_call = false;
 
         let wrapped_agg_type = if *scalar_as_agg {
+            reject_syntax!(
+                !named_args.is_empty(),
+                "named arguments are not allowed in `AGGREGATE:` function call"
+            );
             // Let's firstly try to apply the `AGGREGATE:` prefix.
             // We will reject functions that are not able to be wrapped as aggregate function.
             let mut array_args = args
@@ -258,14 +295,12 @@ impl Binder {
             None
         };
 
-        let schema_path = self.bind_schema_path(schema_name.as_deref());
-        let udf = if wrapped_agg_type.is_none()
-            && let Ok((func, _)) = self.catalog.get_function_by_name_inputs(
-                &self.db_name,
-                schema_path,
-                &func_name,
-                &mut args,
-            ) {
+        let udf = if wrapped_agg_type.is_none() {
+            self.resolve_udf_call(schema_name.as_deref(), &func_name, &mut args, &named_args)?
+        } else {
+            None
+        };
+        if let Some(ref func) = udf {
             // record the dependency upon the UDF
             referred_udfs.insert(func.id);
             is_udf_call = true;
@@ -273,10 +308,7 @@ impl Binder {
                 ObjectCheckItem::new(func.owner, AclMode::Execute, func.name.clone(), func.id),
                 self.database_id,
             )?;
-            Some(func.clone())
-        } else {
-            None
-        };
+        }
 
         if has_secret_ref_arg && !is_udf_call {
             return Err(ErrorCode::InvalidInputSyntax(
@@ -495,7 +527,7 @@ impl Binder {
         filter: Option<&risingwave_sqlparser::ast::Expr>,
         over: Option<&Window>,
     ) -> Result<ExprImpl> {
-        assert!(["array_transform", "map_filter"].contains(&func_name));
+        assert!(["array_transform", "map_filter", "make_interval"].contains(&func_name));
 
         reject_syntax!(
             scalar_as_agg,
@@ -522,10 +554,10 @@ impl Binder {
             "`OVER` is not allowed in `{}` call",
             func_name
         );
-        if func_name == "array_transform" {
-            self.bind_array_transform(&arg_list.args)
-        } else {
-            self.bind_map_filter(&arg_list.args)
+        match func_name {
+            "array_transform" => self.bind_array_transform(&arg_list.args),
+            "map_filter" => self.bind_map_filter(&arg_list.args),
+            _ => self.bind_make_interval(&arg_list.args),
         }
     }
 
@@ -588,6 +620,80 @@ impl Binder {
         )))
     }
 
+    /// Binds a `make_interval` call, expanding named arguments and defaults into the full
+    /// positional form, following PostgreSQL semantics: positional arguments fill the
+    /// parameters in order, named arguments (`days => 3`) may follow, and omitted
+    /// parameters default to zero.
+    fn bind_make_interval(&mut self, args: &[FunctionArg]) -> Result<ExprImpl> {
+        const PARAMS: [&str; 7] = ["years", "months", "weeks", "days", "hours", "mins", "secs"];
+
+        let mut bound: [Option<ExprImpl>; 7] = Default::default();
+        let mut seen_named = false;
+        for (position, arg) in args.iter().enumerate() {
+            let (index, arg_expr) = match arg {
+                FunctionArg::Unnamed(arg_expr) => {
+                    if seen_named {
+                        return Err(ErrorCode::InvalidInputSyntax(
+                            "positional argument cannot follow named argument".to_owned(),
+                        )
+                        .into());
+                    }
+                    (position, arg_expr)
+                }
+                FunctionArg::Named { name, arg } => {
+                    seen_named = true;
+                    let name = name.real_value();
+                    let index = PARAMS.iter().position(|p| *p == name).ok_or_else(|| {
+                        ErrorCode::BindError(format!(
+                            "`make_interval` has no parameter named \"{}\"",
+                            name
+                        ))
+                    })?;
+                    (index, arg)
+                }
+            };
+            if index >= PARAMS.len() {
+                return Err(ErrorCode::BindError(format!(
+                    "`make_interval` expects at most {} arguments, but {} were given",
+                    PARAMS.len(),
+                    args.len()
+                ))
+                .into());
+            }
+            if bound[index].is_some() {
+                return Err(ErrorCode::BindError(format!(
+                    "parameter \"{}\" of `make_interval` specified more than once",
+                    PARAMS[index]
+                ))
+                .into());
+            }
+            let exprs = self.bind_function_expr_arg(arg_expr)?;
+            let [expr] = <[ExprImpl; 1]>::try_from(exprs).map_err(|exprs| -> RwError {
+                ErrorCode::BindError(format!(
+                    "arguments of `make_interval` should be bound to one expression, but {} were got",
+                    exprs.len()
+                ))
+                .into()
+            })?;
+            bound[index] = Some(expr);
+        }
+
+        let args = bound
+            .into_iter()
+            .enumerate()
+            .map(|(index, expr)| {
+                expr.unwrap_or_else(|| {
+                    if PARAMS[index] == "secs" {
+                        ExprImpl::literal_f64(0.0)
+                    } else {
+                        ExprImpl::literal_int(0)
+                    }
+                })
+            })
+            .collect();
+        self.bind_builtin_scalar_function("make_interval", args, false)
+    }
+
     fn bind_unary_lambda_function(
         &mut self,
         input_ty: DataType,
@@ -801,6 +907,186 @@ impl Binder {
         self.bind_sql_udf_inner(body, &func.arg_names, args)
     }
 
+    /// Resolves a function call against the user-defined functions in the catalog,
+    /// supporting named notation (`name => expr`) and parameter defaults.
+    ///
+    /// On a match, `args` is replaced with the completed positional argument list: named
+    /// arguments are reordered into place and omitted parameters are filled with their
+    /// `DEFAULT` expressions. `Ok(None)` is only returned for all-positional calls that
+    /// match no UDF, so that builtin function resolution can take over.
+    fn resolve_udf_call(
+        &mut self,
+        schema_name: Option<&str>,
+        func_name: &str,
+        args: &mut Vec<ExprImpl>,
+        named_args: &[(String, ExprImpl)],
+    ) -> Result<Option<Arc<FunctionCatalog>>> {
+        // An all-positional call that matches an overload exactly (up to implicit casts,
+        // which are applied to `args` here). This is not returned right away: an overload
+        // reachable by filling defaults below still makes the call ambiguous.
+        let exact = if named_args.is_empty() {
+            let schema_path = self.bind_schema_path(schema_name);
+            self.catalog
+                .get_function_by_name_inputs(&self.db_name, schema_path, func_name, args)
+                .ok()
+                .map(|(func, _)| func.clone())
+        } else {
+            None
+        };
+
+        // Find the overloads that the call can be completed to: positional
+        // arguments fill the leading parameters, named arguments are mapped through the
+        // parameter names, and every remaining parameter must have a default.
+        let candidates = {
+            let schema_path = self.bind_schema_path(schema_name);
+            match self
+                .catalog
+                .get_functions_by_name(&self.db_name, schema_path, func_name)
+            {
+                Ok((functions, _)) => functions.into_iter().cloned().collect_vec(),
+                // Named notation is only supported for UDF calls, so surface the
+                // not-found error; otherwise let builtin resolution take over.
+                Err(_) if named_args.is_empty() => return Ok(exact),
+                Err(e) => return Err(e.into()),
+            }
+        };
+
+        let mut viable = Vec::new();
+        'candidate: for func in candidates {
+            let arity = func.arg_types.len();
+            let num_non_default = arity - func.arg_defaults.len();
+            // Exact-arity positional calls were already covered by the fast path above.
+            if args.len() > arity || (named_args.is_empty() && args.len() == arity) {
+                continue;
+            }
+            let mut named_positions = Vec::with_capacity(named_args.len());
+            for (name, _) in named_args {
+                let Some(pos) = func.arg_names.iter().position(|n| n == name) else {
+                    continue 'candidate;
+                };
+                if pos < args.len() || named_positions.contains(&pos) {
+                    // Specified both positionally and by name, or named twice.
+                    continue 'candidate;
+                }
+                named_positions.push(pos);
+            }
+            for pos in args.len()..arity {
+                if !named_positions.contains(&pos) && pos < num_non_default {
+                    continue 'candidate;
+                }
+            }
+            viable.push((func, named_positions));
+        }
+
+        if let Some(exact) = exact {
+            if viable.is_empty() {
+                return Ok(Some(exact));
+            }
+            // The call also completes to another overload by filling defaults.
+            return Err(ErrorCode::BindError(format!(
+                "function \"{}\" is not unique\n\
+                 HINT: Could not choose a best candidate function. \
+                 You might need to add explicit type casts.",
+                func_name
+            ))
+            .into());
+        }
+
+        let (func, named_positions) = match viable.len() {
+            0 if named_args.is_empty() => return Ok(None),
+            0 => {
+                return Err(ErrorCode::BindError(format!(
+                    "function {}({}) does not exist",
+                    func_name,
+                    args.iter()
+                        .map(|arg| arg.return_type().to_string())
+                        .chain(named_args.iter().map(|(name, arg)| format!(
+                            "{} => {}",
+                            name,
+                            arg.return_type()
+                        )))
+                        .join(", ")
+                ))
+                .into());
+            }
+            1 => viable.into_iter().next().unwrap(),
+            _ => {
+                return Err(ErrorCode::BindError(format!(
+                    "function \"{}\" is not unique\n\
+                     HINT: Could not choose a best candidate function. \
+                     You might need to add explicit type casts.",
+                    func_name
+                ))
+                .into());
+            }
+        };
+
+        let arity = func.arg_types.len();
+        let num_non_default = arity - func.arg_defaults.len();
+        let mut completed: Vec<Option<ExprImpl>> = args.iter().cloned().map(Some).collect();
+        completed.resize(arity, None);
+        for ((_, arg), pos) in named_args.iter().zip_eq_fast(&named_positions) {
+            completed[*pos] = Some(arg.clone());
+        }
+        let mut completed: Vec<ExprImpl> = completed
+            .into_iter()
+            .enumerate()
+            .map(|(pos, arg)| match arg {
+                Some(arg) => Ok(arg),
+                None => self.bind_udf_arg_default(&func, pos, num_non_default),
+            })
+            .try_collect()?;
+
+        // Re-run type inference on the completed call, both to apply implicit casts and
+        // to make sure it indeed resolves to the candidate chosen above.
+        let schema_path = self.bind_schema_path(schema_name);
+        match self.catalog.get_function_by_name_inputs(
+            &self.db_name,
+            schema_path,
+            func_name,
+            &mut completed,
+        ) {
+            Ok((matched, _)) if matched.id == func.id => {
+                let matched = matched.clone();
+                *args = completed;
+                Ok(Some(matched))
+            }
+            Ok(_) => Err(ErrorCode::BindError(format!(
+                "function \"{}\" is not unique\n\
+                 HINT: Could not choose a best candidate function. \
+                 You might need to add explicit type casts.",
+                func_name
+            ))
+            .into()),
+            Err(_) if named_args.is_empty() => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Binds the `DEFAULT` expression of the `pos`-th parameter of `func`.
+    ///
+    /// Defaults are stored as SQL text and validated to be free of parameter references
+    /// at `CREATE FUNCTION` time, so any SQL UDF arguments currently in scope are stashed
+    /// away to keep an outer UDF's parameters from being captured accidentally.
+    fn bind_udf_arg_default(
+        &mut self,
+        func: &FunctionCatalog,
+        pos: usize,
+        num_non_default: usize,
+    ) -> Result<ExprImpl> {
+        let ast =
+            Parser::parse_expr_str(&func.arg_defaults[pos - num_non_default]).map_err(|e| {
+                ErrorCode::BindError(format!(
+                    "failed to parse the default expression of parameter \"{}\": {}",
+                    func.arg_names[pos], e
+                ))
+            })?;
+        let stashed_arguments = self.context.sql_udf_arguments.take();
+        let bound = self.bind_expr(&ast);
+        self.context.sql_udf_arguments = stashed_arguments;
+        Ok(bound?.cast_implicit(&func.arg_types[pos])?)
+    }
+
     pub(in crate::binder) fn bind_function_expr_arg(
         &mut self,
         arg_expr: &FunctionArgExpr,
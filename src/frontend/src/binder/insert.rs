@@ -384,7 +384,7 @@ impl Binder {
             cast_exprs,
             returning_list,
             returning_schema: if is_returning {
-                Some(Schema { fields })
+                Some(Schema::new(fields))
             } else {
                 None
             },
@@ -722,11 +722,9 @@ mod tests {
                             ),
                             having: None,
                             window: {},
-                            schema: Schema {
-                                fields: [
+                            schema: Schema::new([
                                     approx_percentile:Float64,
-                                ],
-                            },
+                                ]),
                         },
                     ),
                     order: [],
@@ -333,7 +333,7 @@ impl Binder {
             group_by,
             having,
             window: named_windows,
-            schema: Schema { fields },
+            schema: Schema::new(fields),
         })
     }
 
@@ -285,7 +285,7 @@ impl Binder {
             exprs,
             returning_list,
             returning_schema: if returning {
-                Some(Schema { fields })
+                Some(Schema::new(fields))
             } else {
                 None
             },
@@ -32,6 +32,9 @@ pub struct FunctionCatalog {
     pub kind: FunctionKind,
     pub arg_names: Vec<String>,
     pub arg_types: Vec<DataType>,
+    /// SQL text of the `DEFAULT` expressions of the trailing parameters, aligned to the
+    /// end of `arg_names` / `arg_types`.
+    pub arg_defaults: Vec<String>,
     pub return_type: DataType,
     pub language: String,
     pub runtime: Option<String>,
@@ -74,6 +77,7 @@ impl From<&PbFunction> for FunctionCatalog {
             kind: prost.kind.as_ref().unwrap().into(),
             arg_names: prost.arg_names.clone(),
             arg_types: prost.arg_types.iter().map(|arg| arg.into()).collect(),
+            arg_defaults: prost.arg_defaults.clone(),
             return_type: prost.return_type.as_ref().expect("no return type").into(),
             language: prost.language.clone(),
             runtime: prost.runtime.clone(),
@@ -17,7 +17,7 @@ use risingwave_common::util::epoch::Epoch;
 use risingwave_connector::{WithOptionsSecResolved, WithPropertiesExt};
 use risingwave_pb::catalog::{PbSource, StreamSourceInfo, WatermarkDesc};
 use risingwave_pb::plan_common::SourceRefreshMode;
-use risingwave_sqlparser::ast;
+use risingwave_sqlparser::ast::{self, RedactSqlOptionKeywordsRef};
 use risingwave_sqlparser::parser::Parser;
 use thiserror_ext::AsReport as _;
 
@@ -156,6 +156,14 @@ impl SourceCatalog {
         self.create_sql_ast()
     }
 
+    /// Returns the purified SQL definition with sensitive WITH options redacted, for
+    /// rendering to users, e.g. in `SHOW CREATE SOURCE` and `rw_catalog.rw_sources`.
+    pub fn create_sql_redacted(&self, keywords: RedactSqlOptionKeywordsRef) -> String {
+        self.create_sql_ast_purified()
+            .map(|stmt| stmt.to_redacted_string(keywords))
+            .unwrap_or_else(|_| self.create_sql())
+    }
+
     /// Fills the `definition` field with the purified SQL definition.
     ///
     /// There's no need to call this method for correctness because we automatically purify the
@@ -39,6 +39,10 @@ pub struct SubscriptionCatalog {
     /// The retention seconds of the subscription.
     pub retention_seconds: u64,
 
+    /// The changelog operations exposed to cursors on this subscription, as lowercase
+    /// tokens like `insert`. An empty list means all operations.
+    pub ops: Vec<String>,
+
     /// The database id
     pub database_id: DatabaseId,
 
@@ -77,6 +81,43 @@ impl SubscriptionCatalog {
         Ok(())
     }
 
+    /// Parses the optional `ops` property, a comma-separated list of the changelog
+    /// operations to expose: `insert`, `delete`, `update_insert`, `update_delete`, or
+    /// `update` as a shorthand for both update halves. When absent, all operations are
+    /// exposed.
+    pub fn set_ops(&mut self, properties: &WithOptions) -> Result<()> {
+        let Some(ops_str) = properties.get("ops") else {
+            return Ok(());
+        };
+        let mut ops = Vec::new();
+        for token in ops_str.split(',') {
+            let token = token.trim().to_lowercase();
+            match token.as_str() {
+                "insert" | "delete" | "update_insert" | "update_delete" => {
+                    if !ops.contains(&token) {
+                        ops.push(token);
+                    }
+                }
+                "update" => {
+                    for token in ["update_insert", "update_delete"] {
+                        if !ops.iter().any(|op| op == token) {
+                            ops.push(token.to_owned());
+                        }
+                    }
+                }
+                _ => {
+                    return Err(ErrorCode::InvalidParameterValue(format!(
+                        "invalid op \"{token}\": expected one of insert, delete, \
+                         update_insert, update_delete, update"
+                    ))
+                    .into());
+                }
+            }
+        }
+        self.ops = ops;
+        Ok(())
+    }
+
     pub fn create_sql(&self) -> String {
         self.definition.clone()
     }
@@ -87,6 +128,7 @@ impl SubscriptionCatalog {
             name: self.name.clone(),
             definition: self.definition.clone(),
             retention_seconds: self.retention_seconds,
+            ops: self.ops.clone(),
             database_id: self.database_id,
             schema_id: self.schema_id,
             initialized_at_epoch: self.initialized_at_epoch.map(|e| e.0),
@@ -110,6 +152,7 @@ impl From<&PbSubscription> for SubscriptionCatalog {
             name: prost.name.clone(),
             definition: prost.definition.clone(),
             retention_seconds: prost.retention_seconds,
+            ops: prost.ops.clone(),
             database_id: prost.database_id,
             schema_id: prost.schema_id,
             dependent_table_id: prost.dependent_table_id,
@@ -28,13 +28,32 @@ use risingwave_frontend_macro::system_catalog;
         s.name AS table_schema,
         r.name AS table_name,
         c.name AS column_name,
-        NULL AS column_default,
+        c.default_expression AS column_default,
         NULL::integer AS character_maximum_length,
-        NULL::integer AS numeric_precision,
-        NULL::integer AS numeric_precision_radix,
-        NULL::integer AS numeric_scale,
-        NULL::integer AS datetime_precision,
-        c.position AS ordinal_position,
+        CASE c.udt_type
+            WHEN 'int2' THEN 16
+            WHEN 'int4' THEN 32
+            WHEN 'int8' THEN 64
+            WHEN 'float4' THEN 24
+            WHEN 'float8' THEN 53
+            ELSE NULL::integer
+        END AS numeric_precision,
+        CASE
+            WHEN c.udt_type IN ('int2', 'int4', 'int8', 'float4', 'float8') THEN 2
+            WHEN c.udt_type = 'numeric' THEN 10
+            ELSE NULL::integer
+        END AS numeric_precision_radix,
+        CASE
+            WHEN c.udt_type IN ('int2', 'int4', 'int8') THEN 0
+            ELSE NULL::integer
+        END AS numeric_scale,
+        CASE
+            WHEN c.udt_type IN ('time', 'timestamp', 'timestamptz', 'interval') THEN 6
+            WHEN c.udt_type = 'date' THEN 0
+            ELSE NULL::integer
+        END AS datetime_precision,
+        ROW_NUMBER() OVER (PARTITION BY c.relation_id ORDER BY c.position)::integer
+            AS ordinal_position,
         CASE
             WHEN c.is_nullable THEN 'YES'
             ELSE 'NO'
@@ -70,7 +89,8 @@ use risingwave_frontend_macro::system_catalog;
             ELSE 'NEVER'
         END AS is_generated,
         c.generation_expression,
-        NULL AS interval_type
+        NULL AS interval_type,
+        c.description AS rw_description
     FROM rw_catalog.rw_columns c
     LEFT JOIN rw_catalog.rw_relations r ON c.relation_id = r.id
     JOIN rw_catalog.rw_schemas s ON s.id = r.schema_id
@@ -116,4 +136,6 @@ struct Column {
     is_generated: String,
     generation_expression: String,
     interval_type: String,
+    /// RisingWave extension: the comment on the column, see `COMMENT ON`.
+    rw_description: String,
 }
@@ -39,6 +39,7 @@ use risingwave_common::types::DataType;
 use risingwave_pb::meta::list_streaming_job_states_response::StreamingJobState;
 use risingwave_pb::meta::table_parallelism::{PbFixedParallelism, PbParallelism};
 use risingwave_pb::user::grant_privilege::Object as GrantObject;
+use risingwave_sqlparser::ast::RedactSqlOptionKeywordsRef;
 
 use crate::catalog::catalog_service::CatalogReader;
 use crate::catalog::view_catalog::ViewCatalog;
@@ -105,6 +106,8 @@ pub struct SysCatalogReaderImpl {
     system_params: SystemParamsReaderRef,
     // Read metrics.
     pub(super) metrics_reader: Arc<dyn MetricsReader>,
+    // Keywords for redacting sensitive SQL options in rendered definitions.
+    redact_sql_option_keywords: RedactSqlOptionKeywordsRef,
 }
 
 impl SysCatalogReaderImpl {
@@ -116,6 +119,7 @@ impl SysCatalogReaderImpl {
         config: Arc<RwLock<SessionConfig>>,
         system_params: SystemParamsReaderRef,
         metrics_reader: Arc<dyn MetricsReader>,
+        redact_sql_option_keywords: RedactSqlOptionKeywordsRef,
     ) -> Self {
         Self {
             catalog_reader,
@@ -125,6 +129,7 @@ impl SysCatalogReaderImpl {
             config,
             system_params,
             metrics_reader,
+            redact_sql_option_keywords,
         }
     }
 }
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use risingwave_common::catalog::Field;
 use risingwave_common::types::Fields;
 use risingwave_frontend_macro::system_catalog;
 use risingwave_pb::id::{ObjectId, RelationId, SchemaId};
@@ -54,21 +55,52 @@ struct PgConstraint {
     conbin: Option<String>,
 }
 
+/// Derives a stable synthetic OID for a constraint from the relation id and the
+/// ordinal of the constraint within the relation.
+///
+/// Ordinal 0 is reserved for the primary key, so the OID of a `*_pkey` constraint
+/// stays equal to the relation id as it always has been. Relation ids stay far below
+/// `2^24` in practice, so shifting the ordinal into the high bits keeps the synthetic
+/// OIDs unique across relations.
+fn constraint_oid(relation_id: RelationId, ordinal: u32) -> ObjectId {
+    ObjectId::new(relation_id.as_raw_id().wrapping_add(ordinal << 24))
+}
+
 impl PgConstraint {
     fn from_system_table(schema: &SchemaCatalog, table: &SystemTableCatalog) -> PgConstraint {
         // List of the constrained columns. First column starts from 1.
         let conkey: Vec<_> = table.pk.iter().map(|i| (*i + 1) as i16).collect();
+        PgConstraint::primary_key(schema.id(), table.id.as_relation_id(), &table.name, conkey)
+    }
+
+    fn from_table(schema: &SchemaCatalog, table: &TableCatalog) -> PgConstraint {
+        // List of the constrained columns. First column starts from 1.
+        let conkey: Vec<_> = table
+            .pk
+            .iter()
+            .map(|i| (i.column_index + 1) as i16)
+            .collect();
+        PgConstraint::primary_key(schema.id(), table.id.as_relation_id(), &table.name, conkey)
+    }
+
+    fn primary_key(
+        connamespace: SchemaId,
+        relation_id: RelationId,
+        relation_name: &str,
+        conkey: Vec<i16>,
+    ) -> PgConstraint {
         PgConstraint {
-            oid: table.id.as_object_id(), // Use table_id as a mock oid of constraint here.
-            conname: format!("{}_pkey", &table.name),
-            connamespace: schema.id(),
+            oid: constraint_oid(relation_id, 0),
+            conname: format!("{}_pkey", relation_name),
+            connamespace,
             contype: "p".to_owned(), // p = primary key constraint
             condeferrable: false,
             convalidated: true,
-            conrelid: table.id.as_relation_id(),
+            conrelid: relation_id,
             contypid: 0,
-            // Use table_id as a mock index oid of constraint here.
-            conindid: table.id.as_relation_id(),
+            // Use the relation id as a mock index oid of the constraint here, matching
+            // the primary-key rows synthesized in `pg_index`.
+            conindid: relation_id,
             conparentid: 0,
             confrelid: 0,
             confupdtype: " ".to_owned(),
@@ -88,34 +120,38 @@ impl PgConstraint {
         }
     }
 
-    fn from_table(schema: &SchemaCatalog, table: &TableCatalog) -> PgConstraint {
-        // List of the constrained columns. First column starts from 1.
-        let conkey: Vec<_> = table
-            .pk
-            .iter()
-            .map(|i| (i.column_index + 1) as i16)
-            .collect();
+    #[allow(clippy::too_many_arguments)]
+    fn foreign_key(
+        connamespace: SchemaId,
+        relation_id: RelationId,
+        relation_name: &str,
+        ordinal: u32,
+        field_name: &str,
+        conkey: Vec<i16>,
+        confrelid: RelationId,
+        confkey: Vec<i16>,
+    ) -> PgConstraint {
         PgConstraint {
-            oid: table.id.as_object_id(), // Use table_id as a mock oid of constraint here.
-            conname: format!("{}_pkey", &table.name),
-            connamespace: schema.id(),
-            contype: "p".to_owned(), // p = primary key constraint
+            oid: constraint_oid(relation_id, ordinal),
+            conname: format!("{}_{}_fkey", relation_name, field_name),
+            connamespace,
+            contype: "f".to_owned(), // f = foreign key constraint
             condeferrable: false,
             convalidated: true,
-            conrelid: table.id.as_relation_id(),
+            conrelid: relation_id,
             contypid: 0,
-            // Use table_id as a mock index oid of constraint here.
-            conindid: table.id.as_relation_id(),
+            // Foreign keys are informational only, so there is no backing index.
+            conindid: RelationId::new(0),
             conparentid: 0,
-            confrelid: 0,
-            confupdtype: " ".to_owned(),
-            confdeltype: " ".to_owned(),
-            confmatchtype: " ".to_owned(),
+            confrelid: confrelid.as_raw_id() as i32,
+            confupdtype: "a".to_owned(),   // a = no action
+            confdeltype: "a".to_owned(),   // a = no action
+            confmatchtype: "s".to_owned(), // s = simple
             conislocal: true,
             coninhcount: 0,
             connoinherit: true,
             conkey: Some(conkey),
-            confkey: None,
+            confkey: Some(confkey),
             conpfeqop: None,
             conppeqop: None,
             conffeqop: None,
@@ -126,6 +162,55 @@ impl PgConstraint {
     }
 }
 
+/// Builds `contype = 'f'` rows from the informational foreign-key annotations on the
+/// relation's fields (see [`Field::parse_foreign_key`]).
+///
+/// `resolve_table` maps a referenced table name to its relation id and column names in
+/// attribute-number order. Annotations that are malformed, reference an unknown table
+/// or column, or reference more than one column (a single field cannot match a
+/// composite key) are skipped: `pg_constraint` is introspection-only and must not fail
+/// the whole scan.
+fn foreign_key_rows(
+    connamespace: SchemaId,
+    relation_id: RelationId,
+    relation_name: &str,
+    fields: &[Field],
+    resolve_table: impl Fn(&str) -> Option<(RelationId, Vec<String>)>,
+) -> Vec<PgConstraint> {
+    let mut rows = vec![];
+    // Ordinal 0 is the primary key; foreign keys start from 1.
+    let mut ordinal = 1;
+    for (index, field) in fields.iter().enumerate() {
+        let Ok(Some(fk)) = field.parse_foreign_key() else {
+            continue;
+        };
+        let [referenced_column] = fk.columns.as_slice() else {
+            continue;
+        };
+        let Some((confrelid, referenced_columns)) = resolve_table(&fk.table) else {
+            continue;
+        };
+        let Some(position) = referenced_columns
+            .iter()
+            .position(|name| name == referenced_column)
+        else {
+            continue;
+        };
+        rows.push(PgConstraint::foreign_key(
+            connamespace,
+            relation_id,
+            relation_name,
+            ordinal,
+            &field.name,
+            vec![(index + 1) as i16],
+            confrelid,
+            vec![(position + 1) as i16],
+        ));
+        ordinal += 1;
+    }
+    rows
+}
+
 #[system_catalog(table, "pg_catalog.pg_constraint")]
 fn read_pg_constraint(reader: &SysCatalogReaderImpl) -> Result<Vec<PgConstraint>> {
     let catalog_reader = reader.catalog_reader.read_guard();
@@ -135,14 +220,93 @@ fn read_pg_constraint(reader: &SysCatalogReaderImpl) -> Result<Vec<PgConstraint>
 }
 
 fn read_pg_constraint_in_schema(schema: &SchemaCatalog) -> Vec<PgConstraint> {
-    // Note: We only support primary key constraints now.
     let system_table_rows = schema
         .iter_system_tables()
         .map(|table| PgConstraint::from_system_table(schema, table.as_ref()));
 
-    let table_rows = schema
-        .iter_table_mv_indices()
-        .map(|table| PgConstraint::from_table(schema, table.as_ref()));
+    let table_rows = schema.iter_table_mv_indices().flat_map(|table| {
+        let pkey = PgConstraint::from_table(schema, table.as_ref());
+        // Foreign-key annotations are recorded from `REFERENCES` column options
+        // at `CREATE TABLE` time. They are informational only and not enforced.
+        let fields: Vec<_> = table
+            .columns
+            .iter()
+            .map(|column| Field::from(&column.column_desc))
+            .collect();
+        let fkeys = foreign_key_rows(
+            schema.id(),
+            table.id.as_relation_id(),
+            &table.name,
+            &fields,
+            |name| {
+                schema.get_created_table_by_name(name).map(|referenced| {
+                    let columns = referenced
+                        .columns
+                        .iter()
+                        .map(|column| column.name().to_owned())
+                        .collect();
+                    (referenced.id.as_relation_id(), columns)
+                })
+            },
+        );
+        std::iter::once(pkey).chain(fkeys)
+    });
 
     system_table_rows.chain(table_rows).collect()
 }
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::types::DataType;
+
+    use super::*;
+
+    #[test]
+    fn test_foreign_key_rows() {
+        let fields = vec![
+            Field::with_name(DataType::Int64, "id"),
+            Field::with_name(DataType::Int64, "user_id").with_foreign_key("users(id)"),
+            // Composite references and dangling annotations are skipped.
+            Field::with_name(DataType::Int64, "pair").with_foreign_key("users(id, seq)"),
+            Field::with_name(DataType::Int64, "ghost").with_foreign_key("missing(id)"),
+            Field::with_name(DataType::Varchar, "user_name").with_foreign_key("users(name)"),
+        ];
+
+        let rows = foreign_key_rows(
+            SchemaId::new(2),
+            RelationId::new(100),
+            "orders",
+            &fields,
+            |name| {
+                (name == "users").then(|| {
+                    (
+                        RelationId::new(200),
+                        vec!["id".to_owned(), "name".to_owned()],
+                    )
+                })
+            },
+        );
+
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0].conname, "orders_user_id_fkey");
+        assert_eq!(rows[0].contype, "f");
+        assert_eq!(rows[0].oid, constraint_oid(RelationId::new(100), 1));
+        assert_eq!(rows[0].conkey, Some(vec![2]));
+        assert_eq!(rows[0].confrelid, 200);
+        assert_eq!(rows[0].confkey, Some(vec![1]));
+        assert_eq!(rows[1].conname, "orders_user_name_fkey");
+        assert_eq!(rows[1].oid, constraint_oid(RelationId::new(100), 2));
+        assert_eq!(rows[1].conkey, Some(vec![5]));
+        assert_eq!(rows[1].confkey, Some(vec![2]));
+    }
+
+    #[test]
+    fn test_constraint_oid_stability() {
+        // The primary key keeps the historical `oid == relation id` mapping.
+        assert_eq!(constraint_oid(RelationId::new(42), 0), ObjectId::new(42));
+        assert_ne!(
+            constraint_oid(RelationId::new(42), 1),
+            constraint_oid(RelationId::new(42), 2)
+        );
+    }
+}
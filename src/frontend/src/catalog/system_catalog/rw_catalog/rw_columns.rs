@@ -41,6 +41,10 @@ struct RwColumn {
     type_oid: i32,
     type_len: i16,
     udt_type: String,
+    // the default expression if one was explicitly set
+    default_expression: Option<String>,
+    // the comment on the column, see `COMMENT ON`
+    description: Option<String>,
 }
 
 #[system_catalog(table, "rw_catalog.rw_columns")]
@@ -76,6 +80,8 @@ fn read_rw_columns_in_schema(current_user: &UserCatalog, schema: &SchemaCatalog)
                 type_oid: column.data_type().to_oid(),
                 type_len: column.data_type().type_len(),
                 udt_type: column.data_type().pg_name().into(),
+                default_expression: None,
+                description: column.description.clone(),
             })
     });
 
@@ -99,6 +105,8 @@ fn read_rw_columns_in_schema(current_user: &UserCatalog, schema: &SchemaCatalog)
                 type_oid: column.data_type().to_oid(),
                 type_len: column.data_type().type_len(),
                 udt_type: column.data_type().pg_name().into(),
+                default_expression: None,
+                description: column.column_desc.description.clone(),
             })
     });
 
@@ -121,6 +129,8 @@ fn read_rw_columns_in_schema(current_user: &UserCatalog, schema: &SchemaCatalog)
                 type_oid: column.data_type().to_oid(),
                 type_len: column.data_type().type_len(),
                 udt_type: column.data_type().pg_name().into(),
+                default_expression: None,
+                description: column.column_desc.description.clone(),
             })
     });
 
@@ -153,6 +163,15 @@ fn read_rw_columns_in_schema(current_user: &UserCatalog, schema: &SchemaCatalog)
                     type_oid: column.data_type().to_oid(),
                     type_len: column.data_type().type_len(),
                     udt_type: column.data_type().pg_name().into(),
+                    default_expression: column.default_expr().map(|expr_node| {
+                        let expr = ExprImpl::from_expr_proto(expr_node).unwrap();
+                        let expr_display = ExprDisplay {
+                            expr: &expr,
+                            input_schema: &schema,
+                        };
+                        expr_display.to_string()
+                    }),
+                    description: column.column_desc.description.clone(),
                 })
         });
 
@@ -178,6 +197,8 @@ fn read_rw_columns_in_schema(current_user: &UserCatalog, schema: &SchemaCatalog)
                     type_oid: column.data_type().to_oid(),
                     type_len: column.data_type().type_len(),
                     udt_type: column.data_type().pg_name().into(),
+                    default_expression: None,
+                    description: column.column_desc.description.clone(),
                 })
         });
 
@@ -27,6 +27,7 @@ struct RwDdlProgress {
     ddl_statement: String,
     create_type: String,
     progress: String,
+    phase: String,
     initialized_at: Timestamptz,
     is_serverless_backfill: bool,
     backfill_type: String,
@@ -48,6 +49,7 @@ async fn read(reader: &SysCatalogReaderImpl) -> Result<Vec<RwDdlProgress>> {
             ddl_statement: s.statement,
             create_type: s.create_type,
             progress: s.progress,
+            phase: s.phase,
             initialized_at: *Epoch::from_unix_millis(s.initialized_at_time_millis as _)
                 .as_scalar()
                 .as_timestamptz(),
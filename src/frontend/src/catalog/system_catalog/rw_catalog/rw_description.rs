@@ -57,20 +57,30 @@ fn read(reader: &SysCatalogReaderImpl) -> Result<Vec<RwDescription>> {
         .get_system_table_by_name("rw_tables")
         .map(|st| st.id.as_raw_id())
         .unwrap_or_default() as _;
+    let rw_materialized_views_id: i32 = rw_catalog
+        .get_system_table_by_name("rw_materialized_views")
+        .map(|st| st.id.as_raw_id())
+        .unwrap_or_default() as _;
 
     Ok(schemas
         .flat_map(|schema| {
-            schema.iter_user_table().flat_map(|table| {
+            let tables = schema
+                .iter_user_table()
+                .map(move |table| (table, rw_tables_id));
+            let mvs = schema
+                .iter_created_mvs()
+                .map(move |mv| (mv, rw_materialized_views_id));
+            tables.chain(mvs).flat_map(|(table, classoid)| {
                 iter::once(build_row(
                     table.id,
-                    rw_tables_id,
+                    classoid,
                     None,
                     table.description.as_deref().map(Into::into),
                 ))
-                .chain(table.columns.iter().map(|col| {
+                .chain(table.columns.iter().map(move |col| {
                     build_row(
                         table.id,
-                        rw_tables_id,
+                        classoid,
                         Some(col.column_id().get_id() as _),
                         col.column_desc.description.as_deref().map(Into::into),
                     )
@@ -22,6 +22,7 @@ use crate::catalog::system_catalog::rw_catalog::rw_sources::serialize_props_with
 use crate::catalog::system_catalog::{SysCatalogReaderImpl, get_acl_items};
 use crate::error::Result;
 use crate::handler::create_source::UPSTREAM_SOURCE_KEY;
+use crate::utils::redact_definition;
 
 #[derive(Fields)]
 struct RwSink {
@@ -99,7 +100,10 @@ fn read_rw_sinks_info(reader: &SysCatalogReaderImpl) -> Result<Vec<RwSink>> {
                         .to_uppercase(),
                     sink_type: sink.sink_type.to_proto().as_str_name().into(),
                     connection_id: sink.connection_id,
-                    definition: sink.create_sql(),
+                    definition: redact_definition(
+                        &sink.create_sql(),
+                        reader.redact_sql_option_keywords.clone(),
+                    ),
                     acl: get_acl_items(sink.id, false, &users, username_map),
                     initialized_at: sink.initialized_at_epoch.map(|e| e.as_timestamptz()),
                     created_at: sink.created_at_epoch.map(|e| e.as_timestamptz()),
@@ -90,7 +90,8 @@ fn read_rw_sources_info(reader: &SysCatalogReaderImpl) -> Result<Vec<RwSource>>
                     append_only: source.append_only,
                     associated_table_id: source.associated_table_id,
                     connection_id: source.connection_id,
-                    definition: source.create_sql_purified(),
+                    definition: source
+                        .create_sql_redacted(reader.redact_sql_option_keywords.clone()),
                     acl: get_acl_items(source.id, false, &users, username_map),
                     initialized_at: source.initialized_at_epoch.map(|e| e.as_timestamptz()),
                     created_at: source.created_at_epoch.map(|e| e.as_timestamptz()),
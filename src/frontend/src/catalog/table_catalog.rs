@@ -1022,6 +1022,7 @@ mod tests {
                             version: ColumnDescVersion::LATEST,
                             system_column: None,
                             nullable: true,
+                            foreign_key: None,
                         },
                         is_hidden: false
                     },
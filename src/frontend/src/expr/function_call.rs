@@ -387,6 +387,27 @@ impl std::fmt::Debug for FunctionCallDisplay<'_> {
             ExprType::Proctime => {
                 write!(f, "{:?}", that.func_type)
             }
+            ExprType::Encrypt | ExprType::Decrypt | ExprType::Hmac => {
+                // The key argument is typically a constant secret; redact it from the
+                // explain output instead of echoing it back.
+                let key_index = match that.func_type {
+                    ExprType::Hmac => 0,
+                    _ => 1,
+                };
+                let func_name = format!("{:?}", that.func_type);
+                let mut builder = f.debug_tuple(&func_name);
+                for (i, child) in that.inputs.iter().enumerate() {
+                    if i == key_index && child.is_const() {
+                        builder.field(&"[redacted]");
+                    } else {
+                        builder.field(&ExprDisplay {
+                            expr: child,
+                            input_schema: self.input_schema,
+                        });
+                    }
+                }
+                builder.finish()
+            }
             _ => {
                 let func_name = format!("{:?}", that.func_type);
                 let mut builder = f.debug_tuple(&func_name);
@@ -135,6 +135,7 @@ impl ExprVisitor for ImpureAnalyzer {
             | Type::MakeDate
             | Type::MakeTime
             | Type::MakeTimestamp
+            | Type::MakeInterval
             | Type::CharToTimestamptz
             | Type::CharToDate
             | Type::CastWithTimeZone
@@ -291,6 +292,7 @@ impl ExprVisitor for ImpureAnalyzer {
             | Type::JsonbPopulateMap
             | Type::IsJson
             | Type::ToJsonb
+            | Type::RowToJson
             | Type::Sind
             | Type::Cosd
             | Type::Cotd
@@ -317,6 +319,12 @@ impl ExprVisitor for ImpureAnalyzer {
             | Type::Crc32
             | Type::Crc32c
             | Type::Hmac
+            | Type::Digest
+            | Type::StPoint
+            | Type::StX
+            | Type::StY
+            | Type::StDistance
+            | Type::StDwithin
             | Type::SecureCompare
             | Type::Decrypt
             | Type::Encrypt
@@ -402,7 +410,8 @@ impl ExprVisitor for ImpureAnalyzer {
             | Type::HasFunctionPrivilege
             | Type::OpenaiEmbedding
             | Type::HasDatabasePrivilege
-            | Type::Random => self.impure = Some(func_type.as_str_name().into()),
+            | Type::Random
+            | Type::GenRandomUuid => self.impure = Some(func_type.as_str_name().into()),
         }
     }
 }
@@ -223,6 +223,10 @@ fn cast_struct(source: &DataType, target: &DataType, allows: CastContext) -> Cas
         // https://www.postgresql.org/docs/14/sql-createcast.html#id-1.9.3.58.7.4
         (DataType::Varchar, DataType::Struct(_)) => canbo(CastContext::Explicit <= allows),
         (DataType::Struct(_), DataType::Varchar) => canbo(CastContext::Assign <= allows),
+        // A jsonb object is converted to a struct by matching keys to field names, and vice
+        // versa using the field names as keys.
+        (DataType::Jsonb, DataType::Struct(_)) => canbo(CastContext::Explicit <= allows),
+        (DataType::Struct(_), DataType::Jsonb) => canbo(CastContext::Explicit <= allows),
         _ => cannot(),
     }
 }
@@ -247,6 +251,9 @@ fn cast_array(source: &DataType, target: &DataType, allows: CastContext) -> Cast
                 DataType::Int32 | DataType::Decimal | DataType::Float32 | DataType::Float64
             ) && CastContext::Assign <= allows,
         ),
+        // A jsonb array is converted to a list element by element, and vice versa.
+        (DataType::Jsonb, DataType::List(_)) => canbo(CastContext::Explicit <= allows),
+        (DataType::List(_), DataType::Jsonb) => canbo(CastContext::Explicit <= allows),
         _ => cannot(),
     }
 }
@@ -53,6 +53,8 @@ impl UserDefinedFunction {
             kind: FunctionKind::Scalar,
             arg_names: udf.arg_names.clone(),
             arg_types,
+            // Defaults are filled at bind time, so they are not carried in the udf proto.
+            arg_defaults: vec![],
             return_type,
             language: udf.language.clone(),
             runtime: udf.runtime.clone(),
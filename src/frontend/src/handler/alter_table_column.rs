@@ -22,7 +22,7 @@ use risingwave_common::{bail, bail_not_implemented};
 use risingwave_pb::ddl_service::TableJobType;
 use risingwave_pb::stream_plan::StreamFragmentGraph;
 use risingwave_sqlparser::ast::{
-    AlterColumnOperation, AlterTableOperation, ColumnOption, ObjectName, Statement,
+    AlterColumnOperation, AlterTableOperation, ColumnOption, ObjectName, Statement, TableConstraint,
 };
 
 use super::create_source::SqlColumnStrategy;
@@ -89,10 +89,34 @@ pub async fn get_replace_table_plan(
     TableCatalog,
     StreamFragmentGraph,
     TableJobType,
+)> {
+    let col_id_gen = ColumnIdGenerator::new_alter(old_catalog);
+    get_replace_table_plan_with_col_id_gen(
+        session,
+        table_name,
+        new_definition,
+        old_catalog,
+        sql_column_strategy,
+        col_id_gen,
+    )
+    .await
+}
+
+pub(crate) async fn get_replace_table_plan_with_col_id_gen(
+    session: &Arc<SessionImpl>,
+    table_name: ObjectName,
+    new_definition: Statement,
+    old_catalog: &Arc<TableCatalog>,
+    sql_column_strategy: SqlColumnStrategy,
+    col_id_gen: ColumnIdGenerator,
+) -> Result<(
+    Option<SourceCatalog>,
+    TableCatalog,
+    StreamFragmentGraph,
+    TableJobType,
 )> {
     // Create handler args as if we're creating a new table with the altered definition.
     let handler_args = HandlerArgs::new(session.clone(), &new_definition, Arc::from(""))?;
-    let col_id_gen = ColumnIdGenerator::new_alter(old_catalog);
 
     let (graph, table, source, job_type) = Box::pin(generate_stream_graph_for_replace_table(
         session,
@@ -112,8 +136,8 @@ pub async fn get_replace_table_plan(
     Ok((source, table, graph, job_type))
 }
 
-/// Handle `ALTER TABLE [ADD|DROP] COLUMN` statements. The `operation` must be either `AddColumn` or
-/// `DropColumn`.
+/// Handle `ALTER TABLE [ADD|DROP|RENAME] COLUMN` statements. The `operation` must be one of
+/// `AddColumn`, `DropColumn`, `AlterColumn` or `RenameColumn`.
 pub async fn handle_alter_table_column(
     handler_args: HandlerArgs,
     table_name: ObjectName,
@@ -131,7 +155,13 @@ pub async fn handle_alter_table_column(
 
     // Retrieve the original table definition and parse it to AST.
     let mut definition = original_catalog.create_sql_ast_purified()?;
-    let Statement::CreateTable { columns, .. } = &mut definition else {
+    let Statement::CreateTable {
+        columns,
+        constraints,
+        source_watermarks,
+        ..
+    } = &mut definition
+    else {
         panic!("unexpected statement: {:?}", definition);
     };
 
@@ -158,6 +188,7 @@ pub async fn handle_alter_table_column(
     // - Check against the persisted schema, instead of resolving again.
     //
     // Applied only to tables with schema registry.
+    let mut renamed_column = None;
     let sql_column_strategy = match operation {
         AlterTableOperation::AddColumn {
             column_def: new_column,
@@ -284,14 +315,122 @@ pub async fn handle_alter_table_column(
             SqlColumnStrategy::FollowChecked
         }
 
+        AlterTableOperation::RenameColumn {
+            old_column_name,
+            new_column_name,
+        } => {
+            let old_name = old_column_name.real_value();
+            let new_name = new_column_name.real_value();
+
+            // Dependent relations keep referencing the column by its old name in their
+            // stored definitions, and rewriting those is not supported yet.
+            {
+                let reader = session.env().catalog_reader().read_guard();
+                let schema = reader
+                    .get_schema_by_id(original_catalog.database_id, original_catalog.schema_id)?;
+                if !schema
+                    .get_any_indexes_by_table_id(original_catalog.id)
+                    .is_empty()
+                {
+                    bail_not_implemented!("renaming a column of a table with indexes");
+                }
+                // Materialized views, views, sinks and subscriptions on the table would
+                // keep the old name in their stored definitions, breaking `SHOW CREATE`
+                // and definition-based recovery.
+                let dependent_count = reader
+                    .iter_object_dependencies()
+                    .filter(|dep| {
+                        dep.referenced_object_id == original_catalog.id.as_object_id()
+                    })
+                    .count();
+                if dependent_count > 0 {
+                    bail_not_implemented!(
+                        "renaming a column of a table with {dependent_count} dependent relation(s) (e.g. materialized views or sinks)"
+                    );
+                }
+            }
+            if has_incoming_sinks {
+                return Err(ErrorCode::InvalidInputSyntax(
+                    "renaming columns in the target table of sinks is not supported".to_owned(),
+                ))?;
+            }
+            if source_watermarks
+                .iter()
+                .any(|watermark| watermark.column.real_value() == old_name)
+            {
+                bail_not_implemented!("renaming a column with a watermark");
+            }
+
+            // A generated column would keep referencing the old name in its stored
+            // expression; reject like `DROP COLUMN` does.
+            for column in original_catalog.columns() {
+                if let Some(expr) = column.generated_expr() {
+                    let expr = ExprImpl::from_expr_proto(expr)?;
+                    let refs = expr.collect_input_refs(original_catalog.columns().len());
+                    for idx in refs.ones() {
+                        let refed_column = &original_catalog.columns()[idx];
+                        if refed_column.name() == old_name {
+                            bail!(format!(
+                                "failed to rename column \"{}\" because it's referenced by a generated column \"{}\"",
+                                old_name,
+                                column.name()
+                            ))
+                        }
+                    }
+                }
+            }
+
+            if columns.iter().any(|c| c.name.real_value() == new_name) {
+                Err(ErrorCode::InvalidInputSyntax(format!(
+                    "column \"{new_name}\" of table \"{table_name}\" already exists"
+                )))?
+            }
+
+            // Locate the column by name and rename it.
+            let column = columns
+                .iter_mut()
+                .find(|c| c.name.real_value() == old_name)
+                .ok_or_else(|| {
+                    ErrorCode::InvalidInputSyntax(format!(
+                        "column \"{}\" of table \"{}\" does not exist",
+                        old_name, table_name
+                    ))
+                })?;
+            column.name = new_column_name.clone();
+
+            // Keep table constraints (e.g. `PRIMARY KEY`) referencing the column in sync.
+            for constraint in constraints {
+                if let TableConstraint::Unique { columns, .. } = constraint {
+                    for ident in columns {
+                        if ident.real_value() == old_name {
+                            *ident = new_column_name.clone();
+                        }
+                    }
+                }
+            }
+
+            renamed_column = Some((old_name, new_name));
+            SqlColumnStrategy::FollowChecked
+        }
+
         _ => unreachable!(),
     };
-    let (source, table, graph, job_type) = Box::pin(get_replace_table_plan(
+    // For renames, align the column IDs with the old catalog by the *old* name, so that
+    // the renamed column keeps its ID (and thus its data) instead of being treated as a
+    // drop-and-add.
+    let col_id_gen = match &renamed_column {
+        Some((old_name, new_name)) => {
+            ColumnIdGenerator::new_alter_with_renamed_column(&original_catalog, old_name, new_name)
+        }
+        None => ColumnIdGenerator::new_alter(&original_catalog),
+    };
+    let (source, table, graph, job_type) = Box::pin(get_replace_table_plan_with_col_id_gen(
         &session,
         table_name,
         definition,
         &original_catalog,
         sql_column_strategy,
+        col_id_gen,
     ))
     .await?;
 
@@ -417,4 +556,68 @@ mod tests {
             altered_table.version.as_ref().unwrap().next_column_id
         );
     }
+
+    #[tokio::test]
+    async fn test_rename_column_handler() {
+        let frontend = LocalFrontend::new(Default::default()).await;
+        let session = frontend.session_ref();
+        let schema_path = SchemaPath::Name(DEFAULT_SCHEMA_NAME);
+
+        frontend
+            .run_sql("create table t (i int primary key, r real);")
+            .await
+            .unwrap();
+
+        let get_table = || {
+            let catalog_reader = session.env().catalog_reader().read_guard();
+            catalog_reader
+                .get_created_table_by_name(DEFAULT_DATABASE_NAME, schema_path, "t")
+                .unwrap()
+                .0
+                .clone()
+        };
+
+        let table = get_table();
+        let columns: HashMap<_, _> = table
+            .columns
+            .iter()
+            .map(|col| {
+                (
+                    col.name().to_owned(),
+                    (col.data_type().clone(), col.column_id()),
+                )
+            })
+            .collect();
+
+        frontend
+            .run_sql("alter table t rename column r to s;")
+            .await
+            .unwrap();
+
+        let altered_table = get_table();
+        let altered_columns: HashMap<_, _> = altered_table
+            .columns
+            .iter()
+            .map(|col| {
+                (
+                    col.name().to_owned(),
+                    (col.data_type().clone(), col.column_id()),
+                )
+            })
+            .collect();
+
+        // Only the name changes; the type and column ID are preserved.
+        assert_eq!(columns.len(), altered_columns.len());
+        assert!(!altered_columns.contains_key("r"));
+        assert_eq!(columns["r"], altered_columns["s"]);
+        assert_eq!(columns["i"], altered_columns["i"]);
+
+        // Renaming to an existing name or from a missing name is rejected.
+        for sql in [
+            "alter table t rename column s to i;",
+            "alter table t rename column missing to x;",
+        ] {
+            frontend.run_sql(sql).await.unwrap_err();
+        }
+    }
 }
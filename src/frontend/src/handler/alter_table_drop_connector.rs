@@ -143,6 +143,7 @@ fn rewrite_table_definition(
         on_conflict,
         with_version_columns,
         query,
+        with_no_data: false,
         cdc_table_info: None,
         include_column_options: vec![],
         webhook_info: None,
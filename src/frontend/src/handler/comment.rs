@@ -18,6 +18,7 @@ use risingwave_sqlparser::ast::{CommentObject, ObjectName};
 
 use super::{HandlerArgs, RwPgResponse};
 use crate::Binder;
+use crate::catalog::table_catalog::TableType;
 use crate::error::{ErrorCode, Result};
 
 pub async fn handle_comment(
@@ -31,7 +32,7 @@ pub async fn handle_comment(
 
     let comment = {
         let mut binder = Binder::new_for_ddl(&session);
-        // only `Column` and `Table` object are now supported
+        // only `Column`, `Table` and `MaterializedView` objects are now supported
         match object_type {
             CommentObject::Column => {
                 let [tab @ .., col] = object_name.0.as_slice() else {
@@ -69,12 +70,24 @@ pub async fn handle_comment(
                     description: comment,
                 }
             }
-            CommentObject::Table => {
+            // Materialized views are stored as tables in the catalog, so they share the
+            // same code path. We only check the relation type for the `MATERIALIZED VIEW`
+            // form to reject mismatched usages.
+            CommentObject::Table | CommentObject::MaterializedView => {
                 let (schema, table) =
                     Binder::resolve_schema_qualified_name(&session.database(), &object_name)?;
                 let (database_id, schema_id) =
                     session.get_database_and_schema_id_for_create(schema.clone())?;
                 let table = binder.bind_table(schema.as_deref(), &table)?;
+                if object_type == CommentObject::MaterializedView
+                    && table.table_catalog.table_type() != TableType::MaterializedView
+                {
+                    return Err(ErrorCode::InvalidInputSyntax(format!(
+                        "\"{}\" is not a materialized view",
+                        table.table_catalog.name
+                    ))
+                    .into());
+                }
                 if table.table_catalog.owner != session.user_id() && !session.is_super_user() {
                     return Err(ErrorCode::PermissionDenied(format!(
                         "must be owner of relation {}",
@@ -79,6 +79,9 @@ pub async fn handle_create_aggregate(
     let mut arg_names = vec![];
     let mut arg_types = vec![];
     for arg in args {
+        if arg.default_expr.is_some() {
+            bail_not_implemented!("DEFAULT arguments are only supported for SQL UDFs");
+        }
         arg_names.push(arg.name.map_or("".to_owned(), |n| n.real_value()));
         arg_types.push(bind_data_type(&arg.data_type)?);
     }
@@ -134,6 +137,7 @@ pub async fn handle_create_aggregate(
         kind: Some(Kind::Aggregate(AggregateFunction {})),
         arg_names,
         arg_types: arg_types.into_iter().map(|t| t.into()).collect(),
+        arg_defaults: vec![],
         return_type: Some(return_type.into()),
         language,
         runtime,
@@ -114,6 +114,9 @@ pub async fn handle_create_function(
     let mut arg_names = vec![];
     let mut arg_types = vec![];
     for arg in args.unwrap_or_default() {
+        if arg.default_expr.is_some() {
+            bail_not_implemented!("DEFAULT arguments are only supported for SQL UDFs");
+        }
         arg_names.push(arg.name.map_or("".to_owned(), |n| n.real_value()));
         arg_types.push(bind_data_type(&arg.data_type)?);
     }
@@ -174,6 +177,7 @@ pub async fn handle_create_function(
         kind: Some(kind),
         arg_names,
         arg_types: arg_types.into_iter().map(|t| t.into()).collect(),
+        arg_defaults: vec![],
         return_type: Some(return_type.into()),
         language,
         runtime,
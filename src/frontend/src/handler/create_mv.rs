@@ -46,7 +46,7 @@ use crate::planner::Planner;
 use crate::scheduler::streaming_manager::CreatingStreamingJobInfo;
 use crate::session::{SESSION_MANAGER, SessionImpl};
 use crate::stream_fragmenter::{GraphJobType, build_graph_with_strategy};
-use crate::utils::{MV_REFRESH_INTERVAL_SEC_KEY, ordinal};
+use crate::utils::{MV_REFRESH_INTERVAL_SEC_KEY, STREAMING_PARALLELISM_KEY, ordinal};
 use crate::{TableCatalog, WithOptions};
 
 pub const RESOURCE_GROUP_KEY: &str = "resource_group";
@@ -328,6 +328,10 @@ pub(crate) async fn gen_create_mv_graph(
     let mut with_options = get_with_options(handler_args.clone());
     let refresh_interval_sec = with_options.refresh_interval_sec()?;
     with_options.remove(MV_REFRESH_INTERVAL_SEC_KEY);
+    // Validate the value eagerly here; the actual parallelism is resolved from the
+    // optimizer context's `WITH` options when building the fragment graph.
+    with_options.streaming_parallelism()?;
+    with_options.remove(STREAMING_PARALLELISM_KEY);
     let resource_group = with_options.remove(&RESOURCE_GROUP_KEY.to_owned());
 
     if resource_group.is_some() {
@@ -565,6 +569,40 @@ pub mod tests {
         );
     }
 
+    /// `streaming_parallelism` can be set in the `WITH` clause, overriding the
+    /// session variable for this statement only.
+    #[tokio::test]
+    async fn test_create_mv_with_streaming_parallelism() {
+        let frontend = LocalFrontend::new(Default::default()).await;
+
+        let sql = "create table t(x int)";
+        frontend.run_sql(sql).await.unwrap();
+
+        let sql =
+            "create materialized view mv1 with (streaming_parallelism = 4) as select x from t";
+        frontend.run_sql(sql).await.unwrap();
+
+        // The session variable is untouched.
+        assert_eq!(
+            frontend
+                .session_ref()
+                .config()
+                .streaming_parallelism()
+                .to_string(),
+            "default"
+        );
+
+        // Invalid values are rejected.
+        let sql =
+            "create materialized view mv2 with (streaming_parallelism = '-1') as select x from t";
+        let err = frontend.run_sql(sql).await.unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("invalid `streaming_parallelism` value"),
+            "{err}"
+        );
+    }
+
     /// Creating MV with order by returns a special notice
     #[tokio::test]
     async fn test_create_mv_with_order_by() {
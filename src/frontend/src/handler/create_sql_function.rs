@@ -17,6 +17,7 @@ use risingwave_common::catalog::FunctionId;
 use risingwave_common::types::StructType;
 use risingwave_pb::catalog::PbFunction;
 use risingwave_pb::catalog::function::{Kind, ScalarFunction, TableFunction};
+use thiserror_ext::AsReport;
 
 use super::*;
 use crate::expr::{Expr, Literal};
@@ -111,8 +112,23 @@ pub async fn handle_create_sql_function(
 
     let mut arg_names = vec![];
     let mut arg_types = vec![];
+    // `DEFAULT` expressions of the trailing parameters, parallel to the tail of
+    // `arg_names` / `arg_types`.
+    let mut arg_defaults = vec![];
     for arg in args.unwrap_or_default() {
-        arg_names.push(arg.name.map_or("".to_owned(), |n| n.real_value()));
+        let arg_name = arg.name.map_or("".to_owned(), |n| n.real_value());
+        if let Some(default_expr) = arg.default_expr {
+            arg_defaults.push(default_expr);
+        } else if !arg_defaults.is_empty() {
+            // Same restriction as Postgres: defaults are only allowed on a trailing
+            // run of parameters, so that omitted arguments are unambiguous.
+            return Err(ErrorCode::InvalidParameterValue(
+                "input parameters after one with a default value must also have defaults"
+                    .to_owned(),
+            )
+            .into());
+        }
+        arg_names.push(arg_name);
         arg_types.push(bind_data_type(&arg.data_type)?);
     }
 
@@ -137,6 +153,29 @@ pub async fn handle_create_sql_function(
     // e.g., The provided function body contains invalid syntax, return type mismatch, ..., etc.
     {
         let mut binder = Binder::new_for_system(session);
+
+        // Validate the `DEFAULT` expressions first. They are bound without the function
+        // parameters in scope, so a default referencing another parameter (or any column)
+        // errors out here.
+        let num_non_default = arg_types.len() - arg_defaults.len();
+        for (i, default_expr) in arg_defaults.iter().enumerate() {
+            let pos = num_non_default + i;
+            let bound = binder.bind_expr(default_expr).map_err(|e| {
+                ErrorCode::InvalidParameterValue(format!(
+                    "failed to bind the default expression of parameter \"{}\": {}\nHINT: default expressions cannot reference other parameters",
+                    arg_names[pos],
+                    e.as_report()
+                ))
+            })?;
+            if bound.cast_implicit(&arg_types[pos]).is_err() {
+                return Err(ErrorCode::InvalidParameterValue(format!(
+                    "default expression of parameter \"{}\" cannot be implicitly cast to type \"{}\"",
+                    arg_names[pos], arg_types[pos]
+                ))
+                .into());
+            }
+        }
+
         let args = arg_types
             .iter()
             .map(|ty| Literal::new(None, ty.clone()).into() /* NULL */)
@@ -164,6 +203,7 @@ pub async fn handle_create_sql_function(
         kind: Some(kind),
         arg_names,
         arg_types: arg_types.into_iter().map(|t| t.into()).collect(),
+        arg_defaults: arg_defaults.iter().map(|e| e.to_string()).collect(),
         return_type: Some(return_type.into()),
         language,
         runtime: None,
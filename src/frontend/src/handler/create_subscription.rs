@@ -56,6 +56,7 @@ pub fn create_subscription_catalog(
         name: subscription_name,
         definition,
         retention_seconds: 0,
+        ops: Vec::new(),
         database_id: subscription_database_id,
         schema_id: subscription_schema_id,
         dependent_table_id,
@@ -68,6 +69,7 @@ pub fn create_subscription_catalog(
     };
 
     subscription_catalog.set_retention_seconds(context.with_options())?;
+    subscription_catalog.set_ops(context.with_options())?;
 
     Ok(subscription_catalog)
 }
@@ -124,6 +124,13 @@ fn ensure_column_options_supported(c: &ColumnDef) -> Result<()> {
             ColumnOption::Unique { is_primary: true } => {}
             ColumnOption::Null => {}
             ColumnOption::NotNull => {}
+            // `REFERENCES` is accepted as informational metadata only and is not enforced.
+            // Referential actions would imply enforcement, so they are still rejected.
+            ColumnOption::ForeignKey {
+                on_delete: None,
+                on_update: None,
+                ..
+            } => {}
             _ => bail_not_implemented!("column constraints \"{}\"", option_def),
         }
     }
@@ -189,6 +196,30 @@ pub fn bind_sql_columns(
             .iter()
             .any(|def| matches!(def.option, ColumnOption::NotNull));
 
+        // Record `REFERENCES` options as an informational `table(col[, col...])` annotation,
+        // to be surfaced in `pg_constraint`. The constraint itself is not enforced.
+        let foreign_key = options
+            .iter()
+            .find_map(|def| match &def.option {
+                ColumnOption::ForeignKey {
+                    foreign_table,
+                    referred_columns,
+                    ..
+                } => Some((foreign_table, referred_columns)),
+                _ => None,
+            })
+            .map(|(foreign_table, referred_columns)| {
+                if referred_columns.is_empty() {
+                    bail_not_implemented!("REFERENCES without an explicit column list");
+                }
+                Ok::<_, RwError>(format!(
+                    "{}({})",
+                    foreign_table.real_value(),
+                    referred_columns.iter().map(|c| c.real_value()).join(", ")
+                ))
+            })
+            .transpose()?;
+
         columns.push(ColumnCatalog {
             column_desc: ColumnDesc {
                 data_type: bind_data_type(&data_type)?,
@@ -200,6 +231,7 @@ pub fn bind_sql_columns(
                 version: ColumnDescVersion::LATEST,
                 system_column: None,
                 nullable,
+                foreign_key,
             },
             is_hidden: false,
         });
@@ -139,6 +139,30 @@ impl ColumnIdGenerator {
         }
     }
 
+    /// Creates a new [`ColumnIdGenerator`] for altering an existing table with the column
+    /// `old_name` renamed to `new_name`. Aligning the existing ID under the new name keeps
+    /// the column's ID (and thus its data), instead of treating the rename as a
+    /// drop-and-add.
+    pub fn new_alter_with_renamed_column(
+        original: &TableCatalog,
+        old_name: &str,
+        new_name: &str,
+    ) -> Self {
+        let mut this = Self::new_alter(original);
+        this.existing = std::mem::take(&mut this.existing)
+            .into_iter()
+            .map(|(mut path, entry)| {
+                if let Some(Segment::Field(name)) = path.first_mut()
+                    && name == old_name
+                {
+                    *name = new_name.to_owned();
+                }
+                (path, entry)
+            })
+            .collect();
+        this
+    }
+
     /// Creates a new [`ColumnIdGenerator`] for a new table.
     pub fn new_initial() -> Self {
         Self {
@@ -533,6 +557,7 @@ mod tests {
                     version: Pr13707,
                     system_column: None,
                     nullable: true,
+                    foreign_key: None,
                 },
                 is_hidden: false,
             }
@@ -616,6 +641,7 @@ mod tests {
                     version: Pr13707,
                     system_column: None,
                     nullable: true,
+                    foreign_key: None,
                 },
                 is_hidden: false,
             }
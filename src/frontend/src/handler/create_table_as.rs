@@ -15,17 +15,21 @@
 use std::collections::HashSet;
 
 use either::Either;
-use pgwire::pg_response::StatementType;
+use pgwire::pg_response::{PgResponse, StatementType};
 use risingwave_common::catalog::{ColumnCatalog, ColumnDesc};
+use risingwave_common::util::iter_util::ZipEqFast;
 use risingwave_pb::ddl_service::TableJobType;
 use risingwave_sqlparser::ast::{ColumnDef, ObjectName, OnConflict, Query, Statement};
+use thiserror_ext::AsReport;
 
 use super::{HandlerArgs, RwPgResponse};
-use crate::binder::BoundStatement;
+use crate::binder::{BoundSetExpr, BoundStatement, Relation};
 use crate::error::{ErrorCode, Result};
+use crate::expr::ExprImpl;
 use crate::handler::create_table::{
     ColumnIdGenerator, CreateTableProps, gen_create_table_plan_without_source,
 };
+use crate::handler::drop_table::handle_drop_table;
 use crate::handler::query::handle_query;
 use crate::handler::util::{
     LongRunningNotificationAction, execute_with_long_running_notification,
@@ -43,6 +47,7 @@ pub async fn handle_create_as(
     append_only: bool,
     on_conflict: Option<OnConflict>,
     with_version_columns: Vec<String>,
+    with_no_data: bool,
     ast_engine: risingwave_sqlparser::ast::Engine,
 ) -> Result<RwPgResponse> {
     if column_defs.iter().any(|column| column.data_type.is_some()) {
@@ -74,7 +79,7 @@ pub async fn handle_create_as(
         let bound = binder.bind(Statement::Query(query.clone()))?;
         if let BoundStatement::Query(query) = bound {
             // Create ColumnCatelog by Field
-            let columns = query
+            let mut columns: Vec<_> = query
                 .schema()
                 .fields()
                 .iter()
@@ -83,6 +88,20 @@ pub async fn handle_create_as(
                     is_hidden: false,
                 })
                 .collect();
+            // Mark a column NOT NULL if it provably never yields NULL: a plain
+            // column reference to a non-nullable column of a single base table.
+            if let BoundSetExpr::Select(select) = &query.body
+                && let Some(Relation::BaseTable(table)) = &select.from
+            {
+                for (column, expr) in columns.iter_mut().zip_eq_fast(&select.select_items) {
+                    if let ExprImpl::InputRef(input_ref) = expr
+                        && let Some(table_column) =
+                            table.table_catalog.columns.get(input_ref.index())
+                    {
+                        column.column_desc.nullable = table_column.nullable();
+                    }
+                }
+            }
             (columns, binder.included_relations().clone())
         } else {
             unreachable!()
@@ -108,6 +127,18 @@ pub async fn handle_create_as(
         columns[idx].column_desc.name = column.name.real_value();
     });
 
+    // Duplicate output names would create a table with ambiguous columns.
+    let mut names = HashSet::new();
+    for column in &columns {
+        if !names.insert(column.name()) {
+            return Err(ErrorCode::InvalidInputSyntax(format!(
+                "column \"{}\" specified more than once",
+                column.name()
+            ))
+            .into());
+        }
+    }
+
     let (graph, source, table) = {
         let context = OptimizerContext::from_handler_args(handler_args.clone());
         let (_, secret_refs, connection_refs) = context.with_options().clone().into_parts();
@@ -163,13 +194,33 @@ pub async fn handle_create_as(
     )
     .await?;
 
+    // `WITH NO DATA` creates the table without populating it.
+    if with_no_data {
+        return Ok(PgResponse::empty_result(StatementType::CREATE_TABLE));
+    }
+
     // Generate insert
     let insert = Statement::Insert {
-        table_name,
+        table_name: table_name.clone(),
         columns: vec![],
         source: query,
         returning: vec![],
     };
 
-    handle_query(handler_args, insert, vec![]).await
+    match handle_query(handler_args.clone(), insert, vec![]).await {
+        Ok(resp) => Ok(resp),
+        Err(e) => {
+            // Best-effort cleanup so that a failed `CREATE TABLE AS` does not leave a
+            // half-populated table behind.
+            if let Err(cleanup_err) =
+                Box::pin(handle_drop_table(handler_args, table_name, true, false)).await
+            {
+                tracing::warn!(
+                    error = %cleanup_err.as_report(),
+                    "failed to clean up the table after CREATE TABLE AS failure",
+                );
+            }
+            Err(e)
+        }
+    }
 }
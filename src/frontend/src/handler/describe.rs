@@ -13,7 +13,9 @@
 // limitations under the License.
 
 use std::cmp::max;
+use std::collections::HashSet;
 use std::fmt::Display;
+use std::sync::Arc;
 
 use itertools::Itertools;
 use pgwire::pg_field_descriptor::PgFieldDescriptor;
@@ -32,146 +34,203 @@ use super::explain::ExplainRow;
 use super::show::ShowColumnRow;
 use super::{RwPgResponse, fields_to_descriptors};
 use crate::binder::{Binder, Relation};
-use crate::catalog::{CatalogError, FragmentId};
+use crate::catalog::{CatalogError, FragmentId, IndexCatalog};
 use crate::error::{ErrorCode, Result};
 use crate::handler::show::ShowColumnName;
 use crate::handler::{HandlerArgs, RwPgResponseBuilderExt};
+use crate::session::SessionImpl;
+
+/// Catalog information about the relation being described, shared by the plain and
+/// `EXTENDED` forms of `DESCRIBE`.
+struct DescribedRelation {
+    columns: Vec<ColumnCatalog>,
+    pk_columns: Vec<ColumnDesc>,
+    dist_columns: Vec<ColumnDesc>,
+    indices: Vec<Arc<IndexCatalog>>,
+    relname: String,
+    description: Option<String>,
+    target_table_name: Option<String>,
+    /// `Some` for relations that carry an append-only flag in the catalog.
+    append_only: Option<bool>,
+}
 
-pub fn handle_describe(handler_args: HandlerArgs, object_name: ObjectName) -> Result<RwPgResponse> {
-    let session = handler_args.session;
-    let mut binder = Binder::new_for_system(&session);
+fn describe_relation(session: &SessionImpl, object_name: &ObjectName) -> Result<DescribedRelation> {
+    let mut binder = Binder::new_for_system(session);
     let catalog_reader = session.env().catalog_reader().read_guard();
 
-    Binder::validate_cross_db_reference(&session.database(), &object_name)?;
+    Binder::validate_cross_db_reference(&session.database(), object_name)?;
     let not_found_err =
         CatalogError::not_found("table, source, sink or view", object_name.to_string());
 
-    // Vec<ColumnCatalog>, Vec<ColumnDesc>, Vec<ColumnDesc>, Vec<Arc<IndexCatalog>>, String, Option<String>
-    let (columns, pk_columns, dist_columns, indices, relname, description, target_table_name) =
-        if let Ok(relation) = binder.bind_relation_by_name(&object_name, None, None, false) {
-            match relation {
-                Relation::Source(s) => {
-                    let pk_column_catalogs = s
-                        .catalog
-                        .pk_col_ids
-                        .iter()
-                        .map(|&column_id| {
-                            s.catalog
-                                .columns
-                                .iter()
-                                .filter(|x| x.column_id() == column_id)
-                                .map(|x| x.column_desc.clone())
-                                .exactly_one()
-                                .unwrap()
-                        })
-                        .collect_vec();
-                    (
-                        s.catalog.columns,
-                        pk_column_catalogs,
-                        vec![],
-                        vec![],
-                        s.catalog.name,
-                        None, // Description
-                        None,
-                    )
-                }
-                Relation::BaseTable(t) => {
-                    let pk_column_catalogs = t
-                        .table_catalog
-                        .pk()
-                        .iter()
-                        .map(|x| t.table_catalog.columns[x.column_index].column_desc.clone())
-                        .collect_vec();
-                    let dist_columns = t
-                        .table_catalog
-                        .distribution_key()
-                        .iter()
-                        .map(|idx| t.table_catalog.columns[*idx].column_desc.clone())
-                        .collect_vec();
-                    (
-                        t.table_catalog.columns.clone(),
-                        pk_column_catalogs,
-                        dist_columns,
-                        t.table_indexes,
-                        t.table_catalog.name.clone(),
-                        t.table_catalog.description.clone(),
-                        None,
-                    )
-                }
-                Relation::SystemTable(t) => {
-                    let pk_column_catalogs = t
-                        .sys_table_catalog
-                        .pk
-                        .iter()
-                        .map(|idx| t.sys_table_catalog.columns[*idx].column_desc.clone())
-                        .collect_vec();
-                    (
-                        t.sys_table_catalog.columns.clone(),
-                        pk_column_catalogs,
-                        vec![],
-                        vec![],
-                        t.sys_table_catalog.name.clone(),
-                        None, // Description
-                        None,
-                    )
-                }
-                Relation::Share(_) => {
-                    if let Ok(view) = binder.bind_view_by_name(object_name.clone()) {
-                        let columns = view
-                            .view_catalog
+    if let Ok(relation) = binder.bind_relation_by_name(object_name, None, None, false) {
+        match relation {
+            Relation::Source(s) => {
+                let pk_column_catalogs = s
+                    .catalog
+                    .pk_col_ids
+                    .iter()
+                    .map(|&column_id| {
+                        s.catalog
                             .columns
                             .iter()
-                            .enumerate()
-                            .map(|(idx, field)| ColumnCatalog {
-                                column_desc: ColumnDesc::from_field_with_column_id(field, idx as _),
-                                is_hidden: false,
-                            })
-                            .collect();
-                        (
-                            columns,
-                            vec![],
-                            vec![],
-                            vec![],
-                            view.view_catalog.name.clone(),
-                            None,
-                            None,
-                        )
-                    } else {
-                        return Err(not_found_err.into());
-                    }
-                }
-                _ => {
-                    return Err(not_found_err.into());
+                            .filter(|x| x.column_id() == column_id)
+                            .map(|x| x.column_desc.clone())
+                            .exactly_one()
+                            .unwrap()
+                    })
+                    .collect_vec();
+                Ok(DescribedRelation {
+                    columns: s.catalog.columns.clone(),
+                    pk_columns: pk_column_catalogs,
+                    dist_columns: vec![],
+                    indices: vec![],
+                    relname: s.catalog.name.clone(),
+                    description: None,
+                    target_table_name: None,
+                    append_only: Some(s.catalog.append_only),
+                })
+            }
+            Relation::BaseTable(t) => {
+                let pk_column_catalogs = t
+                    .table_catalog
+                    .pk()
+                    .iter()
+                    .map(|x| t.table_catalog.columns[x.column_index].column_desc.clone())
+                    .collect_vec();
+                let dist_columns = t
+                    .table_catalog
+                    .distribution_key()
+                    .iter()
+                    .map(|idx| t.table_catalog.columns[*idx].column_desc.clone())
+                    .collect_vec();
+                Ok(DescribedRelation {
+                    columns: t.table_catalog.columns.clone(),
+                    pk_columns: pk_column_catalogs,
+                    dist_columns,
+                    indices: t.table_indexes,
+                    relname: t.table_catalog.name.clone(),
+                    description: t.table_catalog.description.clone(),
+                    target_table_name: None,
+                    append_only: Some(t.table_catalog.append_only),
+                })
+            }
+            Relation::SystemTable(t) => {
+                let pk_column_catalogs = t
+                    .sys_table_catalog
+                    .pk
+                    .iter()
+                    .map(|idx| t.sys_table_catalog.columns[*idx].column_desc.clone())
+                    .collect_vec();
+                Ok(DescribedRelation {
+                    columns: t.sys_table_catalog.columns.clone(),
+                    pk_columns: pk_column_catalogs,
+                    dist_columns: vec![],
+                    indices: vec![],
+                    relname: t.sys_table_catalog.name.clone(),
+                    description: None,
+                    target_table_name: None,
+                    append_only: None,
+                })
+            }
+            Relation::Share(_) => {
+                if let Ok(view) = binder.bind_view_by_name(object_name.clone()) {
+                    let columns = view
+                        .view_catalog
+                        .columns
+                        .iter()
+                        .enumerate()
+                        .map(|(idx, field)| ColumnCatalog {
+                            column_desc: ColumnDesc::from_field_with_column_id(field, idx as _),
+                            is_hidden: false,
+                        })
+                        .collect();
+                    Ok(DescribedRelation {
+                        columns,
+                        pk_columns: vec![],
+                        dist_columns: vec![],
+                        indices: vec![],
+                        relname: view.view_catalog.name.clone(),
+                        description: None,
+                        target_table_name: None,
+                        append_only: None,
+                    })
+                } else {
+                    Err(not_found_err.into())
                 }
             }
-        } else if let Ok(sink) = binder.bind_sink_by_name(object_name.clone()) {
-            let columns = sink.sink_catalog.full_columns().to_vec();
-            let pk_columns = (sink.sink_catalog.downstream_pk.clone().unwrap_or_default())
-                .into_iter()
-                .map(|idx| columns[idx].column_desc.clone())
-                .collect_vec();
-            let dist_columns = sink
-                .sink_catalog
-                .distribution_key
-                .iter()
-                .map(|idx| columns[*idx].column_desc.clone())
-                .collect_vec();
-            let target_table_name = sink
-                .sink_catalog
-                .target_table
-                .and_then(|table_id| catalog_reader.get_table_name_by_id(table_id).ok());
-            (
-                columns,
-                pk_columns,
-                dist_columns,
-                vec![],
-                sink.sink_catalog.name.clone(),
-                None,
-                target_table_name,
-            )
-        } else {
-            return Err(not_found_err.into());
-        };
+            _ => Err(not_found_err.into()),
+        }
+    } else if let Ok(sink) = binder.bind_sink_by_name(object_name.clone()) {
+        let columns = sink.sink_catalog.full_columns().to_vec();
+        let pk_columns = (sink.sink_catalog.downstream_pk.clone().unwrap_or_default())
+            .into_iter()
+            .map(|idx| columns[idx].column_desc.clone())
+            .collect_vec();
+        let dist_columns = sink
+            .sink_catalog
+            .distribution_key
+            .iter()
+            .map(|idx| columns[*idx].column_desc.clone())
+            .collect_vec();
+        let target_table_name = sink
+            .sink_catalog
+            .target_table
+            .and_then(|table_id| catalog_reader.get_table_name_by_id(table_id).ok());
+        Ok(DescribedRelation {
+            columns,
+            pk_columns,
+            dist_columns,
+            indices: vec![],
+            relname: sink.sink_catalog.name.clone(),
+            description: None,
+            target_table_name,
+            append_only: None,
+        })
+    } else {
+        Err(not_found_err.into())
+    }
+}
+
+fn concat<T>(display_elems: impl IntoIterator<Item = T>) -> String
+where
+    T: Display,
+{
+    format!(
+        "{}",
+        display_comma_separated(&display_elems.into_iter().collect::<Vec<_>>())
+    )
+}
+
+fn index_type_str(index: &IndexCatalog) -> String {
+    let index_display = index.display();
+    if index_display.include_columns.is_empty() {
+        format!(
+            "index({}) distributed by({})",
+            display_comma_separated(&index_display.index_columns_with_ordering),
+            display_comma_separated(&index_display.distributed_by_columns),
+        )
+    } else {
+        format!(
+            "index({}) include({}) distributed by({})",
+            display_comma_separated(&index_display.index_columns_with_ordering),
+            display_comma_separated(&index_display.include_columns),
+            display_comma_separated(&index_display.distributed_by_columns),
+        )
+    }
+}
+
+pub fn handle_describe(handler_args: HandlerArgs, object_name: ObjectName) -> Result<RwPgResponse> {
+    let session = handler_args.session;
+    let DescribedRelation {
+        columns,
+        pk_columns,
+        dist_columns,
+        indices,
+        relname,
+        description,
+        target_table_name,
+        append_only: _,
+    } = describe_relation(&session, &object_name)?;
 
     // Convert all column descs to rows
     let mut rows = columns
@@ -179,16 +238,6 @@ pub fn handle_describe(handler_args: HandlerArgs, object_name: ObjectName) -> Re
         .flat_map(ShowColumnRow::from_catalog)
         .collect_vec();
 
-    fn concat<T>(display_elems: impl IntoIterator<Item = T>) -> String
-    where
-        T: Display,
-    {
-        format!(
-            "{}",
-            display_comma_separated(&display_elems.into_iter().collect::<Vec<_>>())
-        )
-    }
-
     // Convert primary key to rows
     if !pk_columns.is_empty() {
         rows.push(ShowColumnRow {
@@ -211,24 +260,9 @@ pub fn handle_describe(handler_args: HandlerArgs, object_name: ObjectName) -> Re
 
     // Convert all indexes to rows
     rows.extend(indices.iter().map(|index| {
-        let index_display = index.display();
-
         ShowColumnRow {
             name: ShowColumnName::special(&index.name),
-            r#type: if index_display.include_columns.is_empty() {
-                format!(
-                    "index({}) distributed by({})",
-                    display_comma_separated(&index_display.index_columns_with_ordering),
-                    display_comma_separated(&index_display.distributed_by_columns),
-                )
-            } else {
-                format!(
-                    "index({}) include({}) distributed by({})",
-                    display_comma_separated(&index_display.index_columns_with_ordering),
-                    display_comma_separated(&index_display.include_columns),
-                    display_comma_separated(&index_display.distributed_by_columns),
-                )
-            },
+            r#type: index_type_str(index),
             is_hidden: None,
             // TODO: index description
             description: None,
@@ -258,6 +292,120 @@ pub fn handle_describe(handler_args: HandlerArgs, object_name: ObjectName) -> Re
         .into())
 }
 
+/// A row in the output of `DESCRIBE EXTENDED`, i.e. the output of plain `DESCRIBE` with extra
+/// nullability and primary-key columns.
+#[derive(Fields)]
+#[fields(style = "Title Case")]
+struct DescribeExtendedRow {
+    name: ShowColumnName,
+    r#type: String,
+    is_hidden: Option<String>,
+    is_nullable: Option<String>,
+    is_primary_key: Option<String>,
+    description: Option<String>,
+}
+
+impl DescribeExtendedRow {
+    /// Create a special row carrying extra information like `primary key`, in the same way
+    /// as plain `DESCRIBE` does.
+    fn special(name: &str, r#type: String, description: Option<String>) -> Self {
+        Self {
+            name: ShowColumnName::special(name),
+            r#type,
+            is_hidden: None,
+            is_nullable: None,
+            is_primary_key: None,
+            description,
+        }
+    }
+}
+
+pub fn handle_describe_extended(
+    handler_args: HandlerArgs,
+    object_name: ObjectName,
+) -> Result<RwPgResponse> {
+    let session = handler_args.session;
+    let DescribedRelation {
+        columns,
+        pk_columns,
+        dist_columns,
+        indices,
+        relname,
+        description,
+        target_table_name,
+        append_only,
+    } = describe_relation(&session, &object_name)?;
+
+    let pk_names: HashSet<_> = pk_columns.iter().map(|x| x.name.clone()).collect();
+
+    let mut rows = Vec::new();
+    for column in columns {
+        let nullable = column.nullable();
+        let is_primary_key = pk_names.contains(column.name());
+        for (i, row) in ShowColumnRow::from_catalog(column).into_iter().enumerate() {
+            // Nullability and primary-key information only apply to the top-level column,
+            // not to the flattened rows of its struct fields.
+            let top_level = i == 0;
+            rows.push(DescribeExtendedRow {
+                name: row.name,
+                r#type: row.r#type,
+                is_hidden: row.is_hidden,
+                is_nullable: top_level.then(|| nullable.to_string()),
+                is_primary_key: top_level.then(|| is_primary_key.to_string()),
+                description: row.description,
+            });
+        }
+    }
+
+    if !pk_columns.is_empty() {
+        rows.push(DescribeExtendedRow::special(
+            "primary key",
+            concat(pk_columns.iter().map(|x| &x.name)),
+            None,
+        ));
+    }
+
+    if !dist_columns.is_empty() {
+        rows.push(DescribeExtendedRow::special(
+            "distribution key",
+            concat(dist_columns.iter().map(|x| &x.name)),
+            None,
+        ));
+    }
+
+    rows.extend(
+        indices
+            .iter()
+            .map(|index| DescribeExtendedRow::special(&index.name, index_type_str(index), None)),
+    );
+
+    if let Some(append_only) = append_only {
+        rows.push(DescribeExtendedRow::special(
+            "append only",
+            append_only.to_string(),
+            None,
+        ));
+    }
+
+    rows.push(DescribeExtendedRow::special(
+        "table description",
+        relname,
+        description,
+    ));
+
+    if let Some(target_table) = target_table_name {
+        rows.push(DescribeExtendedRow::special(
+            "target table name",
+            target_table,
+            None,
+        ));
+    }
+
+    Ok(PgResponse::builder(StatementType::DESCRIBE)
+        .rows(rows)
+        .into())
+}
+
 pub fn infer_describe(kind: &DescribeKind) -> Vec<PgFieldDescriptor> {
     match kind {
         DescribeKind::Fragments => vec![PgFieldDescriptor::new(
@@ -266,6 +414,7 @@ pub fn infer_describe(kind: &DescribeKind) -> Vec<PgFieldDescriptor> {
             DataType::Varchar.type_len(),
         )],
         DescribeKind::Plain => fields_to_descriptors(ShowColumnRow::fields()),
+        DescribeKind::Extended => fields_to_descriptors(DescribeExtendedRow::fields()),
     }
 }
 
@@ -515,6 +664,41 @@ mod tests {
         assert_eq!(columns, expected_columns);
     }
 
+    #[tokio::test]
+    async fn test_describe_extended_handler() {
+        let frontend = LocalFrontend::new(Default::default()).await;
+        frontend
+            .run_sql("create table t (v1 int not null, v2 int, v3 int primary key);")
+            .await
+            .unwrap();
+
+        let sql = "describe extended t";
+        let mut pg_response = frontend.run_sql(sql).await.unwrap();
+
+        // Map from column name to its `Is Nullable` and `Is Primary Key` output.
+        let mut rows = HashMap::new();
+        #[for_await]
+        for row_set in pg_response.values_stream() {
+            let row_set = row_set.unwrap();
+            for row in row_set {
+                let get = |i: usize| {
+                    row.index(i)
+                        .as_ref()
+                        .map(|v| std::str::from_utf8(v).unwrap().to_owned())
+                };
+                rows.insert(get(0).unwrap(), (get(3), get(4)));
+            }
+        }
+
+        assert_eq!(rows["v1"], (Some("false".into()), Some("false".into())));
+        assert_eq!(rows["v2"], (Some("true".into()), Some("false".into())));
+        assert_eq!(rows["v3"].1, Some("true".into()));
+        // Special rows do not carry nullability or primary-key information.
+        assert_eq!(rows["primary key"], (None, None));
+        // The append-only flag is reported for tables.
+        assert!(rows.contains_key("append only"));
+    }
+
     #[tokio::test]
     async fn test_describe_handler_with_target_table() {
         let frontend = LocalFrontend::new(Default::default()).await;
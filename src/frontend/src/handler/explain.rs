@@ -19,6 +19,7 @@ use pgwire::pg_response::{PgResponse, StatementType};
 use risingwave_batch::worker_manager::worker_node_manager::WorkerNodeSelector;
 use risingwave_common::bail_not_implemented;
 use risingwave_common::types::Fields;
+use risingwave_connector::sink::catalog::SinkCatalog;
 use risingwave_sqlparser::ast::{
     ExplainFormat, ExplainOptions, ExplainType, FetchCursorStatement, Statement,
 };
@@ -26,11 +27,10 @@ use thiserror_ext::AsReport;
 
 use super::create_index::{gen_create_index_plan, resolve_index_schema};
 use super::create_mv::explain_create_mv_plan;
-use super::create_sink::gen_sink_plan;
+use super::create_sink::{SinkPlanContext, gen_sink_plan};
 use super::query::{BatchPlanChoice, gen_batch_plan_by_statement};
 use super::util::SourceSchemaCompatExt;
 use super::{RwPgResponse, RwPgResponseBuilderExt};
-use crate::OptimizerContextRef;
 #[cfg(feature = "datafusion")]
 use crate::datafusion::{
     DfBatchQueryPlanResult, build_datafusion_physical_plan, create_datafusion_context,
@@ -45,6 +45,7 @@ use crate::optimizer::plan_node::{BatchPlanRef, Explain, StreamPlanRef};
 use crate::scheduler::BatchPlanFragmenter;
 use crate::stream_fragmenter::{GraphJobType, build_graph};
 use crate::utils::{explain_stream_graph, explain_stream_graph_as_dot};
+use crate::{OptimizerContextRef, TableCatalog};
 
 pub async fn do_handle_explain(
     handler_args: HandlerArgs,
@@ -55,6 +56,7 @@ pub async fn do_handle_explain(
     // Workaround to avoid `Rc` across `await` point.
     let mut batch_plan_fragmenter = None;
     let mut batch_plan_fragmenter_fmt = ExplainFormat::Json;
+    let mut sink_columns_block = None;
     #[cfg(feature = "datafusion")]
     let mut datafusion_physical_plan_request: Option<(
         DfBatchQueryPlanResult,
@@ -128,9 +130,16 @@ pub async fn do_handle_explain(
                 )
             }
             Statement::CreateSink { stmt } => {
-                let plan = gen_sink_plan(handler_args, stmt, Some(explain_options), false)
-                    .await
-                    .map(|plan| plan.sink_plan)?;
+                let SinkPlanContext {
+                    sink_plan: plan,
+                    sink_catalog,
+                    target_table_catalog,
+                    ..
+                } = gen_sink_plan(handler_args, stmt, Some(explain_options), false).await?;
+                sink_columns_block = Some(explain_sink_columns(
+                    &sink_catalog,
+                    target_table_catalog.as_deref(),
+                ));
                 let context = plan.ctx();
                 (
                     Ok(PlanToExplain::Rw(PhysicalPlanRef::Stream(
@@ -376,6 +385,16 @@ pub async fn do_handle_explain(
             }
         }
 
+        // For `CREATE SINK`, additionally show the resolved sink columns and how they map
+        // to the target table, so that mapping mistakes surface before the sink runs.
+        // Only emitted for the text format to keep structured formats parseable.
+        if explain_format == ExplainFormat::Text
+            && plan.is_ok()
+            && let Some(block) = sink_columns_block.take()
+        {
+            blocks.push(block);
+        }
+
         // Throw the error.
         plan?;
     }
@@ -429,6 +448,46 @@ fn explain_datafusion_plan(
     Ok(())
 }
 
+/// Renders the sink columns resolved by the planner, their mapping to the target table
+/// (for `CREATE SINK INTO`), and the primary keys as an extra block of
+/// `EXPLAIN CREATE SINK` output.
+fn explain_sink_columns(sink: &SinkCatalog, target_table: Option<&TableCatalog>) -> String {
+    use std::fmt::Write;
+
+    let mut output = String::from("Sink columns:");
+    let target_columns: Vec<_> = target_table
+        .map(|table| (table.columns().iter()).filter(|c| !c.is_hidden).collect())
+        .unwrap_or_default();
+    for (i, column) in sink.visible_columns().enumerate() {
+        write!(output, "\n  {}: {}", column.name(), column.data_type()).unwrap();
+        // The planner has already reordered the columns of a sink into a table to match
+        // the target, so the mapping is positional here.
+        if let Some(target) = target_columns.get(i) {
+            write!(output, " -> {}: {}", target.name(), target.data_type()).unwrap();
+        }
+    }
+
+    let pk_names = |indices: &[usize]| {
+        (indices.iter())
+            .map(|i| sink.full_columns()[*i].name())
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+    if !sink.plan_pk.is_empty() {
+        let indices: Vec<_> = (sink.plan_pk.iter()).map(|o| o.column_index).collect();
+        write!(output, "\nDerived primary key: {}", pk_names(&indices)).unwrap();
+    }
+    if let Some(downstream_pk) = &sink.downstream_pk {
+        write!(
+            output,
+            "\nDownstream primary key: {}",
+            pk_names(downstream_pk)
+        )
+        .unwrap();
+    }
+    output
+}
+
 pub async fn handle_explain(
     handler_args: HandlerArgs,
     stmt: Statement,
@@ -450,7 +509,7 @@ pub async fn handle_explain(
     if options.explain_type == ExplainType::DistSql && options.explain_format == ExplainFormat::Json
     {
         return Err(ErrorCode::NotSupported(
-            "EXPLAIN (TRACE, JSON FORMAT)".to_owned(),
+            "EXPLAIN (DISTSQL, JSON FORMAT)".to_owned(),
             "Only EXPLAIN (LOGICAL | PHYSICAL, JSON FORMAT) is supported.".to_owned(),
         )
         .into());
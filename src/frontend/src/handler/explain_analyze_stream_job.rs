@@ -42,6 +42,7 @@ struct ExplainAnalyzeStreamJobOutput {
     actor_ids: String,
     output_rows_per_second: Option<String>,
     downstream_backpressure_ratio: Option<String>,
+    state_size_bytes: Option<String>,
 }
 
 pub async fn handle_explain_analyze_stream_job(
@@ -86,12 +87,31 @@ pub async fn handle_explain_analyze_stream_job(
     );
     tracing::debug!(?aggregated_stats, "collected aggregated stats");
 
+    // Point-in-time state sizes from the hummock version stats cached in the catalog,
+    // keyed by state table id.
+    let state_table_sizes: HashMap<u32, u64> = handler_args
+        .session
+        .env()
+        .catalog_reader()
+        .read_guard()
+        .table_stats()
+        .table_stats
+        .iter()
+        .map(|(id, stats)| {
+            (
+                *id,
+                (stats.total_key_size + stats.total_value_size).max(0) as u64,
+            )
+        })
+        .collect();
+
     // Render graph with metrics
     let rows = render_graph_with_metrics(
         &adjacency_list,
         root_node,
         &aggregated_stats,
         &profiling_duration,
+        &state_table_sizes,
     );
     let builder = RwPgResponseBuilder::empty(StatementType::EXPLAIN);
     let builder = builder.rows(rows);
@@ -583,6 +603,7 @@ mod graph {
         unique_executor_id_from_unique_operator_id, unique_operator_id,
         unique_operator_id_into_parts,
     };
+    use risingwave_common::util::stream_graph_visitor::visit_stream_node_tables_inner;
     use risingwave_pb::id::{ActorId, GlobalOperatorId};
     use risingwave_pb::meta::list_table_fragments_response::FragmentInfo;
     use risingwave_pb::stream_plan::stream_node::{NodeBody, NodeBodyDiscriminants};
@@ -603,6 +624,9 @@ mod graph {
         identity: NodeBodyDiscriminants,
         actor_ids: HashSet<ActorId>,
         dependencies: Vec<OperatorId>,
+        /// Ids of the state tables owned by this operator, including the table of
+        /// a materialize node. Empty for stateless operators.
+        state_table_ids: Vec<u32>,
     }
 
     impl Debug for StreamNode {
@@ -614,8 +638,13 @@ mod graph {
             );
             write!(
                 f,
-                "StreamNode {{ operator_id: {}, fragment_id: {}, identity: {:?}, actor_ids: {:?}, dependencies: {:?} }}",
-                operator_id_str, self.fragment_id, self.identity, self.actor_ids, self.dependencies
+                "StreamNode {{ operator_id: {}, fragment_id: {}, identity: {:?}, actor_ids: {:?}, dependencies: {:?}, state_table_ids: {:?} }}",
+                operator_id_str,
+                self.fragment_id,
+                self.identity,
+                self.actor_ids,
+                self.dependencies,
+                self.state_table_ids
             )
         }
     }
@@ -628,6 +657,7 @@ mod graph {
                 identity: NodeBodyDiscriminants::Exchange,
                 actor_ids: Default::default(),
                 dependencies: Default::default(),
+                state_table_ids: Default::default(),
             }
         }
     }
@@ -662,7 +692,7 @@ mod graph {
             fragment_id: FragmentId,
             fragment_id_to_merge_operator_id: &mut HashMap<FragmentId, OperatorId>,
             operator_id_to_stream_node: &mut HashMap<OperatorId, StreamNode>,
-            node: &PbStreamNode,
+            node: &mut PbStreamNode,
             actor_ids: &HashSet<ActorId>,
         ) {
             let identity = node
@@ -679,11 +709,16 @@ mod graph {
             {
                 fragment_id_to_merge_operator_id.insert(*upstream_fragment_id, operator_id);
             }
-            let dependencies = &node.input;
-            let dependency_ids = dependencies
+            let dependency_ids = node
+                .input
                 .iter()
                 .map(|input| unique_operator_id(fragment_id, input.operator_id))
                 .collect::<Vec<_>>();
+            // The visitor only takes a mutable reference; no table is actually modified.
+            let mut state_table_ids = vec![];
+            visit_stream_node_tables_inner(node, false, false, |table, _| {
+                state_table_ids.push(table.id)
+            });
             operator_id_to_stream_node.insert(
                 operator_id,
                 StreamNode {
@@ -692,9 +727,10 @@ mod graph {
                     identity,
                     actor_ids: actor_ids.clone(),
                     dependencies: dependency_ids,
+                    state_table_ids,
                 },
             );
-            for dependency in dependencies {
+            for dependency in &mut node.input {
                 extract_stream_node_info(
                     fragment_id,
                     fragment_id_to_merge_operator_id,
@@ -717,12 +753,12 @@ mod graph {
                 fragment.id
             );
             let actor_ids = actors.iter().map(|actor| actor.id).collect::<HashSet<_>>();
-            let node = actors[0].node.as_ref().expect("should have stream node");
+            let mut node = actors[0].node.clone().expect("should have stream node");
             extract_stream_node_info(
                 fragment.id,
                 &mut fragment_id_to_merge_operator_id,
                 &mut operator_id_to_stream_node,
-                node,
+                &mut node,
                 &actor_ids,
             );
         }
@@ -807,6 +843,7 @@ mod graph {
         root_node: OperatorId,
         stats: &OperatorStats,
         profiling_duration: &Duration,
+        state_table_sizes: &HashMap<u32, u64>,
     ) -> Vec<ExplainAnalyzeStreamJobOutput> {
         let profiling_duration_secs = profiling_duration.as_secs_f64();
         let mut rows = vec![];
@@ -849,6 +886,15 @@ mod graph {
                 ),
                 None => (None, None),
             };
+            // Tables not yet reported by the version stats (e.g. freshly created)
+            // count as zero-sized.
+            let state_size_bytes = (!node.state_table_ids.is_empty()).then(|| {
+                node.state_table_ids
+                    .iter()
+                    .map(|id| state_table_sizes.get(id).copied().unwrap_or(0))
+                    .sum::<u64>()
+                    .to_string()
+            });
             let row = ExplainAnalyzeStreamJobOutput {
                 identity: identity_rendered,
                 actor_ids: node
@@ -860,6 +906,7 @@ mod graph {
                     .join(","),
                 output_rows_per_second,
                 downstream_backpressure_ratio,
+                state_size_bytes,
             };
             rows.push(row);
             for (position, dependency) in node.dependencies.iter().enumerate() {
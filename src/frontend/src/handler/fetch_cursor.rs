@@ -57,6 +57,27 @@ pub async fn handle_fetch_cursor(
     stmt: FetchCursorStatement,
     formats: &Vec<Format>,
 ) -> Result<RwPgResponse> {
+    let (rows, pg_descs) = fetch_from_cursor(handler_args, stmt, formats).await?;
+    Ok(build_fetch_cursor_response(rows, pg_descs))
+}
+
+/// Handles `MOVE`, which advances the cursor like `FETCH` but discards the rows instead
+/// of returning them. The command tag reports the number of rows the cursor moved over.
+pub async fn handle_move_cursor(
+    handler_args: HandlerArgs,
+    stmt: FetchCursorStatement,
+) -> Result<RwPgResponse> {
+    let (rows, _) = fetch_from_cursor(handler_args, stmt, &vec![]).await?;
+    Ok(PgResponse::builder(StatementType::MOVE)
+        .row_cnt_opt(Some(rows.len() as i32))
+        .into())
+}
+
+async fn fetch_from_cursor(
+    handler_args: HandlerArgs,
+    stmt: FetchCursorStatement,
+    formats: &Vec<Format>,
+) -> Result<(Vec<Row>, Vec<PgFieldDescriptor>)> {
     let session = handler_args.session.clone();
     let cursor_name = stmt.cursor_name.real_value();
     let with_options = WithOptions::try_from(stmt.with_properties.0.as_slice())?;
@@ -76,7 +97,7 @@ pub async fn handle_fetch_cursor(
 
     let cursor_manager = session.get_cursor_manager();
 
-    let (rows, pg_descs) = cursor_manager
+    cursor_manager
         .get_rows_with_cursor(
             &cursor_name,
             stmt.count,
@@ -84,8 +105,7 @@ pub async fn handle_fetch_cursor(
             formats,
             timeout_seconds,
         )
-        .await?;
-    Ok(build_fetch_cursor_response(rows, pg_descs))
+        .await
 }
 
 fn build_fetch_cursor_response(rows: Vec<Row>, pg_descs: Vec<PgFieldDescriptor>) -> RwPgResponse {
@@ -40,7 +40,7 @@ use crate::handler::cancel_job::handle_cancel;
 use crate::handler::kill_process::handle_kill;
 use crate::scheduler::{DistributedQueryStream, LocalQueryStream};
 use crate::session::SessionImpl;
-use crate::utils::WithOptions;
+use crate::utils::{OverwriteOptions, WithOptions};
 
 mod alter_compaction_group;
 mod alter_connection_props;
@@ -207,10 +207,13 @@ pub struct HandlerArgs {
 
 impl HandlerArgs {
     pub fn new(session: Arc<SessionImpl>, stmt: &Statement, sql: Arc<str>) -> Result<Self> {
+        let with_options = WithOptions::try_from(stmt)?;
+        // `OverwriteOptions::new` consumes these options infallibly later on.
+        OverwriteOptions::validate(&with_options)?;
         Ok(Self {
             session,
             sql,
-            with_options: WithOptions::try_from(stmt)?,
+            with_options,
             normalized_sql: Self::normalize_sql(stmt),
         })
     }
@@ -404,6 +407,7 @@ pub async fn handle(
             append_only,
             on_conflict,
             with_version_columns,
+            with_no_data,
             cdc_table_info,
             include_column_options,
             webhook_info,
@@ -428,6 +432,7 @@ pub async fn handle(
                         .iter()
                         .map(|col| col.real_value())
                         .collect(),
+                    with_no_data,
                     engine,
                 )
                 .await;
@@ -489,6 +494,9 @@ pub async fn handle(
         Statement::FetchCursor { stmt } => {
             fetch_cursor::handle_fetch_cursor(handler_args, stmt, &formats).await
         }
+        Statement::MoveCursor { stmt } => {
+            fetch_cursor::handle_move_cursor(handler_args, stmt).await
+        }
         Statement::CloseCursor { stmt } => {
             close_cursor::handle_close_cursor(handler_args, stmt).await
         }
@@ -504,6 +512,7 @@ pub async fn handle(
                 describe::handle_describe_fragments(handler_args, name).await
             }
             DescribeKind::Plain => describe::handle_describe(handler_args, name),
+            DescribeKind::Extended => describe::handle_describe_extended(handler_args, name),
         },
         Statement::DescribeFragment { fragment_id } => {
             describe::handle_describe_fragment(handler_args, fragment_id.into()).await
@@ -666,7 +675,7 @@ pub async fn handle(
         }
         Statement::Recover => recover::handle_recover(handler_args).await,
         Statement::SetVariable {
-            local: _,
+            local,
             variable,
             value,
         } => {
@@ -685,10 +694,10 @@ pub async fn handle(
                 }
                 return Ok(builder.into());
             }
-            variable::handle_set(handler_args, variable, value)
+            variable::handle_set(handler_args, variable, value, local)
         }
-        Statement::SetTimeZone { local: _, value } => {
-            variable::handle_set_time_zone(handler_args, value)
+        Statement::SetTimeZone { local, value } => {
+            variable::handle_set_time_zone(handler_args, value, local)
         }
         Statement::ShowVariable { variable } => variable::handle_show(handler_args, variable),
         Statement::CreateIndex {
@@ -828,7 +837,8 @@ pub async fn handle(
         Statement::AlterTable { name, operation } => match operation {
             AlterTableOperation::AddColumn { .. }
             | AlterTableOperation::DropColumn { .. }
-            | AlterTableOperation::AlterColumn { .. } => {
+            | AlterTableOperation::AlterColumn { .. }
+            | AlterTableOperation::RenameColumn { .. } => {
                 Box::pin(alter_table_column::handle_alter_table_column(
                     handler_args,
                     name,
@@ -978,7 +988,6 @@ pub async fn handle(
             }
             AlterTableOperation::AddConstraint { .. }
             | AlterTableOperation::DropConstraint { .. }
-            | AlterTableOperation::RenameColumn { .. }
             | AlterTableOperation::ChangeColumn { .. }
             | AlterTableOperation::RenameConstraint { .. } => {
                 bail_not_implemented!(
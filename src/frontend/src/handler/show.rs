@@ -52,6 +52,7 @@ use crate::handler::privilege::ObjectCheckItem;
 use crate::session::{SessionImpl, WorkerProcessId};
 use crate::user::user_catalog::UserCatalog;
 use crate::user::{has_access_to_object, has_schema_usage_privilege};
+use crate::utils::redact_definition;
 
 pub fn get_columns_from_table(
     session: &SessionImpl,
@@ -417,6 +418,7 @@ struct ShowJobRow {
     statement: String,
     create_type: String,
     progress: String,
+    phase: String,
 }
 
 #[derive(Fields)]
@@ -793,6 +795,7 @@ pub async fn handle_show_object(
                 statement: job.statement,
                 create_type: job.create_type,
                 progress: job.progress,
+                phase: job.phase,
             });
             return Ok(PgResponse::builder(StatementType::SHOW_COMMAND)
                 .rows(rows)
@@ -912,7 +915,13 @@ pub fn handle_show_create_object(
             if !has_access_to_object(current_user, sink.id, sink.owner) {
                 return Err(CatalogError::not_found("sink", name.to_string()).into());
             }
-            (sink.create_sql(), schema)
+            (
+                redact_definition(
+                    &sink.create_sql(),
+                    session.env().redact_sql_option_keywords(),
+                ),
+                schema,
+            )
         }
         ShowCreateType::Source => {
             let (source, schema) = schema_path
@@ -928,7 +937,10 @@ pub fn handle_show_create_object(
                     )
                 })?
                 .ok_or_else(|| CatalogError::not_found("source", name.to_string()))?;
-            (source.create_sql_purified(), schema)
+            (
+                source.create_sql_redacted(session.env().redact_sql_option_keywords()),
+                schema,
+            )
         }
         ShowCreateType::Index => {
             let (index, schema) = schema_path
@@ -1192,6 +1204,28 @@ mod tests {
         assert_eq!(rows, vec!["Row([Some(b\"public.t1\")])".to_owned(),]);
     }
 
+    #[tokio::test]
+    async fn test_show_create_source_redacted() {
+        let frontend = LocalFrontend::new(Default::default()).await;
+
+        let sql = r#"CREATE SOURCE s1 (column1 varchar)
+        WITH (
+            connector = 'kafka',
+            kafka.topic = 'abc',
+            kafka.brokers = 'localhost:1001',
+            properties.sasl.password = 'not-for-your-eyes'
+        )
+        FORMAT PLAIN ENCODE JSON"#;
+        frontend.run_sql(sql).await.unwrap();
+
+        let rows = frontend
+            .query_formatted_result("SHOW CREATE SOURCE s1")
+            .await;
+        let definition = rows.join("\n");
+        assert!(definition.contains("[REDACTED]"), "{definition}");
+        assert!(!definition.contains("not-for-your-eyes"), "{definition}");
+    }
+
     #[tokio::test]
     async fn test_show_column() {
         let proto_file = create_proto_file(PROTO_FILE_DATA);
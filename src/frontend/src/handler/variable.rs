@@ -25,6 +25,7 @@ use risingwave_sqlparser::ast::{Ident, SetTimeZoneValue, SetVariableValue, Value
 use super::{RwPgResponse, RwPgResponseBuilderExt, fields_to_descriptors};
 use crate::error::Result;
 use crate::handler::HandlerArgs;
+use crate::session::SessionImpl;
 
 /// convert `SetVariableValue` to string while remove the quotes on literals.
 pub(crate) fn set_var_to_param_str(value: &SetVariableValue) -> Option<String> {
@@ -39,10 +40,26 @@ pub(crate) fn set_var_to_param_str(value: &SetVariableValue) -> Option<String> {
     }
 }
 
+/// Records the original value of `name` so that a `SET LOCAL` can be reverted when the
+/// current transaction ends. Returns `false` if there is no explicit transaction in
+/// progress, after notifying the user like the warning of PostgreSQL, in which case the
+/// `SET LOCAL` should take no effect.
+fn record_local_override(session: &SessionImpl, name: &str) -> Result<bool> {
+    let original = session.config().get(name)?;
+    if session.txn_record_local_config(name.to_owned(), original) {
+        Ok(true)
+    } else {
+        // TODO: should be warning
+        session.notice_to_user("SET LOCAL can only be used in transaction blocks");
+        Ok(false)
+    }
+}
+
 pub fn handle_set(
     handler_args: HandlerArgs,
     name: Ident,
     value: SetVariableValue,
+    local: bool,
 ) -> Result<RwPgResponse> {
     // Strip double and single quotes
     let string_val = set_var_to_param_str(&value);
@@ -59,6 +76,10 @@ pub fn handle_set(
             .get_connection_by_name(Some(schema_name.to_owned()), connection_name)?;
     }
 
+    if local && !record_local_override(&handler_args.session, &param_name)? {
+        return Ok(PgResponse::empty_result(StatementType::SET_VARIABLE));
+    }
+
     let mut status = ParameterStatus::default();
 
     struct Reporter<'a> {
@@ -92,6 +113,7 @@ pub fn handle_set(
 pub(super) fn handle_set_time_zone(
     handler_args: HandlerArgs,
     value: SetTimeZoneValue,
+    local: bool,
 ) -> Result<RwPgResponse> {
     let tz_info = match value {
         SetTimeZoneValue::Local => {
@@ -104,6 +126,10 @@ pub(super) fn handle_set_time_zone(
         _ => Ok(value.to_string()),
     }?;
 
+    if local && !record_local_override(&handler_args.session, "timezone")? {
+        return Ok(PgResponse::empty_result(StatementType::SET_VARIABLE));
+    }
+
     handler_args.session.set_config("timezone", tz_info)?;
 
     Ok(PgResponse::empty_result(StatementType::SET_VARIABLE))
@@ -111,7 +137,11 @@ pub(super) fn handle_set_time_zone(
 
 pub(super) fn handle_show(handler_args: HandlerArgs, variable: Vec<Ident>) -> Result<RwPgResponse> {
     // TODO: Verify that the name used in `show` command is indeed always case-insensitive.
-    let name = variable.iter().map(|e| e.real_value()).join(" ");
+    let mut name = variable.iter().map(|e| e.real_value()).join(" ");
+    // `SHOW TIME ZONE` is the PostgreSQL spelling of `SHOW timezone`.
+    if name.eq_ignore_ascii_case("TIME ZONE") {
+        name = "timezone".to_owned();
+    }
     if name.eq_ignore_ascii_case("PARAMETERS") {
         handle_show_system_params(handler_args)
     } else if name.eq_ignore_ascii_case("ALL") {
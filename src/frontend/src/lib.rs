@@ -41,7 +41,6 @@ risingwave_batch_executors::enable!();
 #[macro_use]
 mod catalog;
 
-use std::collections::HashSet;
 use std::time::Duration;
 
 pub use catalog::TableCatalog;
@@ -242,15 +241,7 @@ pub fn start(
 
         let session_mgr = Arc::new(SessionManagerImpl::new(opts).await.unwrap());
         SESSION_MANAGER.get_or_init(|| session_mgr.clone());
-        let redact_sql_option_keywords = Arc::new(
-            session_mgr
-                .env()
-                .batch_config()
-                .redact_sql_option_keywords
-                .iter()
-                .map(|s| s.to_lowercase())
-                .collect::<HashSet<_>>(),
-        );
+        let redact_sql_option_keywords = session_mgr.env().redact_sql_option_keywords();
         let frontend_config = &session_mgr.env().frontend_config();
         let message_memory_manager = Arc::new(MessageMemoryManager::new(
             frontend_config.max_total_query_size_bytes,
@@ -229,9 +229,8 @@ impl<P: PlanPhase> PlanRoot<P> {
     pub fn schema(&self) -> Schema {
         // The schema can be derived from the `out_fields` and `out_names`, so we don't maintain it
         // as a field and always construct one on demand here to keep it in sync.
-        Schema {
-            fields: self
-                .out_fields
+        Schema::new(
+            self.out_fields
                 .ones()
                 .map(|i| self.plan.schema().fields()[i].clone())
                 .zip_eq_debug(&self.out_names)
@@ -240,7 +239,7 @@ impl<P: PlanPhase> PlanRoot<P> {
                     ..field
                 })
                 .collect(),
-        }
+        )
     }
 }
 
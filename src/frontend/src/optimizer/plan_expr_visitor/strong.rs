@@ -137,6 +137,7 @@ impl Strong {
             | ExprType::MakeDate
             | ExprType::MakeTime
             | ExprType::MakeTimestamp
+            | ExprType::MakeInterval
             | ExprType::SecToTimestamptz
             | ExprType::AtTimeZone
             | ExprType::DateTrunc
@@ -245,6 +246,12 @@ impl Strong {
             | ExprType::SetByte
             | ExprType::BitCount
             | ExprType::Hmac
+            | ExprType::Digest
+            | ExprType::StPoint
+            | ExprType::StX
+            | ExprType::StY
+            | ExprType::StDistance
+            | ExprType::StDwithin
             | ExprType::SecureCompare
             | ExprType::Left
             | ExprType::Right
@@ -308,6 +315,7 @@ impl Strong {
             | ExprType::JsonbDeletePath
             | ExprType::JsonbStripNulls
             | ExprType::ToJsonb
+            | ExprType::RowToJson
             | ExprType::JsonbBuildArray
             | ExprType::JsonbBuildArrayVariadic
             | ExprType::JsonbBuildObject
@@ -367,7 +375,8 @@ impl Strong {
             | ExprType::RwEpochToTs
             | ExprType::OpenaiEmbedding
             | ExprType::HasDatabasePrivilege
-            | ExprType::Random => false,
+            | ExprType::Random
+            | ExprType::GenRandomUuid => false,
             ExprType::Unspecified => unreachable!(),
         }
     }
@@ -222,7 +222,7 @@ impl GenericPlanNode for CdcScan {
                 Field::from_with_table_name_prefix(col, &self.table_name)
             })
             .collect();
-        Schema { fields }
+        Schema::new(fields)
     }
 
     fn stream_key(&self) -> Option<Vec<usize>> {
@@ -127,6 +127,112 @@ impl<PlanRef: GenericPlanRef> DynamicFilter<PlanRef> {
     }
 }
 
+/// A variant of [`DynamicFilter`] for band predicates like
+/// `lhs BETWEEN (<scalar subquery>) AND (<scalar subquery>)`, where the right input is a
+/// single row of the two inclusive bounds `(lower, upper)`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BandedDynamicFilter<PlanRef> {
+    left_index: usize,
+    left: PlanRef,
+    /// The right input must have exactly the two columns `(lower, upper)`.
+    right: PlanRef,
+}
+
+impl<PlanRef> BandedDynamicFilter<PlanRef> {
+    pub fn left_index(&self) -> usize {
+        self.left_index
+    }
+
+    pub fn left(&self) -> &PlanRef {
+        &self.left
+    }
+
+    pub fn right(&self) -> &PlanRef {
+        &self.right
+    }
+}
+
+impl<PlanRef: GenericPlanRef> GenericPlanNode for BandedDynamicFilter<PlanRef> {
+    fn schema(&self) -> Schema {
+        self.left.schema().clone()
+    }
+
+    fn stream_key(&self) -> Option<Vec<usize>> {
+        Some(self.left.stream_key()?.to_vec())
+    }
+
+    fn ctx(&self) -> OptimizerContextRef {
+        self.left.ctx()
+    }
+
+    fn functional_dependency(&self) -> FunctionalDependencySet {
+        self.left.functional_dependency().clone()
+    }
+}
+
+impl<PlanRef: GenericPlanRef> BandedDynamicFilter<PlanRef> {
+    pub fn new(left_index: usize, left: PlanRef, right: PlanRef) -> Self {
+        assert_eq!(right.schema().len(), 2);
+        Self {
+            left_index,
+            left,
+            right,
+        }
+    }
+
+    pub fn clone_with_left_right(&self, left: PlanRef, right: PlanRef) -> Self {
+        Self::new(self.left_index, left, right)
+    }
+
+    /// normalize to the join predicate, i.e. the two inclusive bound comparisons
+    pub fn predicate(&self) -> Condition {
+        let left_ref = || {
+            ExprImpl::from(InputRef::new(
+                self.left_index,
+                self.left.schema().fields()[self.left_index].data_type(),
+            ))
+        };
+        let right_ref = |i: usize| {
+            ExprImpl::from(InputRef::new(
+                self.left.schema().len() + i,
+                self.right.schema().fields()[i].data_type(),
+            ))
+        };
+        Condition {
+            conjunctions: vec![
+                ExprImpl::from(
+                    FunctionCall::new(
+                        ExprType::GreaterThanOrEqual,
+                        vec![left_ref(), right_ref(0)],
+                    )
+                    .unwrap(),
+                ),
+                ExprImpl::from(
+                    FunctionCall::new(ExprType::LessThanOrEqual, vec![left_ref(), right_ref(1)])
+                        .unwrap(),
+                ),
+            ],
+        }
+    }
+
+    fn condition_display(&self) -> (Condition, Schema) {
+        let mut concat_schema = self.left.schema().fields.clone();
+        concat_schema.extend(self.right.schema().fields.clone());
+        let concat_schema = Schema::new(concat_schema);
+
+        let predicate = self.predicate();
+        (predicate, concat_schema)
+    }
+
+    pub fn pretty_field<'a>(&self) -> Pretty<'a> {
+        let (condition, input_schema) = &self.condition_display();
+        Pretty::debug(&ConditionDisplay {
+            condition,
+            input_schema,
+        })
+    }
+}
+
 pub fn infer_left_internal_table_catalog(
     me: impl stream::StreamPlanNodeMetadata,
     left_key_index: usize,
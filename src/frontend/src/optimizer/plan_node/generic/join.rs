@@ -299,7 +299,7 @@ impl<PlanRef: GenericPlanRef> GenericPlanNode for Join<PlanRef> {
                 ),
             })
             .collect();
-        Schema { fields }
+        Schema::new(fields)
     }
 
     fn stream_key(&self) -> Option<Vec<usize>> {
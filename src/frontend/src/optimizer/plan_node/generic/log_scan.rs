@@ -144,7 +144,7 @@ impl LogScan {
             OP_TYPE,
             format!("{}.{}", &self.table_name, OP_NAME),
         ));
-        Schema { fields }
+        Schema::new(fields)
     }
 
     pub(crate) fn out_fields(&self) -> FixedBitSet {
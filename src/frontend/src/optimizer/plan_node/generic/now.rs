@@ -51,14 +51,14 @@ impl GenericPlanNode for Now {
     }
 
     fn schema(&self) -> risingwave_common::catalog::Schema {
-        Schema::new(vec![Field {
-            data_type: DataType::Timestamptz,
-            name: String::from(if self.mode.is_update_current() {
+        Schema::new(vec![Field::with_name(
+            DataType::Timestamptz,
+            String::from(if self.mode.is_update_current() {
                 "now"
             } else {
                 "ts"
             }),
-        }])
+        )])
     }
 
     fn stream_key(&self) -> Option<Vec<usize>> {
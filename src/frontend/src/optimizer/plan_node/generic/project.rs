@@ -125,7 +125,7 @@ impl<PlanRef: GenericPlanRef> GenericPlanNode for Project<PlanRef> {
                 Field::with_name(expr.return_type(), name)
             })
             .collect();
-        Schema { fields }
+        Schema::new(fields)
     }
 
     fn stream_key(&self) -> Option<Vec<usize>> {
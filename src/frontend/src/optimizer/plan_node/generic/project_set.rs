@@ -92,7 +92,7 @@ impl<PlanRef: GenericPlanRef> GenericPlanNode for ProjectSet<PlanRef> {
             Field::with_name(expr.return_type(), name)
         }));
 
-        Schema { fields }
+        Schema::new(fields)
     }
 
     fn stream_key(&self) -> Option<Vec<usize>> {
@@ -73,7 +73,7 @@ impl GenericPlanNode for Source {
             .iter()
             .map(|c| (&c.column_desc).into())
             .collect();
-        Schema { fields }
+        Schema::new(fields)
     }
 
     fn stream_key(&self) -> Option<Vec<usize>> {
@@ -138,20 +138,14 @@ impl Source {
             vec![
                 ColumnCatalog {
                     column_desc: ColumnDesc::from_field_with_column_id(
-                        &Field {
-                            name: "file_path".to_owned(),
-                            data_type: DataType::Varchar,
-                        },
+                        &Field::with_name(DataType::Varchar, "file_path".to_owned()),
                         0,
                     ),
                     is_hidden: false,
                 },
                 ColumnCatalog {
                     column_desc: ColumnDesc::from_field_with_column_id(
-                        &Field {
-                            name: "file_scan_task".to_owned(),
-                            data_type: DataType::Jsonb,
-                        },
+                        &Field::with_name(DataType::Jsonb, "file_scan_task".to_owned()),
                         1,
                     ),
                     is_hidden: false,
@@ -161,20 +155,14 @@ impl Source {
             vec![
                 ColumnCatalog {
                     column_desc: ColumnDesc::from_field_with_column_id(
-                        &Field {
-                            name: "batch_task_id".to_owned(),
-                            data_type: DataType::Varchar,
-                        },
+                        &Field::with_name(DataType::Varchar, "batch_task_id".to_owned()),
                         0,
                     ),
                     is_hidden: false,
                 },
                 ColumnCatalog {
                     column_desc: ColumnDesc::from_field_with_column_id(
-                        &Field {
-                            name: "batch_task_info".to_owned(),
-                            data_type: DataType::Jsonb,
-                        },
+                        &Field::with_name(DataType::Jsonb, "batch_task_info".to_owned()),
                         1,
                     ),
                     is_hidden: false,
@@ -184,10 +172,7 @@ impl Source {
             vec![
                 ColumnCatalog {
                     column_desc: ColumnDesc::from_field_with_column_id(
-                        &Field {
-                            name: "filename".to_owned(),
-                            data_type: DataType::Varchar,
-                        },
+                        &Field::with_name(DataType::Varchar, "filename".to_owned()),
                         0,
                     ),
                     is_hidden: false,
@@ -195,20 +180,14 @@ impl Source {
                 // This columns seems unused.
                 ColumnCatalog {
                     column_desc: ColumnDesc::from_field_with_column_id(
-                        &Field {
-                            name: "last_edit_time".to_owned(),
-                            data_type: DataType::Timestamptz,
-                        },
+                        &Field::with_name(DataType::Timestamptz, "last_edit_time".to_owned()),
                         1,
                     ),
                     is_hidden: false,
                 },
                 ColumnCatalog {
                     column_desc: ColumnDesc::from_field_with_column_id(
-                        &Field {
-                            name: "file_size".to_owned(),
-                            data_type: DataType::Int64,
-                        },
+                        &Field::with_name(DataType::Int64, "file_size".to_owned()),
                         2,
                     ),
                     is_hidden: false,
@@ -326,14 +305,8 @@ impl Source {
     pub fn infer_internal_table_catalog(is_distributed: bool) -> TableCatalog {
         let mut builder = TableCatalogBuilder::default();
 
-        let key = Field {
-            data_type: DataType::Varchar,
-            name: "partition_id".to_owned(),
-        };
-        let value = Field {
-            data_type: DataType::Jsonb,
-            name: "offset_info".to_owned(),
-        };
+        let key = Field::with_name(DataType::Varchar, "partition_id".to_owned());
+        let value = Field::with_name(DataType::Jsonb, "offset_info".to_owned());
 
         let ordered_col_idx = builder.add_column(&key);
         builder.add_column(&value);
@@ -114,7 +114,7 @@ impl GenericPlanNode for SysScan {
                 Field::from_with_table_name_prefix(col, &self.table.name)
             })
             .collect();
-        Schema { fields }
+        Schema::new(fields)
     }
 
     fn stream_key(&self) -> Option<Vec<usize>> {
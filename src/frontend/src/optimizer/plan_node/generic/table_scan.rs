@@ -331,7 +331,7 @@ impl TableScan {
             .iter()
             .map(|col| Field::from_with_table_name_prefix(&col.column_desc, self.table_name()))
             .collect();
-        Schema { fields }
+        Schema::new(fields)
     }
 
     // Check if the scan is cross-database
@@ -350,7 +350,7 @@ impl GenericPlanNode for TableScan {
                 Field::from_with_table_name_prefix(&col.column_desc, self.table_name())
             })
             .collect();
-        Schema { fields }
+        Schema::new(fields)
     }
 
     fn stream_key(&self) -> Option<Vec<usize>> {
@@ -1582,7 +1582,7 @@ mod tests {
             Field::with_name(ty.clone(), "v2"),
             Field::with_name(ty.clone(), "v3"),
         ];
-        let values = LogicalValues::new(vec![], Schema { fields }, ctx);
+        let values = LogicalValues::new(vec![], Schema::new(fields), ctx);
         let input = PlanRef::from(values);
         let input_ref_1 = InputRef::new(0, ty.clone());
         let input_ref_2 = InputRef::new(1, ty.clone());
@@ -1731,7 +1731,7 @@ mod tests {
     fn generate_agg_call(ty: DataType, fields: Vec<Field>) -> LogicalAgg {
         let ctx = OptimizerContext::mock();
 
-        let values = LogicalValues::new(vec![], Schema { fields }, ctx);
+        let values = LogicalValues::new(vec![], Schema::new(fields), ctx);
         let agg_call = PlanAggCall {
             agg_type: PbAggKind::Min.into(),
             return_type: ty.clone(),
@@ -1845,13 +1845,7 @@ mod tests {
             Field::with_name(ty.clone(), "v2"),
             Field::with_name(ty.clone(), "v3"),
         ];
-        let values: LogicalValues = LogicalValues::new(
-            vec![],
-            Schema {
-                fields: fields.clone(),
-            },
-            ctx,
-        );
+        let values: LogicalValues = LogicalValues::new(vec![], Schema::new(fields.clone()), ctx);
         let agg_call = PlanAggCall {
             agg_type: PbAggKind::Min.into(),
             return_type: ty.clone(),
@@ -1907,13 +1901,7 @@ mod tests {
             Field::with_name(ty.clone(), "v2"),
             Field::with_name(ty.clone(), "v3"),
         ];
-        let values = LogicalValues::new(
-            vec![],
-            Schema {
-                fields: fields.clone(),
-            },
-            ctx,
-        );
+        let values = LogicalValues::new(vec![], Schema::new(fields.clone()), ctx);
 
         let agg_calls = vec![
             PlanAggCall {
@@ -222,7 +222,7 @@ mod tests {
             Field::with_name(DataType::Int32, "v2"),
             Field::with_name(DataType::Int32, "v3"),
         ];
-        let mut values = LogicalValues::new(vec![], Schema { fields }, ctx);
+        let mut values = LogicalValues::new(vec![], Schema::new(fields), ctx);
         values
             .base
             .functional_dependency_mut()
@@ -263,13 +263,7 @@ mod tests {
             Field::with_name(DataType::Int32, "v2"),
             Field::with_name(DataType::Int32, "v3"),
         ];
-        let values = LogicalValues::new(
-            vec![],
-            Schema {
-                fields: fields.clone(),
-            },
-            ctx,
-        );
+        let values = LogicalValues::new(vec![], Schema::new(fields.clone()), ctx);
         let predicate: ExprImpl = ExprImpl::FunctionCall(Box::new(
             FunctionCall::new(
                 Type::LessThan,
@@ -330,13 +324,7 @@ mod tests {
             Field::with_name(DataType::Int32, "v2"),
             Field::with_name(DataType::Int32, "v3"),
         ];
-        let values = LogicalValues::new(
-            vec![],
-            Schema {
-                fields: fields.clone(),
-            },
-            ctx,
-        );
+        let values = LogicalValues::new(vec![], Schema::new(fields.clone()), ctx);
         let predicate: ExprImpl = ExprImpl::FunctionCall(Box::new(
             FunctionCall::new(
                 Type::LessThan,
@@ -398,13 +386,7 @@ mod tests {
             Field::with_name(ty.clone(), "v2"),
             Field::with_name(ty.clone(), "v3"),
         ];
-        let values = LogicalValues::new(
-            vec![],
-            Schema {
-                fields: fields.clone(),
-            },
-            ctx,
-        );
+        let values = LogicalValues::new(vec![], Schema::new(fields.clone()), ctx);
 
         let predicate: ExprImpl = ExprImpl::FunctionCall(Box::new(
             FunctionCall::new(
@@ -456,7 +438,7 @@ mod tests {
             Field::with_name(DataType::Int32, "v3"),
             Field::with_name(DataType::Int32, "v4"),
         ];
-        let mut values = LogicalValues::new(vec![], Schema { fields }, ctx);
+        let mut values = LogicalValues::new(vec![], Schema::new(fields), ctx);
         // 3 --> 1, 2
         values
             .base
@@ -398,13 +398,7 @@ mod test {
             Field::with_name(DataType::Int32, "v1"),
             Field::with_name(DataType::Int32, "v2"),
         ];
-        let values = LogicalValues::new(
-            vec![],
-            Schema {
-                fields: fields.clone(),
-            },
-            ctx,
-        );
+        let values = LogicalValues::new(vec![], Schema::new(fields.clone()), ctx);
         let hop_window: PlanRef = LogicalHopWindow::new(
             values.into(),
             InputRef::new(0, DataType::Date),
@@ -455,7 +449,7 @@ mod test {
             Field::with_name(DataType::Int32, "v1"),
             Field::with_name(DataType::Int32, "v2"),
         ];
-        let mut values = LogicalValues::new(vec![], Schema { fields }, ctx);
+        let mut values = LogicalValues::new(vec![], Schema::new(fields), ctx);
         // 0, 1 --> 2
         values
             .base
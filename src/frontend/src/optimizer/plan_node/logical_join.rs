@@ -42,7 +42,8 @@ use crate::optimizer::plan_node::utils::IndicesDisplay;
 use crate::optimizer::plan_node::{
     BatchHashJoin, BatchLookupJoin, BatchNestedLoopJoin, ColumnPruningContext, EqJoinPredicate,
     LogicalFilter, LogicalScan, PredicatePushdownContext, RewriteStreamContext,
-    StreamDynamicFilter, StreamFilter, StreamTableScan, StreamTemporalJoin, ToStreamContext,
+    StreamBandedDynamicFilter, StreamDynamicFilter, StreamFilter, StreamTableScan,
+    StreamTemporalJoin, ToStreamContext,
 };
 use crate::optimizer::plan_visitor::LogicalCardinalityExt;
 use crate::optimizer::property::{Distribution, RequiredDist};
@@ -546,6 +547,66 @@ impl LogicalJoin {
         Some((left_ref.index, comparator))
     }
 
+    /// Like [`Self::dynamic_filter_candidate`], but for a band predicate
+    /// `left.x >= right.lower AND left.x <= right.upper` against a one-row, two-column
+    /// right side. Returns the left key index.
+    fn banded_dynamic_filter_candidate(&self, predicate: &Condition) -> Option<usize> {
+        // Banded dynamic filter only supports `Inner`/`LeftSemi`.
+        if !matches!(self.join_type(), JoinType::Inner | JoinType::LeftSemi) {
+            return None;
+        }
+
+        // The right side must be a single row of the two bounds `(lower, upper)`.
+        if !self.right().max_one_row() || self.right().schema().len() != 2 {
+            return None;
+        }
+
+        // Exactly the two inclusive bound comparisons.
+        if predicate.conjunctions.len() != 2 {
+            return None;
+        }
+        let left_len = self.left().schema().len();
+        let mut lower_key = None;
+        let mut upper_key = None;
+        for conjunction in &predicate.conjunctions {
+            let (left_ref, comparator, right_ref) = conjunction.as_comparison_cond()?;
+            // Comparison must cross inputs: left input ref vs right bound input ref.
+            if left_ref.index >= left_len || right_ref.index < left_len {
+                return None;
+            }
+            // Both bounds are inclusive in the executor, and each must compare against
+            // its own right column.
+            match (comparator, right_ref.index - left_len) {
+                (PbType::GreaterThanOrEqual, 0) => lower_key = Some(left_ref.index),
+                (PbType::LessThanOrEqual, 1) => upper_key = Some(left_ref.index),
+                _ => return None,
+            }
+        }
+        let left_key_idx = match (lower_key, upper_key) {
+            (Some(l), Some(u)) if l == u => l,
+            _ => return None,
+        };
+
+        // Comparison keys must be type aligned.
+        let left_data_type = &self.left().schema().fields()[left_key_idx].data_type;
+        if self
+            .right()
+            .schema()
+            .fields()
+            .iter()
+            .any(|f| f.data_type != *left_data_type)
+        {
+            return None;
+        }
+
+        // Banded dynamic filter output can only come from the left side.
+        if !self.output_indices().iter().all(|i| *i < left_len) {
+            return None;
+        }
+
+        Some(left_key_idx)
+    }
+
     /// Check whether this join can be treated as a temporal filter for locality optimization.
     ///
     /// This is intentionally stricter than dynamic filter:
@@ -1448,6 +1509,56 @@ impl LogicalJoin {
         }
     }
 
+    fn to_stream_banded_dynamic_filter(
+        &self,
+        predicate: Condition,
+        ctx: &mut ToStreamContext,
+    ) -> Result<Option<StreamPlanRef>> {
+        use super::stream::prelude::*;
+
+        // If the predicate is a band over a one-row, two-column right side, convert the
+        // two scalar subqueries into a single `StreamBandedDynamicFilter` instead of two
+        // stacked dynamic filters.
+        let Some(left_key_idx) = self.banded_dynamic_filter_candidate(&predicate) else {
+            return Ok(None);
+        };
+
+        let left = self.left().to_stream(ctx)?.enforce_concrete_distribution();
+        let right = self.right().to_stream_with_dist_required(
+            &RequiredDist::PhysicalDist(Distribution::Broadcast),
+            ctx,
+        )?;
+
+        assert!(right.as_stream_exchange().is_some());
+        assert_eq!(
+            *right.inputs().iter().exactly_one().unwrap().distribution(),
+            Distribution::Single
+        );
+
+        let core = generic::BandedDynamicFilter::new(left_key_idx, left, right);
+        let plan = StreamBandedDynamicFilter::new(core)?.into();
+        // TODO: `BandedDynamicFilterExecutor` should support `output_indices` in `ChunkBuilder`
+        if self
+            .output_indices()
+            .iter()
+            .copied()
+            .ne(0..self.left().schema().len())
+        {
+            // The schema of banded dynamic filter is always the same as the left side now,
+            // and we have checked that all output columns are from the left side before.
+            let logical_project = generic::Project::with_mapping(
+                plan,
+                ColIndexMapping::with_remaining_columns(
+                    self.output_indices(),
+                    self.left().schema().len(),
+                ),
+            );
+            Ok(Some(StreamProject::new(logical_project).into()))
+        } else {
+            Ok(Some(plan))
+        }
+    }
+
     pub fn index_lookup_join_to_batch_lookup_join(&self) -> Result<BatchPlanRef> {
         let predicate = EqJoinPredicate::create(
             self.left().schema().len(),
@@ -1649,6 +1760,10 @@ impl ToStream for LogicalJoin {
             self.to_stream_dynamic_filter(self.on().clone(), ctx)?
         {
             Ok(dynamic_filter)
+        } else if let Some(banded_dynamic_filter) =
+            self.to_stream_banded_dynamic_filter(self.on().clone(), ctx)?
+        {
+            Ok(banded_dynamic_filter)
         } else {
             Err(RwError::from(ErrorCode::NotSupported(
                 "streaming nested-loop join".to_owned(),
@@ -1856,20 +1971,8 @@ mod tests {
         let fields: Vec<Field> = (1..7)
             .map(|i| Field::with_name(ty.clone(), format!("v{}", i)))
             .collect();
-        let left = LogicalValues::new(
-            vec![],
-            Schema {
-                fields: fields[0..3].to_vec(),
-            },
-            ctx.clone(),
-        );
-        let right = LogicalValues::new(
-            vec![],
-            Schema {
-                fields: fields[3..6].to_vec(),
-            },
-            ctx,
-        );
+        let left = LogicalValues::new(vec![], Schema::new(fields[0..3].to_vec()), ctx.clone());
+        let right = LogicalValues::new(vec![], Schema::new(fields[3..6].to_vec()), ctx);
         let on: ExprImpl = ExprImpl::FunctionCall(Box::new(
             FunctionCall::new(
                 Type::Equal,
@@ -1920,20 +2023,8 @@ mod tests {
         let fields: Vec<Field> = (1..7)
             .map(|i| Field::with_name(ty.clone(), format!("v{}", i)))
             .collect();
-        let left = LogicalValues::new(
-            vec![],
-            Schema {
-                fields: fields[0..3].to_vec(),
-            },
-            ctx.clone(),
-        );
-        let right = LogicalValues::new(
-            vec![],
-            Schema {
-                fields: fields[3..6].to_vec(),
-            },
-            ctx,
-        );
+        let left = LogicalValues::new(vec![], Schema::new(fields[0..3].to_vec()), ctx.clone());
+        let right = LogicalValues::new(vec![], Schema::new(fields[3..6].to_vec()), ctx);
         let on: ExprImpl = ExprImpl::FunctionCall(Box::new(
             FunctionCall::new(
                 Type::Equal,
@@ -2000,20 +2091,8 @@ mod tests {
         let fields: Vec<Field> = (1..7)
             .map(|i| Field::with_name(ty.clone(), format!("v{}", i)))
             .collect();
-        let left = LogicalValues::new(
-            vec![],
-            Schema {
-                fields: fields[0..3].to_vec(),
-            },
-            ctx.clone(),
-        );
-        let right = LogicalValues::new(
-            vec![],
-            Schema {
-                fields: fields[3..6].to_vec(),
-            },
-            ctx,
-        );
+        let left = LogicalValues::new(vec![], Schema::new(fields[0..3].to_vec()), ctx.clone());
+        let right = LogicalValues::new(vec![], Schema::new(fields[3..6].to_vec()), ctx);
         let on: ExprImpl = ExprImpl::FunctionCall(Box::new(
             FunctionCall::new(
                 Type::Equal,
@@ -2075,20 +2154,8 @@ mod tests {
         let fields: Vec<Field> = (1..7)
             .map(|i| Field::with_name(DataType::Int32, format!("v{}", i)))
             .collect();
-        let left = LogicalValues::new(
-            vec![],
-            Schema {
-                fields: fields[0..3].to_vec(),
-            },
-            ctx.clone(),
-        );
-        let right = LogicalValues::new(
-            vec![],
-            Schema {
-                fields: fields[3..6].to_vec(),
-            },
-            ctx,
-        );
+        let left = LogicalValues::new(vec![], Schema::new(fields[0..3].to_vec()), ctx.clone());
+        let right = LogicalValues::new(vec![], Schema::new(fields[3..6].to_vec()), ctx);
 
         fn input_ref(i: usize) -> ExprImpl {
             ExprImpl::InputRef(Box::new(InputRef::new(i, DataType::Int32)))
@@ -2246,20 +2313,8 @@ mod tests {
         let fields: Vec<Field> = (1..7)
             .map(|i| Field::with_name(ty.clone(), format!("v{}", i)))
             .collect();
-        let left = LogicalValues::new(
-            vec![],
-            Schema {
-                fields: fields[0..3].to_vec(),
-            },
-            ctx.clone(),
-        );
-        let right = LogicalValues::new(
-            vec![],
-            Schema {
-                fields: fields[3..6].to_vec(),
-            },
-            ctx,
-        );
+        let left = LogicalValues::new(vec![], Schema::new(fields[0..3].to_vec()), ctx.clone());
+        let right = LogicalValues::new(vec![], Schema::new(fields[3..6].to_vec()), ctx);
         let on: ExprImpl = ExprImpl::FunctionCall(Box::new(
             FunctionCall::new(
                 Type::Equal,
@@ -2332,7 +2387,7 @@ mod tests {
                 Field::with_name(DataType::Int32, "l0"),
                 Field::with_name(DataType::Int32, "l1"),
             ];
-            let mut values = LogicalValues::new(vec![], Schema { fields }, ctx.clone());
+            let mut values = LogicalValues::new(vec![], Schema::new(fields), ctx.clone());
             // 0 --> 1
             values
                 .base
@@ -2346,7 +2401,7 @@ mod tests {
                 Field::with_name(DataType::Int32, "r1"),
                 Field::with_name(DataType::Int32, "r2"),
             ];
-            let mut values = LogicalValues::new(vec![], Schema { fields }, ctx);
+            let mut values = LogicalValues::new(vec![], Schema::new(fields), ctx);
             // 0 --> 1, 2
             values
                 .base
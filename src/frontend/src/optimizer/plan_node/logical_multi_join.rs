@@ -33,7 +33,7 @@ use crate::optimizer::plan_node::{
     ColumnPruningContext, PlanTreeNode, PredicatePushdownContext, RewriteStreamContext,
     ToStreamContext,
 };
-use crate::optimizer::plan_visitor::TemporalJoinValidator;
+use crate::optimizer::plan_visitor::{CardinalityVisitor, PlanVisitor, TemporalJoinValidator};
 use crate::optimizer::property::FunctionalDependencySet;
 use crate::utils::{
     ColIndexMapping, ColIndexMappingRewriteExt, Condition, ConditionDisplay,
@@ -66,7 +66,7 @@ impl Distill for LogicalMultiJoin {
         let fields = (self.inputs.iter())
             .flat_map(|input| input.schema().fields.clone())
             .collect();
-        let input_schema = Schema { fields };
+        let input_schema = Schema::new(fields);
         let cond = Pretty::display(&ConditionDisplay {
             condition: self.on(),
             input_schema: &input_schema,
@@ -229,13 +229,13 @@ impl LogicalMultiJoin {
         };
         let inner2output = ColIndexMapping::with_remaining_columns(&output_indices, tot_col_num);
 
-        let schema = Schema {
-            fields: output_indices
+        let schema = Schema::new(
+            output_indices
                 .iter()
                 .map(|idx| inner_o2i_mapping[*idx])
                 .map(|(input_idx, col_idx)| input_schemas[input_idx].fields()[col_idx].clone())
                 .collect(),
-        };
+        );
 
         let inner_i2o_mappings = {
             let mut i2o_maps = vec![];
@@ -501,6 +501,88 @@ impl LogicalMultiJoin {
         Ok(join_ordering)
     }
 
+    /// Maximum number of inputs for which [`Self::dp_ordering`] enumerates join orders.
+    /// The DP explores `O(2^n * n)` states, so larger joins fall back to the greedy
+    /// [`Self::heuristic_ordering`].
+    const DP_ORDERING_MAX_INPUTS: usize = 8;
+
+    /// Selinger-style dynamic programming over left-deep join orders, minimizing the total
+    /// estimated number of intermediate rows.
+    ///
+    /// Row counts are the upper bounds derived by [`CardinalityVisitor`], so they account
+    /// for scan cardinalities and selective predicates (e.g. an equality on a unique key).
+    /// The output of joining two sides is estimated with a textbook foreign-key
+    /// assumption: each equi-join condition between them divides the cross product by the
+    /// larger side's row count, and a pair without any condition stays a cross product.
+    ///
+    /// Returns `None` when the join is too large for the DP or when some input has no
+    /// known upper bound, in which case there is nothing meaningful to compare and the
+    /// caller should use [`Self::heuristic_ordering`] instead. The chosen order can be
+    /// inspected via `EXPLAIN (verbose)`, where scans display their `cardinality`.
+    pub(crate) fn dp_ordering(&self) -> Option<Vec<usize>> {
+        let n = self.inputs.len();
+        if n > Self::DP_ORDERING_MAX_INPUTS {
+            return None;
+        }
+        let rows: Vec<u128> = self
+            .inputs
+            .iter()
+            .map(|input| {
+                CardinalityVisitor
+                    .visit(input.clone())
+                    .hi()
+                    .map(|hi| hi as u128)
+            })
+            .collect::<Option<_>>()?;
+
+        // Count the equi-join conditions between each pair of inputs.
+        let (eq_join_conditions, _) = self.on.clone().split_by_input_col_nums(
+            &self.input_col_nums(),
+            // only_eq=
+            true,
+        );
+        let mut eq_cond_count = vec![vec![0usize; n]; n];
+        for (&(a, b), cond) in &eq_join_conditions {
+            eq_cond_count[a][b] = cond.conjunctions.len();
+            eq_cond_count[b][a] = cond.conjunctions.len();
+        }
+
+        // `best[mask]` is the cheapest left-deep plan joining exactly the inputs in
+        // `mask`: its total cost, its estimated output rows, and its join order. Ties
+        // are broken towards the user-written order for determinism.
+        let mut best: Vec<Option<(u128, u128, Vec<usize>)>> = vec![None; 1 << n];
+        for (i, &row_count) in rows.iter().enumerate() {
+            best[1 << i] = Some((0, row_count, vec![i]));
+        }
+        for mask in 1usize..(1 << n) {
+            let Some((cost, lhs_rows, order)) = best[mask].clone() else {
+                continue;
+            };
+            for j in 0..n {
+                if mask & (1 << j) != 0 {
+                    continue;
+                }
+                let eq_conds: usize = order.iter().map(|&i| eq_cond_count[i][j]).sum();
+                let mut out = lhs_rows.saturating_mul(rows[j]);
+                for _ in 0..eq_conds {
+                    out /= lhs_rows.max(rows[j]).max(1);
+                }
+                let cost = cost.saturating_add(out);
+                let mut order = order.clone();
+                order.push(j);
+                let candidate = best[mask | (1 << j)].as_ref();
+                if candidate.is_none_or(|(best_cost, _, best_order)| {
+                    (cost, &order) < (*best_cost, best_order)
+                }) {
+                    best[mask | (1 << j)] = Some((cost, out, order));
+                }
+            }
+        }
+
+        let (_, _, join_ordering) = best[(1 << n) - 1].take()?;
+        Some(join_ordering)
+    }
+
     /// transform multijoin into bushy tree join.
     ///
     /// 1. First, use equivalent condition derivation to get derive join relation.
@@ -905,7 +987,7 @@ mod test {
                 Field::with_name(DataType::Int32, "v0"),
                 Field::with_name(DataType::Int32, "v1"),
             ];
-            let mut values = LogicalValues::new(vec![], Schema { fields }, ctx.clone());
+            let mut values = LogicalValues::new(vec![], Schema::new(fields), ctx.clone());
             // 0 --> 1
             values
                 .base
@@ -919,7 +1001,7 @@ mod test {
                 Field::with_name(DataType::Int32, "v3"),
                 Field::with_name(DataType::Int32, "v4"),
             ];
-            let mut values = LogicalValues::new(vec![], Schema { fields }, ctx.clone());
+            let mut values = LogicalValues::new(vec![], Schema::new(fields), ctx.clone());
             // 0 --> 1, 2
             values
                 .base
@@ -932,7 +1014,7 @@ mod test {
                 Field::with_name(DataType::Int32, "v5"),
                 Field::with_name(DataType::Int32, "v6"),
             ];
-            let mut values = LogicalValues::new(vec![], Schema { fields }, ctx);
+            let mut values = LogicalValues::new(vec![], Schema::new(fields), ctx);
             // {} --> 0
             values
                 .base
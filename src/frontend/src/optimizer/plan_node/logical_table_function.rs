@@ -52,12 +52,10 @@ impl LogicalTableFunction {
             // If the function returns a struct, it will be flattened into multiple columns.
             Schema::from(&s)
         } else {
-            Schema {
-                fields: vec![Field::with_name(
-                    table_function.return_type(),
-                    table_function.name(),
-                )],
-            }
+            Schema::new(vec![Field::with_name(
+                table_function.return_type(),
+                table_function.name(),
+            )])
         };
         if with_ordinality {
             schema
@@ -401,7 +401,7 @@ mod tests {
             Field::with_name(ty.clone(), "v2"),
             Field::with_name(ty, "v3"),
         ];
-        let values = LogicalValues::new(vec![], Schema { fields }, ctx);
+        let values = LogicalValues::new(vec![], Schema::new(fields), ctx);
         let input = PlanRef::from(values);
 
         let original_logical = LogicalTopN::new(input, 1, 0, false, Order::default(), vec![1]);
@@ -353,7 +353,7 @@ mod tests {
             Field::with_name(ty.clone(), "v2"),
             Field::with_name(ty, "v3"),
         ];
-        let values1 = LogicalValues::new(vec![], Schema { fields }, ctx);
+        let values1 = LogicalValues::new(vec![], Schema::new(fields), ctx);
 
         let values2 = values1.clone();
 
@@ -380,7 +380,7 @@ mod tests {
             Field::with_name(ty.clone(), "v2"),
             Field::with_name(ty, "v3"),
         ];
-        let values1 = LogicalValues::new(vec![], Schema { fields }, ctx);
+        let values1 = LogicalValues::new(vec![], Schema::new(fields), ctx);
 
         let values2 = values1.clone();
 
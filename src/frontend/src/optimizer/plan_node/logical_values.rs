@@ -162,7 +162,7 @@ impl ColPrunable for LogicalValues {
             .iter()
             .map(|i| self.schema().fields[*i].clone())
             .collect();
-        Self::new(rows, Schema { fields }, self.base.ctx()).into()
+        Self::new(rows, Schema::new(fields), self.base.ctx()).into()
     }
 }
 
@@ -99,7 +99,7 @@ impl GenericPlanNode for VectorSearchCore {
             .map(|idx| self.input.schema()[*idx].clone())
             .chain([Field::new("vector_distance", DataType::Float64)])
             .collect();
-        Schema { fields }
+        Schema::new(fields)
     }
 
     fn stream_key(&self) -> Option<Vec<usize>> {
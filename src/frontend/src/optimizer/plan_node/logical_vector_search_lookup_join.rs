@@ -97,7 +97,7 @@ impl GenericPlanNode for VectorSearchLookupJoinCore {
             )])
             .collect();
 
-        Schema { fields }
+        Schema::new(fields)
     }
 
     fn stream_key(&self) -> Option<Vec<usize>> {
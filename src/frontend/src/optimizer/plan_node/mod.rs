@@ -1074,6 +1074,7 @@ mod logical_union;
 mod logical_update;
 mod logical_values;
 mod stream_asof_join;
+mod stream_banded_dynamic_filter;
 mod stream_changelog;
 mod stream_dedup;
 mod stream_delta_join;
@@ -1223,6 +1224,7 @@ pub use stream_changelog::StreamChangeLog;
 pub use stream_dedup::StreamDedup;
 pub use stream_delta_join::StreamDeltaJoin;
 pub use stream_dml::StreamDml;
+pub use stream_banded_dynamic_filter::StreamBandedDynamicFilter;
 pub use stream_dynamic_filter::StreamDynamicFilter;
 pub use stream_eowc_gap_fill::StreamEowcGapFill;
 pub use stream_eowc_over_window::StreamEowcOverWindow;
@@ -1388,6 +1390,7 @@ macro_rules! for_all_plan_nodes {
             , { Stream, DeltaJoin }
             , { Stream, Expand }
             , { Stream, DynamicFilter }
+            , { Stream, BandedDynamicFilter }
             , { Stream, ProjectSet }
             , { Stream, GroupTopN }
             , { Stream, Union }
@@ -0,0 +1,105 @@
+// Copyright 2025 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use pretty_xmlish::XmlNode;
+use risingwave_pb::stream_plan::BandedDynamicFilterNode;
+use risingwave_pb::stream_plan::stream_node::NodeBody;
+
+use super::generic::BandedDynamicFilter;
+use super::stream::prelude::*;
+use super::utils::{Distill, childless_record, column_names_pretty};
+use super::{ExprRewritable, generic};
+use crate::optimizer::plan_node::expr_visitable::ExprVisitable;
+use crate::optimizer::plan_node::{
+    PlanBase, PlanTreeNodeBinary, StreamNode, StreamPlanRef as PlanRef,
+};
+use crate::optimizer::property::{MonotonicityMap, StreamKind, WatermarkColumns};
+use crate::stream_fragmenter::BuildFragmentGraphState;
+
+/// Streams a band predicate `lhs BETWEEN lower AND upper` where both bounds come from a
+/// single one-row right input, keeping one copy of the left state instead of stacking two
+/// [`StreamDynamicFilter`](super::StreamDynamicFilter)s.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct StreamBandedDynamicFilter {
+    pub base: PlanBase<Stream>,
+    core: generic::BandedDynamicFilter<PlanRef>,
+}
+
+impl StreamBandedDynamicFilter {
+    pub fn new(core: BandedDynamicFilter<PlanRef>) -> Result<Self> {
+        // TODO(kind): theoretically, the impl can handle upsert stream.
+        let _left_kind = reject_upsert_input!(core.left());
+
+        // The band can move in both directions, so the output always retracts, and
+        // watermarks can neither clean the left state nor be propagated.
+        let base = PlanBase::new_stream_with_core(
+            &core,
+            core.left().distribution().clone(),
+            StreamKind::Retract,
+            false, // TODO(rc): decide EOWC property
+            WatermarkColumns::new(),
+            MonotonicityMap::new(),
+        );
+
+        Ok(Self { base, core })
+    }
+}
+
+impl Distill for StreamBandedDynamicFilter {
+    fn distill<'a>(&self) -> XmlNode<'a> {
+        let pred = self.core.pretty_field();
+        let vec = vec![
+            ("predicate", pred),
+            ("output", column_names_pretty(self.schema())),
+        ];
+        childless_record("StreamBandedDynamicFilter", vec)
+    }
+}
+
+impl PlanTreeNodeBinary<Stream> for StreamBandedDynamicFilter {
+    fn left(&self) -> PlanRef {
+        self.core.left().clone()
+    }
+
+    fn right(&self) -> PlanRef {
+        self.core.right().clone()
+    }
+
+    fn clone_with_left_right(&self, left: PlanRef, right: PlanRef) -> Self {
+        Self::new(self.core.clone_with_left_right(left, right)).unwrap()
+    }
+}
+
+impl_plan_tree_node_for_binary! { Stream, StreamBandedDynamicFilter }
+
+impl StreamNode for StreamBandedDynamicFilter {
+    fn to_stream_prost_body(&self, state: &mut BuildFragmentGraphState) -> NodeBody {
+        use generic::dynamic_filter::*;
+        let left_index = self.core.left_index();
+        let left_table = infer_left_internal_table_catalog(&self.base, left_index)
+            .with_id(state.gen_table_id_wrapped());
+        let right = self.right();
+        let right_table = infer_right_internal_table_catalog(right.plan_base())
+            .with_id(state.gen_table_id_wrapped());
+        NodeBody::BandedDynamicFilter(Box::new(BandedDynamicFilterNode {
+            left_key: left_index as u32,
+            left_table: Some(left_table.to_internal_table_prost()),
+            right_table: Some(right_table.to_internal_table_prost()),
+        }))
+    }
+}
+
+impl ExprRewritable<Stream> for StreamBandedDynamicFilter {}
+
+impl ExprVisitable for StreamBandedDynamicFilter {}
@@ -62,6 +62,16 @@ impl StreamDedup {
             builder.add_order_column(*idx, OrderType::ascending());
         });
 
+        // State cleaning by watermark is a range delete on the pk prefix, so it is only
+        // possible when the first dedup column carries a watermark, e.g. time-keyed
+        // deduplication like `DISTINCT ON (ts, event_id)` with a watermark on `ts`. The
+        // retention window is then configured by the lag of the watermark expression.
+        if let Some(first_dedup_col) = self.core.dedup_cols.first()
+            && self.base.watermark_columns().contains(*first_dedup_col)
+        {
+            builder.set_clean_watermark_indices(vec![*first_dedup_col]);
+        }
+
         let read_prefix_len_hint = builder.get_current_pk_len();
 
         builder.build(
@@ -68,10 +68,10 @@ impl StreamSource {
         } else {
             // iceberg list node (singleton) stores last_snapshot (just 1 row, no pk)
             let mut builder = TableCatalogBuilder::default();
-            builder.add_column(&Field {
-                data_type: DataType::Int64,
-                name: "last_snapshot".to_owned(),
-            });
+            builder.add_column(&Field::with_name(
+                DataType::Int64,
+                "last_snapshot".to_owned(),
+            ));
             builder.build(vec![], 0)
         }
     }
@@ -85,14 +85,11 @@ impl StreamSourceScan {
     pub fn infer_internal_table_catalog() -> TableCatalog {
         let mut builder = TableCatalogBuilder::default();
 
-        let key = Field {
-            data_type: DataType::Varchar,
-            name: Self::PARTITION_ID_COLUMN_NAME.to_owned(),
-        };
-        let value = Field {
-            data_type: DataType::Jsonb,
-            name: Self::BACKFILL_PROGRESS_COLUMN_NAME.to_owned(),
-        };
+        let key = Field::with_name(DataType::Varchar, Self::PARTITION_ID_COLUMN_NAME.to_owned());
+        let value = Field::with_name(
+            DataType::Jsonb,
+            Self::BACKFILL_PROGRESS_COLUMN_NAME.to_owned(),
+        );
 
         let ordered_col_idx = builder.add_column(&key);
         builder.add_column(&value);
@@ -479,6 +479,8 @@ impl StreamTableScan {
             state_table: Some(catalog),
             arrangement_table,
             rate_limit: self.base.ctx().overwrite_options().backfill_rate_limit,
+            adaptive_rate_limit_min: self.base.ctx().overwrite_options().backfill_rate_limit_min,
+            adaptive_rate_limit_max: self.base.ctx().overwrite_options().backfill_rate_limit_max,
             pk_scan_range: self.pk_scan_range.as_ref().map(|sr| sr.to_protobuf()),
             ..Default::default()
         }));
@@ -133,14 +133,8 @@ impl_plan_tree_node_for_unary! { Stream, StreamWatermarkFilter}
 pub fn infer_internal_table_catalog(watermark_type: DataType) -> TableCatalog {
     let mut builder = TableCatalogBuilder::default();
 
-    let key = Field {
-        data_type: DataType::Int16,
-        name: "vnode".to_owned(),
-    };
-    let value = Field {
-        data_type: watermark_type,
-        name: "offset".to_owned(),
-    };
+    let key = Field::with_name(DataType::Int16, "vnode".to_owned());
+    let value = Field::with_name(watermark_type, "offset".to_owned());
 
     let ordered_col_idx = builder.add_column(&key);
     builder.add_column(&value);
@@ -156,7 +156,7 @@ impl LogicalPlanVisitor for InputRefValidator {
             .iter()
             .map(|col| Field::from_with_table_name_prefix(col, plan.table_name()))
             .collect();
-        let input_schema = Schema { fields };
+        let input_schema = Schema::new(fields);
         let mut vis = ExprVis {
             schema: &input_schema,
             string: None,
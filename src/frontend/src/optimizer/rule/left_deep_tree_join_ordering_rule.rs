@@ -21,7 +21,12 @@ impl Rule<Logical> for LeftDeepTreeJoinOrderingRule {
     fn apply(&self, plan: PlanRef) -> Option<PlanRef> {
         let join = plan.as_logical_multi_join()?;
         // check if join is inner and can be merged into multijoin
-        let join_ordering = join.heuristic_ordering().ok()?; // maybe panic here instead?
+        // Prefer the cost-based DP ordering when row-count estimates are available;
+        // fall back to the heuristic ordering otherwise.
+        let join_ordering = match join.dp_ordering() {
+            Some(ordering) => ordering,
+            None => join.heuristic_ordering().ok()?, // maybe panic here instead?
+        };
         let left_deep_join = join.as_reordered_left_deep_join(&join_ordering);
         Some(left_deep_join)
     }
@@ -74,27 +79,11 @@ mod tests {
         let fields: Vec<Field> = (1..10)
             .map(|i| Field::with_name(ty.clone(), format!("v{}", i)))
             .collect();
-        let relation_a = LogicalValues::new(
-            vec![],
-            Schema {
-                fields: fields[0..3].to_vec(),
-            },
-            ctx.clone(),
-        );
-        let relation_c = LogicalValues::new(
-            vec![],
-            Schema {
-                fields: fields[3..6].to_vec(),
-            },
-            ctx.clone(),
-        );
-        let relation_b = LogicalValues::new(
-            vec![],
-            Schema {
-                fields: fields[6..9].to_vec(),
-            },
-            ctx,
-        );
+        let relation_a =
+            LogicalValues::new(vec![], Schema::new(fields[0..3].to_vec()), ctx.clone());
+        let relation_c =
+            LogicalValues::new(vec![], Schema::new(fields[3..6].to_vec()), ctx.clone());
+        let relation_b = LogicalValues::new(vec![], Schema::new(fields[6..9].to_vec()), ctx);
 
         let join_type = JoinType::Inner;
         let join_0 = LogicalJoin::new(
@@ -132,4 +121,50 @@ mod tests {
 
         assert_eq!(multi_join.heuristic_ordering().unwrap(), vec![0, 2, 1]);
     }
+
+    #[tokio::test]
+    async fn test_dp_join_ordering_from_multijoin() {
+        // A join graph A-B, A-C with row counts A: 8, C: 4, B: 1 and initial ordering
+        // (A ⋈ C) ⋈ B. The DP joins the single-row B below the larger C, i.e. A, B, C.
+
+        let ty = DataType::Int32;
+        let ctx = OptimizerContext::mock();
+        let fields: Vec<Field> = (1..10)
+            .map(|i| Field::with_name(ty.clone(), format!("v{}", i)))
+            .collect();
+        let rows = |count: usize| vec![vec![ExprImpl::literal_int(1); 3]; count];
+        let relation_a = LogicalValues::new(rows(8), Schema::new(fields[0..3].to_vec()), ctx.clone());
+        let relation_c = LogicalValues::new(rows(4), Schema::new(fields[3..6].to_vec()), ctx.clone());
+        let relation_b = LogicalValues::new(rows(1), Schema::new(fields[6..9].to_vec()), ctx);
+
+        let eq_cond = |l: usize, r: usize| {
+            ExprImpl::FunctionCall(Box::new(
+                FunctionCall::new(
+                    Type::Equal,
+                    vec![
+                        ExprImpl::InputRef(Box::new(InputRef::new(l, ty.clone()))),
+                        ExprImpl::InputRef(Box::new(InputRef::new(r, ty.clone()))),
+                    ],
+                )
+                .unwrap(),
+            ))
+        };
+        // A.v1 = C.v4
+        let join_0 = LogicalJoin::new(
+            relation_a.into(),
+            relation_c.into(),
+            JoinType::Inner,
+            Condition::with_expr(eq_cond(0, 3)),
+        );
+        // A.v3 = B.v9
+        let join_1 = LogicalJoin::new(
+            join_0.into(),
+            relation_b.into(),
+            JoinType::Inner,
+            Condition::with_expr(eq_cond(2, 8)),
+        );
+        let multi_join = LogicalMultiJoinBuilder::new(join_1.into()).build();
+
+        assert_eq!(multi_join.dp_ordering().unwrap(), vec![0, 2, 1]);
+    }
 }
@@ -55,27 +55,9 @@ mod tests {
         let fields: Vec<Field> = (1..10)
             .map(|i| Field::with_name(ty.clone(), format!("v{}", i)))
             .collect();
-        let left = LogicalValues::new(
-            vec![],
-            Schema {
-                fields: fields[0..3].to_vec(),
-            },
-            ctx.clone(),
-        );
-        let right = LogicalValues::new(
-            vec![],
-            Schema {
-                fields: fields[3..6].to_vec(),
-            },
-            ctx.clone(),
-        );
-        let mid = LogicalValues::new(
-            vec![],
-            Schema {
-                fields: fields[6..9].to_vec(),
-            },
-            ctx,
-        );
+        let left = LogicalValues::new(vec![], Schema::new(fields[0..3].to_vec()), ctx.clone());
+        let right = LogicalValues::new(vec![], Schema::new(fields[3..6].to_vec()), ctx.clone());
+        let mid = LogicalValues::new(vec![], Schema::new(fields[6..9].to_vec()), ctx);
 
         let join_type = JoinType::Inner;
         let on_0: ExprImpl = ExprImpl::FunctionCall(Box::new(
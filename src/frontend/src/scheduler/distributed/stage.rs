@@ -722,6 +722,8 @@ impl StageRunner {
         let expr_context = ExprContext {
             time_zone: self.ctx.session().config().timezone(),
             strict_mode: self.ctx.session().config().batch_expr_strict_mode(),
+            jsonb_decimal_as_string: self.ctx.session().config().jsonb_decimal_as_string(),
+            jsonb_cast_case_insensitive: self.ctx.session().config().jsonb_cast_case_insensitive(),
         };
         // If root, we execute it locally.
         if !self.is_root_stage() {
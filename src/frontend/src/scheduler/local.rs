@@ -135,6 +135,8 @@ impl LocalQueryExecution {
         let search_path = self.session.config().search_path();
         let time_zone = self.session.config().timezone();
         let strict_mode = self.session.config().batch_expr_strict_mode();
+        let jsonb_decimal_as_string = self.session.config().jsonb_decimal_as_string();
+        let jsonb_cast_case_insensitive = self.session.config().jsonb_cast_case_insensitive();
         let timeout = self.timeout;
         let meta_client = self.front_env.meta_client_ref();
 
@@ -169,6 +171,13 @@ impl LocalQueryExecution {
         let exec = async move { AUTH_CONTEXT::scope(auth_context, exec).await }.boxed();
         let exec = async move { TIME_ZONE::scope(time_zone, exec).await }.boxed();
         let exec = async move { STRICT_MODE::scope(strict_mode, exec).await }.boxed();
+        let exec =
+            async move { JSONB_DECIMAL_AS_STRING::scope(jsonb_decimal_as_string, exec).await }
+                .boxed();
+        let exec = async move {
+            JSONB_CAST_CASE_INSENSITIVE::scope(jsonb_cast_case_insensitive, exec).await
+        }
+        .boxed();
         let exec = async move { META_CLIENT::scope(meta_client, exec).await }.boxed();
 
         if let Some(timeout) = timeout {
@@ -68,6 +68,7 @@ impl BatchTaskContext for FrontendBatchTaskContext {
                 self.session.env().prometheus_client().cloned(),
                 self.session.env().prometheus_selector().to_owned(),
             )),
+            self.session.env().redact_sql_option_keywords(),
         ))
     }
 
@@ -12,7 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::fmt::{Display, Formatter};
 use std::io::{Error, ErrorKind};
 use std::iter;
@@ -84,7 +84,7 @@ use risingwave_rpc_client::{
     ComputeClientPool, ComputeClientPoolRef, FrontendClientPool, FrontendClientPoolRef, MetaClient,
     MonitorClientPool, MonitorClientPoolRef,
 };
-use risingwave_sqlparser::ast::{ObjectName, Statement};
+use risingwave_sqlparser::ast::{ObjectName, RedactSqlOptionKeywordsRef, Statement};
 use risingwave_sqlparser::parser::Parser;
 use thiserror::Error;
 use thiserror_ext::AsReport;
@@ -683,6 +683,18 @@ impl FrontendEnv {
         &self.frontend_config
     }
 
+    /// The lowercase keywords on which SQL option redaction is based, e.g. when logging
+    /// statements or rendering `SHOW CREATE` definitions.
+    pub fn redact_sql_option_keywords(&self) -> RedactSqlOptionKeywordsRef {
+        Arc::new(
+            self.batch_config
+                .redact_sql_option_keywords
+                .iter()
+                .map(|s| s.to_lowercase())
+                .collect::<HashSet<_>>(),
+        )
+    }
+
     pub fn streaming_config(&self) -> &StreamingConfig {
         &self.streaming_config
     }
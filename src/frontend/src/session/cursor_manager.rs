@@ -26,6 +26,7 @@ use itertools::Itertools;
 use pgwire::pg_field_descriptor::PgFieldDescriptor;
 use pgwire::pg_response::StatementType;
 use pgwire::types::{Format, Row};
+use risingwave_common::array::Op;
 use risingwave_common::catalog::{ColumnCatalog, Field};
 use risingwave_common::error::BoxedError;
 use risingwave_common::session_config::QueryMode;
@@ -37,7 +38,7 @@ use super::SessionImpl;
 use crate::catalog::TableId;
 use crate::catalog::subscription_catalog::SubscriptionCatalog;
 use crate::error::{ErrorCode, Result};
-use crate::expr::{ExprType, FunctionCall, InputRef, Literal};
+use crate::expr::{ExprImpl, ExprType, FunctionCall, InputRef, Literal};
 use crate::handler::HandlerArgs;
 use crate::handler::declare_cursor::create_chunk_stream_for_cursor;
 use crate::handler::query::{RwBatchQueryPlanResult, gen_batch_plan_fragmenter};
@@ -412,8 +413,14 @@ impl SubscriptionCursor {
             // future fetch on the cursor starts from the snapshot when the cursor is declared.
             //
             // TODO: is this the right behavior? Should we delay the query stream initiation till the first fetch?
-            let (chunk_stream, init_query_timer, table_catalog) =
-                Self::initiate_query(None, dependent_table_id, handler_args.clone(), None).await?;
+            let (chunk_stream, init_query_timer, table_catalog) = Self::initiate_query(
+                None,
+                dependent_table_id,
+                handler_args.clone(),
+                None,
+                &subscription.ops,
+            )
+            .await?;
             let pinned_epoch = match handler_args.session.get_pinned_snapshot().ok_or_else(
                 || ErrorCode::InternalError("Fetch Cursor can't find snapshot epoch".to_owned()),
             )? {
@@ -498,6 +505,7 @@ impl SubscriptionCursor {
                                     self.dependent_table_id,
                                     handler_args.clone(),
                                     None,
+                                    &self.subscription.ops,
                                 )
                                 .await?;
                             let table_schema_changed =
@@ -739,6 +747,7 @@ impl SubscriptionCursor {
                 self.dependent_table_id,
                 handler_args,
                 self.seek_pk_row.clone(),
+                &self.subscription.ops,
             ),
             State::Fetch {
                 from_snapshot,
@@ -751,6 +760,7 @@ impl SubscriptionCursor {
                         self.dependent_table_id,
                         handler_args,
                         self.seek_pk_row.clone(),
+                        &self.subscription.ops,
                     )
                 } else {
                     Self::init_batch_plan_for_subscription_cursor(
@@ -758,6 +768,7 @@ impl SubscriptionCursor {
                         self.dependent_table_id,
                         handler_args,
                         self.seek_pk_row.clone(),
+                        &self.subscription.ops,
                     )
                 }
             }
@@ -773,6 +784,7 @@ impl SubscriptionCursor {
         dependent_table_id: TableId,
         handler_args: HandlerArgs,
         seek_pk_row: Option<Row>,
+        ops: &[String],
     ) -> Result<RwBatchQueryPlanResult> {
         let session = handler_args.clone().session;
         let table_catalog = session.get_table_by_id(dependent_table_id)?;
@@ -796,6 +808,7 @@ impl SubscriptionCursor {
             rw_timestamp.map(|rw_timestamp| (rw_timestamp, rw_timestamp)),
             version_id,
             seek_pk_row,
+            ops,
         )
     }
 
@@ -804,6 +817,7 @@ impl SubscriptionCursor {
         dependent_table_id: TableId,
         handler_args: HandlerArgs,
         seek_pk_row: Option<Row>,
+        ops: &[String],
     ) -> Result<(CursorDataChunkStream, Instant, Arc<TableCatalog>)> {
         let init_query_timer = Instant::now();
         let session = handler_args.clone().session;
@@ -813,6 +827,7 @@ impl SubscriptionCursor {
             dependent_table_id,
             handler_args.clone(),
             seek_pk_row,
+            ops,
         )?;
         let plan_fragmenter_result = gen_batch_plan_fragmenter(&handler_args.session, plan_result)?;
         let (chunk_stream, _) =
@@ -878,6 +893,7 @@ impl SubscriptionCursor {
         epoch_range: Option<(u64, u64)>,
         version_id: HummockVersionId,
         seek_pk_rows: Option<Row>,
+        ops: &[String],
     ) -> Result<RwBatchQueryPlanResult> {
         // pk + all column without hidden
         let output_col_idx = table_catalog
@@ -948,6 +964,55 @@ impl SubscriptionCursor {
             (None, None)
         };
 
+        // Push the subscription's `ops` option into the query rather than filtering the
+        // fetched rows: the changelog query filters on its `op` column, and the snapshot
+        // query only yields `Insert` rows, so it degenerates to a constant filter when
+        // inserts are excluded.
+        let op_predicate = if ops.is_empty() {
+            None
+        } else if epoch_range.is_some() {
+            let mut in_args: Vec<ExprImpl> = Vec::with_capacity(ops.len() + 1);
+            in_args.push(
+                InputRef {
+                    // The `op` column is appended right after the output columns, see
+                    // `LogScan::schema`.
+                    index: output_col_idx.len(),
+                    data_type: DataType::Varchar,
+                }
+                .into(),
+            );
+            for op in ops {
+                let op = match op.as_str() {
+                    "insert" => Op::Insert,
+                    "delete" => Op::Delete,
+                    "update_insert" => Op::UpdateInsert,
+                    "update_delete" => Op::UpdateDelete,
+                    // Unknown tokens are rejected in `SubscriptionCatalog::set_ops`.
+                    _ => continue,
+                };
+                in_args.push(
+                    Literal::new(
+                        Some(ScalarImpl::Utf8(op.to_varchar().into())),
+                        DataType::Varchar,
+                    )
+                    .into(),
+                );
+            }
+            Some(Condition {
+                conjunctions: vec![
+                    FunctionCall::new_unchecked(ExprType::In, in_args, DataType::Boolean).into(),
+                ],
+            })
+        } else if ops.iter().any(|op| op == "insert") {
+            None
+        } else {
+            Some(Condition::false_cond())
+        };
+        let predicate = match (predicate, op_predicate) {
+            (Some(predicate), Some(op_predicate)) => Some(predicate.and(op_predicate)),
+            (predicate, op_predicate) => predicate.or(op_predicate),
+        };
+
         let (seq_scan, out_fields, out_names) = if let Some(epoch_range) = epoch_range {
             let core = generic::LogScan::new(
                 table_catalog.name.clone(),
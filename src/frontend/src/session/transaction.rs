@@ -18,6 +18,7 @@ use std::sync::{Arc, Weak};
 use parking_lot::{MappedMutexGuard, Mutex, MutexGuard};
 use risingwave_common::session_config::VisibilityMode;
 use risingwave_hummock_sdk::EpochWithGap;
+use thiserror_ext::AsReport;
 
 use super::SessionImpl;
 use crate::catalog::catalog_service::CatalogWriter;
@@ -66,6 +67,10 @@ pub struct Context {
     /// The snapshot of the transaction, acquired lazily at the first read operation in the
     /// transaction.
     snapshot: Option<ReadSnapshot>,
+
+    /// The session configurations overridden by `SET LOCAL` in this transaction, recorded
+    /// as `(name, original value)` pairs to be restored when the transaction ends.
+    local_config_restore: Vec<(String, String)>,
 }
 
 /// Transaction state.
@@ -119,6 +124,7 @@ impl SessionImpl {
                     id: Id::new(),
                     access_mode: AccessMode::ReadWrite,
                     snapshot: Default::default(),
+                    local_config_restore: Default::default(),
                 })
             }
             State::Implicit(_) => unreachable!("implicit transaction is already in progress"),
@@ -147,6 +153,7 @@ impl SessionImpl {
                     id: ctx.id,
                     access_mode,
                     snapshot: ctx.snapshot.clone(),
+                    local_config_restore: Default::default(),
                 })
             }
             State::Explicit(_) => {
@@ -169,7 +176,7 @@ impl SessionImpl {
             }
             State::Explicit(ctx) => match ctx.access_mode {
                 AccessMode::ReadWrite => unimplemented!(),
-                AccessMode::ReadOnly => *txn = State::Initial,
+                AccessMode::ReadOnly => self.txn_end_explicit(txn),
             },
         }
     }
@@ -187,11 +194,48 @@ impl SessionImpl {
             }
             State::Explicit(ctx) => match ctx.access_mode {
                 AccessMode::ReadWrite => unimplemented!(),
-                AccessMode::ReadOnly => *txn = State::Initial,
+                AccessMode::ReadOnly => self.txn_end_explicit(txn),
             },
         }
     }
 
+    /// Ends an explicit transaction and restores the session configurations overridden by
+    /// `SET LOCAL` in it. Like PostgreSQL, the restoration happens regardless of whether
+    /// the transaction is committed or rolled back.
+    fn txn_end_explicit(&self, mut txn: MutexGuard<'_, State>) {
+        let State::Explicit(ctx) = std::mem::take(&mut *txn) else {
+            unreachable!("explicit transaction is not in progress")
+        };
+        drop(txn); // release the lock as `set_config` may re-enter the session
+
+        // Restore in reverse order so that for a configuration set multiple times, the
+        // value recorded first (i.e., the one before the transaction) wins.
+        for (name, value) in ctx.local_config_restore.into_iter().rev() {
+            if let Err(e) = self.set_config(&name, value) {
+                tracing::warn!(
+                    error = %e.as_report(),
+                    name,
+                    "failed to restore session config on transaction end",
+                );
+            }
+        }
+    }
+
+    /// Records the original value of a session configuration overridden by `SET LOCAL`, to
+    /// be restored when the current explicit transaction ends.
+    ///
+    /// Returns `false` if there is no explicit transaction in progress, in which case
+    /// nothing is recorded and, like PostgreSQL, the `SET LOCAL` should take no effect.
+    pub fn txn_record_local_config(&self, name: String, original: String) -> bool {
+        match &mut *self.txn.lock() {
+            State::Explicit(ctx) => {
+                ctx.local_config_restore.push((name, original));
+                true
+            }
+            State::Initial | State::Implicit(_) => false,
+        }
+    }
+
     /// Returns the transaction context.
     fn txn_ctx(&self) -> MappedMutexGuard<'_, Context> {
         MutexGuard::map(self.txn.lock(), |txn| match txn {
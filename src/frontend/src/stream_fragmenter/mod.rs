@@ -207,7 +207,12 @@ pub fn build_graph_with_strategy(
     ) = {
         let config = ctx.session_ctx().config();
         let streaming_parallelism = config.streaming_parallelism();
-        let job_parallelism = job_type.map(|t| t.to_parallelism(config.deref()));
+        // A `streaming_parallelism` option in the statement's `WITH` clause takes
+        // precedence over the session variables for this job only.
+        let job_parallelism = ctx
+            .with_options()
+            .streaming_parallelism()?
+            .or_else(|| job_type.map(|t| t.to_parallelism(config.deref())));
         let normal_parallelism =
             derive_parallelism(job_type, job_parallelism, streaming_parallelism);
         let backfill_parallelism = if state.has_any_backfill {
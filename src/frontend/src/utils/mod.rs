@@ -209,6 +209,20 @@ impl Hash for dyn DynHash {
     }
 }
 
+/// Redacts sensitive WITH options (e.g. passwords and secret keys) in a SQL definition,
+/// best effort. Returns the definition unchanged if it cannot be parsed.
+///
+/// Options backed by `SECRET` references are rendered as such and need no redaction.
+pub fn redact_definition(
+    definition: &str,
+    keywords: risingwave_sqlparser::ast::RedactSqlOptionKeywordsRef,
+) -> String {
+    match risingwave_sqlparser::parser::Parser::parse_exactly_one(definition) {
+        Ok(stmt) => stmt.to_redacted_string(keywords),
+        Err(_) => definition.to_owned(),
+    }
+}
+
 pub fn ordinal(i: usize) -> String {
     let s = i.to_string();
     let suffix = if s.ends_with('1') && !s.ends_with("11") {
@@ -12,7 +12,11 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use risingwave_common_rate_limit::RateLimit;
+
+use crate::error::{ErrorCode, Result};
 use crate::handler::HandlerArgs;
+use crate::utils::WithOptions;
 
 /// Some options can be configured both in `WITH` clause and session variables.
 /// The config in `WITH` clause has higher priority.
@@ -20,20 +24,106 @@ use crate::handler::HandlerArgs;
 pub struct OverwriteOptions {
     pub source_rate_limit: Option<u32>,
     pub backfill_rate_limit: Option<u32>,
+    /// Lower bound of the adaptive backfill rate, only valid with
+    /// `backfill_rate_limit = 'adaptive'`.
+    pub backfill_rate_limit_min: Option<u64>,
+    /// Upper bound of the adaptive backfill rate, only valid with
+    /// `backfill_rate_limit = 'adaptive'`.
+    pub backfill_rate_limit_max: Option<u64>,
     pub dml_rate_limit: Option<u32>,
     pub sink_rate_limit: Option<u32>,
 }
 
 impl OverwriteOptions {
     pub(crate) const BACKFILL_RATE_LIMIT_KEY: &'static str = "backfill_rate_limit";
+    pub(crate) const BACKFILL_RATE_LIMIT_MAX_KEY: &'static str = "backfill_rate_limit_max";
+    pub(crate) const BACKFILL_RATE_LIMIT_MIN_KEY: &'static str = "backfill_rate_limit_min";
     pub(crate) const DML_RATE_LIMIT_KEY: &'static str = "dml_rate_limit";
     pub(crate) const SINK_RATE_LIMIT_KEY: &'static str = "sink_rate_limit";
     pub(crate) const SOURCE_RATE_LIMIT_KEY: &'static str = "source_rate_limit";
 
+    /// Validate the rate limit options in the `WITH` clause upfront, as
+    /// [`OverwriteOptions::new`] runs in infallible contexts and relies on the values
+    /// being well-formed.
+    pub(crate) fn validate(with_options: &WithOptions) -> Result<()> {
+        let parse = |key: &str, value: &str| {
+            value.parse::<u32>().map_err(|_| {
+                ErrorCode::InvalidParameterValue(format!(
+                    "{key} must be a non-negative integer: {value}"
+                ))
+            })
+        };
+
+        let mut adaptive = false;
+        if let Some(value) = with_options.get(Self::BACKFILL_RATE_LIMIT_KEY) {
+            if value.eq_ignore_ascii_case("adaptive") {
+                adaptive = true;
+            } else if parse(Self::BACKFILL_RATE_LIMIT_KEY, value)? == RateLimit::ADAPTIVE {
+                // `u32::MAX` is the wire sentinel for the adaptive policy; accepting it as
+                // a fixed rate would silently turn the job adaptive downstream.
+                return Err(ErrorCode::InvalidParameterValue(format!(
+                    "{} = {} is reserved, use '{}' = 'adaptive' to enable adaptive rate limiting",
+                    Self::BACKFILL_RATE_LIMIT_KEY,
+                    RateLimit::ADAPTIVE,
+                    Self::BACKFILL_RATE_LIMIT_KEY,
+                ))
+                .into());
+            }
+        }
+        for key in [
+            Self::SOURCE_RATE_LIMIT_KEY,
+            Self::DML_RATE_LIMIT_KEY,
+            Self::SINK_RATE_LIMIT_KEY,
+        ] {
+            if let Some(value) = with_options.get(key)
+                && parse(key, value)? == RateLimit::ADAPTIVE
+            {
+                return Err(ErrorCode::InvalidParameterValue(format!(
+                    "{key} = {} is reserved for the adaptive policy, which is only supported for {}",
+                    RateLimit::ADAPTIVE,
+                    Self::BACKFILL_RATE_LIMIT_KEY,
+                ))
+                .into());
+            }
+        }
+
+        let bound = |key: &str| -> Result<Option<u64>> {
+            let Some(value) = with_options.get(key) else {
+                return Ok(None);
+            };
+            if !adaptive {
+                return Err(ErrorCode::InvalidParameterValue(format!(
+                    "{key} is only valid with {} = 'adaptive'",
+                    Self::BACKFILL_RATE_LIMIT_KEY,
+                ))
+                .into());
+            }
+            let value = value.parse::<u64>().ok().filter(|v| *v > 0).ok_or_else(|| {
+                ErrorCode::InvalidParameterValue(format!(
+                    "{key} must be a positive integer: {value}"
+                ))
+            })?;
+            Ok(Some(value))
+        };
+        let min = bound(Self::BACKFILL_RATE_LIMIT_MIN_KEY)?;
+        let max = bound(Self::BACKFILL_RATE_LIMIT_MAX_KEY)?;
+        if let (Some(min), Some(max)) = (min, max)
+            && min > max
+        {
+            return Err(ErrorCode::InvalidParameterValue(format!(
+                "{} ({min}) must not exceed {} ({max})",
+                Self::BACKFILL_RATE_LIMIT_MIN_KEY,
+                Self::BACKFILL_RATE_LIMIT_MAX_KEY,
+            ))
+            .into());
+        }
+        Ok(())
+    }
+
     pub fn new(args: &mut HandlerArgs) -> Self {
         let source_rate_limit = {
             if let Some(x) = args.with_options.remove(Self::SOURCE_RATE_LIMIT_KEY) {
-                // FIXME(tabVersion): validate the value
+                // Validated by `Self::validate` in `HandlerArgs::new`.
                 Some(x.parse::<u32>().unwrap())
             } else {
                 let rate_limit = args.session.config().source_rate_limit();
@@ -46,8 +136,13 @@ impl OverwriteOptions {
         };
         let backfill_rate_limit = {
             if let Some(x) = args.with_options.remove(Self::BACKFILL_RATE_LIMIT_KEY) {
-                // FIXME(tabVersion): validate the value
-                Some(x.parse::<u32>().unwrap())
+                if x.eq_ignore_ascii_case("adaptive") {
+                    // Driven by barrier latency, see `AdaptiveRateLimiter`.
+                    Some(RateLimit::ADAPTIVE)
+                } else {
+                    // Validated by `Self::validate` in `HandlerArgs::new`.
+                    Some(x.parse::<u32>().unwrap())
+                }
             } else {
                 let rate_limit = args.session.config().backfill_rate_limit();
                 if rate_limit < 0 {
@@ -57,9 +152,17 @@ impl OverwriteOptions {
                 }
             }
         };
+        let backfill_rate_limit_min = args
+            .with_options
+            .remove(Self::BACKFILL_RATE_LIMIT_MIN_KEY)
+            .map(|x| x.parse::<u64>().unwrap());
+        let backfill_rate_limit_max = args
+            .with_options
+            .remove(Self::BACKFILL_RATE_LIMIT_MAX_KEY)
+            .map(|x| x.parse::<u64>().unwrap());
         let dml_rate_limit = {
             if let Some(x) = args.with_options.remove(Self::DML_RATE_LIMIT_KEY) {
-                // FIXME(tabVersion): validate the value
+                // Validated by `Self::validate` in `HandlerArgs::new`.
                 Some(x.parse::<u32>().unwrap())
             } else {
                 let rate_limit = args.session.config().dml_rate_limit();
@@ -72,7 +175,7 @@ impl OverwriteOptions {
         };
         let sink_rate_limit = {
             if let Some(x) = args.with_options.remove(Self::SINK_RATE_LIMIT_KEY) {
-                // FIXME(tabVersion): validate the value
+                // Validated by `Self::validate` in `HandlerArgs::new`.
                 Some(x.parse::<u32>().unwrap())
             } else {
                 let rate_limit = args.session.config().sink_rate_limit();
@@ -86,8 +189,72 @@ impl OverwriteOptions {
         Self {
             source_rate_limit,
             backfill_rate_limit,
+            backfill_rate_limit_min,
+            backfill_rate_limit_max,
             dml_rate_limit,
             sink_rate_limit,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn options(pairs: &[(&str, &str)]) -> WithOptions {
+        WithOptions::new_with_options(
+            pairs
+                .iter()
+                .map(|(k, v)| ((*k).to_owned(), (*v).to_owned()))
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn test_validate_rate_limit_options() {
+        assert!(OverwriteOptions::validate(&options(&[])).is_ok());
+        assert!(OverwriteOptions::validate(&options(&[("backfill_rate_limit", "1000")])).is_ok());
+        assert!(
+            OverwriteOptions::validate(&options(&[("backfill_rate_limit", "adaptive")])).is_ok()
+        );
+
+        // The adaptive sentinel must be spelled as 'adaptive', and garbage is rejected
+        // instead of panicking in `OverwriteOptions::new`.
+        assert!(
+            OverwriteOptions::validate(&options(&[("backfill_rate_limit", "4294967295")])).is_err()
+        );
+        assert!(OverwriteOptions::validate(&options(&[("backfill_rate_limit", "oops")])).is_err());
+        assert!(OverwriteOptions::validate(&options(&[("sink_rate_limit", "adaptive")])).is_err());
+        assert!(
+            OverwriteOptions::validate(&options(&[("dml_rate_limit", "4294967295")])).is_err()
+        );
+
+        // Bounds require the adaptive policy and must form a proper band.
+        assert!(
+            OverwriteOptions::validate(&options(&[
+                ("backfill_rate_limit", "adaptive"),
+                ("backfill_rate_limit_min", "100"),
+                ("backfill_rate_limit_max", "1000"),
+            ]))
+            .is_ok()
+        );
+        assert!(
+            OverwriteOptions::validate(&options(&[("backfill_rate_limit_min", "100")])).is_err()
+        );
+        assert!(
+            OverwriteOptions::validate(&options(&[
+                ("backfill_rate_limit", "adaptive"),
+                ("backfill_rate_limit_min", "1000"),
+                ("backfill_rate_limit_max", "100"),
+            ]))
+            .is_err()
+        );
+        assert!(
+            OverwriteOptions::validate(&options(&[
+                ("backfill_rate_limit", "adaptive"),
+                ("backfill_rate_limit_min", "0"),
+            ]))
+            .is_err()
+        );
+    }
+}
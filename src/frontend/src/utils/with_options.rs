@@ -16,6 +16,7 @@ use std::collections::BTreeMap;
 use std::num::NonZeroU32;
 
 use risingwave_common::catalog::ConnectionId;
+use risingwave_common::session_config::parallelism::ConfigParallelism;
 pub use risingwave_connector::WithOptionsSecResolved;
 use risingwave_connector::connector_common::{
     PRIVATE_LINK_BROKER_REWRITE_MAP_KEY, PRIVATE_LINK_TARGETS_KEY,
@@ -52,6 +53,7 @@ pub mod options {
 }
 
 pub const MV_REFRESH_INTERVAL_SEC_KEY: &str = "refresh.interval.sec";
+pub const STREAMING_PARALLELISM_KEY: &str = "streaming_parallelism";
 pub const SOURCE_REFRESH_MODE_KEY: &str = "refresh_mode";
 pub const SOURCE_REFRESH_INTERVAL_SEC_KEY: &str = "refresh_interval_sec";
 
@@ -178,6 +180,26 @@ impl WithOptions {
             .transpose()
     }
 
+    /// Parse the per-statement streaming parallelism from the options.
+    ///
+    /// When specified, it takes precedence over the session-level
+    /// `streaming_parallelism` variables for this statement only.
+    pub fn streaming_parallelism(&self) -> RwResult<Option<ConfigParallelism>> {
+        self.inner
+            .get(STREAMING_PARALLELISM_KEY)
+            .map(|v| {
+                v.parse::<ConfigParallelism>().map_err(|e| {
+                    RwError::from(ErrorCode::InvalidParameterValue(format!(
+                        "invalid `{}` value {}: {}",
+                        STREAMING_PARALLELISM_KEY,
+                        v,
+                        e.as_report()
+                    )))
+                })
+            })
+            .transpose()
+    }
+
     /// Get a subset of the options from the given keys.
     pub fn subset(&self, keys: impl IntoIterator<Item = impl AsRef<str>>) -> Self {
         let inner = keys
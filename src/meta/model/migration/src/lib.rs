@@ -72,6 +72,7 @@ mod m20260311_000000_legacy_streaming_parallelism_session_params;
 mod m20260312_000000_streaming_job_backfill_parallelism_strategy;
 mod m20260518_000000_disable_unused_read_prefix_hints;
 mod m20260519_000000_streaming_job_batch_refresh_seconds;
+mod m20260829_000000_subscription_ops;
 mod utils;
 
 pub struct Migrator;
@@ -182,6 +183,7 @@ impl MigratorTrait for Migrator {
             Box::new(m20260312_000000_streaming_job_backfill_parallelism_strategy::Migration),
             Box::new(m20260518_000000_disable_unused_read_prefix_hints::Migration),
             Box::new(m20260519_000000_streaming_job_batch_refresh_seconds::Migration),
+            Box::new(m20260829_000000_subscription_ops::Migration),
         ]
     }
 }
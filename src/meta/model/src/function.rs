@@ -104,6 +104,14 @@ impl From<PbFunction> for ActiveModel {
         if let Some(b) = function.is_async {
             options.insert("async".to_string(), b.to_string());
         }
+        if !function.arg_defaults.is_empty() {
+            // Defaults may contain arbitrary SQL text (e.g. commas), so they are stored
+            // JSON-encoded instead of joining like `arg_names`.
+            options.insert(
+                "arg_defaults".to_string(),
+                serde_json::to_string(&function.arg_defaults).unwrap(),
+            );
+        }
         Self {
             function_id: Set(function.id),
             name: Set(function.name),
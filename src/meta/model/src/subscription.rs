@@ -27,6 +27,8 @@ pub struct Model {
     pub subscription_id: SubscriptionId,
     pub name: String,
     pub retention_seconds: i64,
+    // Comma-separated list of exposed changelog operations, empty means all.
+    pub ops: String,
     pub definition: String,
     pub subscription_state: i32,
     pub dependent_table_id: TableId,
@@ -58,6 +60,7 @@ impl From<PbSubscription> for ActiveModel {
             subscription_id: Set(pb_subscription.id),
             name: Set(pb_subscription.name),
             retention_seconds: Set(pb_subscription.retention_seconds as _),
+            ops: Set(pb_subscription.ops.join(",")),
             definition: Set(pb_subscription.definition),
             subscription_state: Set(pb_subscription.subscription_state),
             dependent_table_id: Set(pb_subscription.dependent_table_id),
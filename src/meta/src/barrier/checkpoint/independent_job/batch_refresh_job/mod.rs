@@ -967,11 +967,13 @@ impl BatchRefreshJobCheckpointControl {
                 };
                 Some(BackfillProgress {
                     progress,
+                    phase: "backfilling".to_owned(),
                     backfill_type: PbBackfillType::SnapshotBackfill,
                 })
             }
             BatchRefreshJobStatus::FinishingSnapshot { .. } => Some(BackfillProgress {
                 progress: "BatchRefresh Stopping".to_owned(),
+                phase: "finishing".to_owned(),
                 backfill_type: PbBackfillType::SnapshotBackfill,
             }),
             BatchRefreshJobStatus::Idle | BatchRefreshJobStatus::Resetting { .. } => None,
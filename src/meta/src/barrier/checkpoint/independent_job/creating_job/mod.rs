@@ -534,41 +534,44 @@ impl CreatingStreamingJobControl {
     }
 
     pub(crate) fn gen_backfill_progress(&self) -> BackfillProgress {
-        let progress = match &self.status {
+        let (progress, phase) = match &self.status {
             CreatingStreamingJobStatus::ConsumingSnapshot {
                 create_mview_tracker,
                 ..
             } => {
-                if create_mview_tracker.is_finished() {
+                let progress = if create_mview_tracker.is_finished() {
                     "Snapshot finished".to_owned()
                 } else {
                     let progress = create_mview_tracker.gen_backfill_progress();
                     format!("Snapshot [{}]", progress)
-                }
+                };
+                (progress, "backfilling")
             }
             CreatingStreamingJobStatus::ConsumingLogStore {
                 log_store_progress_tracker,
                 ..
-            } => {
+            } => (
                 format!(
                     "LogStore [{}]",
                     log_store_progress_tracker.gen_backfill_progress()
-                )
-            }
+                ),
+                "catching up log store",
+            ),
             CreatingStreamingJobStatus::Finishing(finish_epoch, ..) => {
                 let committed_epoch = self.max_committed_epoch.expect("should have committed");
                 let lag = Duration::from_millis(
                     Epoch(*finish_epoch).physical_time() - Epoch(committed_epoch).physical_time(),
                 );
-                format!("Finishing [epoch lag: {lag:?}]",)
+                (format!("Finishing [epoch lag: {lag:?}]",), "finishing")
             }
-            CreatingStreamingJobStatus::Resetting(_) => "Resetting".to_owned(),
+            CreatingStreamingJobStatus::Resetting(_) => ("Resetting".to_owned(), "resetting"),
             CreatingStreamingJobStatus::PlaceHolder => {
                 unreachable!()
             }
         };
         BackfillProgress {
             progress,
+            phase: phase.to_owned(),
             backfill_type: PbBackfillType::SnapshotBackfill,
         }
     }
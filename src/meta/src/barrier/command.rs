@@ -1669,6 +1669,7 @@ impl Command {
                                                 .clone(),
                                         ),
                                         name: col.column_desc.as_ref().unwrap().name.clone(),
+                                        ..Default::default()
                                     })
                                     .collect(),
                                 op: Some(op),
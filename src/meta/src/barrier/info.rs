@@ -525,13 +525,24 @@ impl InflightDatabaseInfo {
         self.jobs
             .iter()
             .filter_map(|(job_id, job)| match &job.status {
-                CreateStreamingJobStatus::Init => None,
+                // The first barrier has not been collected yet, so the job is still
+                // building its fragments on the compute nodes.
+                CreateStreamingJobStatus::Init => Some((
+                    *job_id,
+                    BackfillProgress {
+                        progress: "0.0%".to_owned(),
+                        phase: "building fragments".to_owned(),
+                        backfill_type: PbBackfillType::NormalBackfill,
+                    },
+                )),
                 CreateStreamingJobStatus::Creating { tracker } => {
                     let progress = tracker.gen_backfill_progress();
+                    let phase = tracker.gen_backfill_phase();
                     Some((
                         *job_id,
                         BackfillProgress {
                             progress,
+                            phase,
                             backfill_type: PbBackfillType::NormalBackfill,
                         },
                     ))
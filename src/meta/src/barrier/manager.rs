@@ -28,7 +28,6 @@ use thiserror_ext::AsReport;
 use tokio::sync::mpsc::unbounded_channel;
 use tokio::sync::{mpsc, oneshot};
 use tokio::task::JoinHandle;
-use tracing::warn;
 
 use crate::MetaResult;
 use crate::barrier::BarrierManagerRequest::MayHaveSnapshotBackfillingJob;
@@ -62,8 +61,8 @@ impl GlobalBarrierManager {
                 .context("failed to send get ddl progress request")?;
             rx.await.context("failed to receive get ddl progress")?
         };
-        // If not in tracker, means the first barrier not collected yet.
-        // In that case just return progress 0.
+        // If not known to the barrier worker, the job has not injected its first barrier
+        // yet, i.e. it is still building fragments. In that case just return progress 0.
         let job_info = self
             .metadata_manager
             .catalog_controller
@@ -75,17 +74,19 @@ impl GlobalBarrierManager {
                 |(job_id, definition, init_at, create_type, is_serverless_backfill)| {
                     let BackfillProgress {
                         progress,
+                        phase,
                         backfill_type,
                     } = match &mut backfill_progress {
-                        Ok(progress) => progress.remove(&job_id).unwrap_or_else(|| {
-                            warn!(%job_id, "background job has no ddl progress");
-                            BackfillProgress {
+                        Ok(progress) => {
+                            progress.remove(&job_id).unwrap_or_else(|| BackfillProgress {
                                 progress: "0.0%".into(),
+                                phase: "building fragments".into(),
                                 backfill_type: PbBackfillType::NormalBackfill,
-                            }
-                        }),
+                            })
+                        }
                         Err(e) => BackfillProgress {
                             progress: format!("Err[{}]", e.as_report()),
+                            phase: "unknown".into(),
                             backfill_type: PbBackfillType::NormalBackfill,
                         },
                     };
@@ -95,6 +96,7 @@ impl GlobalBarrierManager {
                         create_type: create_type.as_str().into(),
                         initialized_at_time_millis: datetime_to_timestamp_millis(init_at),
                         progress,
+                        phase,
                         is_serverless_backfill,
                         backfill_type: backfill_type as _,
                     }
@@ -108,6 +108,7 @@ impl From<&BarrierManagerStatus> for PbRecoveryStatus {
 
 pub(crate) struct BackfillProgress {
     pub(crate) progress: String,
+    pub(crate) phase: String,
     pub(crate) backfill_type: PbBackfillType,
 }
 
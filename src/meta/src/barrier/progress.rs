@@ -14,6 +14,7 @@
 
 use std::collections::HashMap;
 use std::mem::take;
+use std::time::Instant;
 
 use risingwave_common::catalog::{FragmentTypeFlag, TableId};
 use risingwave_common::id::JobId;
@@ -81,6 +82,10 @@ pub(super) struct Progress {
     /// Buffered rows (for locality backfill) that are yet to be consumed
     /// This is used to calculate precise progress: consumed / (`upstream_total` + buffered)
     mv_backfill_buffered_rows: u64,
+
+    /// When this tracker started observing the job, for estimating the remaining
+    /// backfill time from the observed consumption rate.
+    tracking_since: Instant,
 }
 
 impl Progress {
@@ -111,6 +116,7 @@ impl Progress {
             source_backfill_consumed_rows: 0,
             mv_backfill_buffered_rows: 0,
             backfill_order_state,
+            tracking_since: Instant::now(),
         }
     }
 
@@ -270,11 +276,26 @@ impl Progress {
                 if progress > 1.0 {
                     progress = 0.9999;
                 }
+                // Estimate the remaining time from the consumption rate observed by this
+                // tracker. This is a rough estimate: the rate may vary over time, and
+                // after a recovery the rows consumed before it are attributed to the
+                // tracking duration since it.
+                let eta = {
+                    let elapsed = self.tracking_since.elapsed().as_secs_f64();
+                    let consumed = self.mv_backfill_consumed_rows;
+                    (consumed > 0 && elapsed > 0.0).then(|| {
+                        let rate = consumed as f64 / elapsed;
+                        let remaining = total_rows_to_consume.saturating_sub(consumed);
+                        (remaining as f64 / rate) as u64
+                    })
+                };
+                let eta = eta.map_or_else(String::new, |secs| format!(", ETA: {}s", secs));
                 format!(
-                    "{:.2}% ({}/{})",
+                    "{:.2}% ({}/{}{})",
                     progress * 100.0,
                     self.mv_backfill_consumed_rows,
-                    total_rows_to_consume
+                    total_rows_to_consume,
+                    eta
                 )
             }
         });
@@ -505,6 +526,7 @@ impl CreateMviewProgressTracker {
             mv_backfill_consumed_rows: 0, // Fill only after first barrier pass
             source_backfill_consumed_rows: 0, // Fill only after first barrier pass
             mv_backfill_buffered_rows: 0, // Fill only after first barrier pass
+            tracking_since: Instant::now(),
         }
     }
 
@@ -516,6 +538,21 @@ impl CreateMviewProgressTracker {
         }
     }
 
+    /// Human-readable phase of the tracked DDL for `SHOW JOBS` / `rw_ddl_progress`.
+    /// The "building fragments" phase is reported by the barrier worker before this
+    /// tracker exists (see `InflightDatabaseInfo::gen_backfill_progress`).
+    pub fn gen_backfill_phase(&self) -> String {
+        match &self.status {
+            CreateMviewStatus::Backfilling { progress, .. } => format!(
+                "backfilling ({}/{} actors done)",
+                progress.done_count,
+                progress.states.len()
+            ),
+            CreateMviewStatus::CdcSourceInit => "initializing CDC source".to_owned(),
+            CreateMviewStatus::Finished { .. } => "finishing".to_owned(),
+        }
+    }
+
     pub(crate) fn actor_progresses(&self) -> Vec<ActorBackfillProgress> {
         match &self.status {
             CreateMviewStatus::Backfilling { progress, .. } => {
@@ -994,6 +1031,7 @@ mod tests {
             mv_backfill_consumed_rows: 0,
             source_backfill_consumed_rows: 0,
             mv_backfill_buffered_rows: 0,
+            tracking_since: Instant::now(),
         }
     }
 
@@ -1030,6 +1068,7 @@ mod tests {
             mv_backfill_consumed_rows: 5,
             source_backfill_consumed_rows: 0,
             mv_backfill_buffered_rows: 0,
+            tracking_since: Instant::now(),
         };
 
         let mut tracker = CreateMviewProgressTracker {
@@ -1165,4 +1204,33 @@ mod tests {
         assert!(matches!(tracker.status, CreateMviewStatus::Finished { .. }));
         assert!(tracker.is_finished());
     }
+
+    // The phase reported to `SHOW JOBS` should follow the tracker status.
+    #[test]
+    fn test_gen_backfill_phase_follows_status() {
+        let actor = ActorId::new(1);
+        let tracker_with_status = |status| CreateMviewProgressTracker {
+            tracking_job: TrackingJob {
+                job_id: JobId::new(1),
+                is_recovered: false,
+                source_change: None,
+            },
+            status,
+        };
+
+        let tracker = tracker_with_status(CreateMviewStatus::Backfilling {
+            progress: sample_progress(actor),
+            pending_backfill_nodes: vec![],
+            table_ids_to_truncate: vec![],
+        });
+        assert_eq!(tracker.gen_backfill_phase(), "backfilling (0/1 actors done)");
+
+        let tracker = tracker_with_status(CreateMviewStatus::CdcSourceInit);
+        assert_eq!(tracker.gen_backfill_phase(), "initializing CDC source");
+
+        let tracker = tracker_with_status(CreateMviewStatus::Finished {
+            table_ids_to_truncate: vec![],
+        });
+        assert_eq!(tracker.gen_backfill_phase(), "finishing");
+    }
 }
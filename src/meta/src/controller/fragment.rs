@@ -2240,6 +2240,8 @@ mod tests {
                     expr_context: ExprContext::from(&PbExprContext {
                         time_zone: String::from("America/New_York"),
                         strict_mode: false,
+                        jsonb_decimal_as_string: false,
+                        jsonb_cast_case_insensitive: false,
                     }),
                     config_override: "a.b.c = true".into(),
                 }
@@ -406,6 +406,11 @@ impl From<ObjectModel<subscription::Model>> for PbSubscription {
             name: value.0.name,
             owner: value.1.owner_id as _,
             retention_seconds: value.0.retention_seconds as _,
+            ops: if value.0.ops.is_empty() {
+                vec![]
+            } else {
+                value.0.ops.split(',').map(|s| s.to_owned()).collect()
+            },
             definition: value.0.definition,
             initialized_at_epoch: Some(
                 Epoch::from_unix_millis(datetime_to_timestamp_millis(value.1.initialized_at) as _)
@@ -512,6 +517,13 @@ impl From<ObjectModel<function::Model>> for PbFunction {
             compressed_binary: value.0.compressed_binary,
             kind: Some(value.0.kind.into()),
             always_retry_on_network_error: value.0.always_retry_on_network_error,
+            arg_defaults: value
+                .0
+                .options
+                .as_ref()
+                .and_then(|o| o.0.get("arg_defaults"))
+                .map(|v| serde_json::from_str(v).expect("corrupted arg_defaults"))
+                .unwrap_or_default(),
             is_async: value
                 .0
                 .options
@@ -816,6 +816,20 @@ impl CatalogController {
             .ok_or_else(|| anyhow!("obj has no database id: {:?}", obj))?;
         let streaming_job = streaming_job::Entity::find_by_id(job_id).one(&txn).await?;
 
+        if is_cancelled
+            && let Some(streaming_job) = &streaming_job
+            && streaming_job.job_status == JobStatus::Created
+        {
+            // The job finished creating concurrently with the cancel request. Aborting now
+            // would drop a created object, so refuse within the transaction instead of
+            // relying on the caller's earlier (racy) status check.
+            tracing::warn!(
+                id = %job_id,
+                "streaming job is already created when cancelling, skip aborting"
+            );
+            return Ok((false, Some(database_id)));
+        }
+
         if !is_cancelled && let Some(streaming_job) = &streaming_job {
             assert_ne!(streaming_job.job_status, JobStatus::Created);
             if streaming_job.create_type == CreateType::Background
@@ -2326,6 +2326,7 @@ mod tests {
                     ..Default::default()
                 }),
                 name: "col_v1".into(),
+                ..Default::default()
             }],
             op: Some(SinkSchemachangeOp::AddColumns(PbSinkAddColumnsOp {
                 fields: vec![PbField {
@@ -2334,6 +2335,7 @@ mod tests {
                         ..Default::default()
                     }),
                     name: "new_col".into(),
+                    ..Default::default()
                 }],
             })),
         };
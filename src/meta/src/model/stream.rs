@@ -305,6 +305,8 @@ impl StreamContext {
             // `self.timezone` must always be set; an invalid value is used here for debugging if it's not.
             time_zone: self.timezone.clone().unwrap_or("Empty Time Zone".into()),
             strict_mode: false,
+            jsonb_decimal_as_string: false,
+            jsonb_cast_case_insensitive: false,
         }
     }
 
@@ -436,9 +436,16 @@ impl GlobalStreamManager {
                                     .await?;
                                 let cleanup_state_table_ids =
                                     job_fragments.all_table_ids().collect_vec();
-                                self.metadata_manager.catalog_controller
+                                let (aborted, _) = self.metadata_manager.catalog_controller
                                     .try_abort_creating_streaming_job(job_id, true)
                                     .await?;
+                                if !aborted {
+                                    // The job finished creating concurrently with the
+                                    // cancel request; there is nothing to cancel.
+                                    return Err(MetaError::invalid_parameter(format!(
+                                        "streaming job {job_id} is already created"
+                                    )));
+                                }
 
                                 self.barrier_scheduler
                                     .run_command(database_id, cancel_command)
@@ -777,12 +784,6 @@ impl GlobalStreamManager {
                 );
                 return Ok(None);
             }
-            if fragment.is_created() {
-                Err(MetaError::invalid_parameter(format!(
-                    "streaming job {} is already created",
-                    id
-                )))?;
-            }
 
             let cancel_command = self
                 .metadata_manager
@@ -791,11 +792,19 @@ impl GlobalStreamManager {
                 .await?;
             let cleanup_state_table_ids = fragment.all_table_ids().collect_vec();
 
-            let (_, database_id) = self
+            let (aborted, database_id) = self
                 .metadata_manager
                 .catalog_controller
                 .try_abort_creating_streaming_job(id, true)
                 .await?;
+            if !aborted {
+                // The job finished creating between the status check above and the abort.
+                tracing::warn!(
+                    "streaming job {} is already created, ignore cancel request",
+                    id
+                );
+                return Ok(None);
+            }
 
             if let Some(database_id) = database_id {
                 self.barrier_scheduler
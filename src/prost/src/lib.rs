@@ -750,6 +750,7 @@ impl std::fmt::Debug for plan_common::ColumnDesc {
             generated_or_default_column,
             version,
             nullable,
+            foreign_key,
         } = self;
 
         let mut s = f.debug_struct("ColumnDesc");
@@ -777,6 +778,9 @@ impl std::fmt::Debug for plan_common::ColumnDesc {
             s.field("generated_or_default_column", &generated_or_default_column);
         }
         s.field("nullable", nullable);
+        if let Some(foreign_key) = foreign_key {
+            s.field("foreign_key", foreign_key);
+        }
         s.finish()
     }
 }
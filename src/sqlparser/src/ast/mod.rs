@@ -1118,6 +1118,7 @@ impl fmt::Display for ShowCreateType {
 pub enum CommentObject {
     Column,
     Table,
+    MaterializedView,
 }
 
 impl fmt::Display for CommentObject {
@@ -1125,6 +1126,7 @@ impl fmt::Display for CommentObject {
         match self {
             CommentObject::Column => f.write_str("COLUMN"),
             CommentObject::Table => f.write_str("TABLE"),
+            CommentObject::MaterializedView => f.write_str("MATERIALIZED VIEW"),
         }
     }
 }
@@ -1351,6 +1353,9 @@ pub enum Statement {
         with_version_columns: Vec<Ident>,
         /// `AS ( query )`
         query: Option<Box<Query>>,
+        /// `WITH NO DATA` for `CREATE TABLE AS`: create the table without
+        /// populating it from the query.
+        with_no_data: bool,
         /// `FROM cdc_source TABLE database_name.table_name`
         cdc_table_info: Option<CdcTableInfo>,
         /// `INCLUDE a AS b INCLUDE c`
@@ -1430,6 +1435,11 @@ pub enum Statement {
         stmt: FetchCursorStatement,
     },
 
+    // MOVE CURSOR, i.e. FETCH without returning the rows
+    MoveCursor {
+        stmt: FetchCursorStatement,
+    },
+
     // CLOSE CURSOR
     CloseCursor {
         stmt: CloseCursorStatement,
@@ -1726,6 +1736,9 @@ pub enum DescribeKind {
     /// `DESCRIBE <name>`
     Plain,
 
+    /// `DESCRIBE EXTENDED <name>`
+    Extended,
+
     /// `DESCRIBE FRAGMENTS <name>`
     Fragments,
 }
@@ -1819,13 +1832,10 @@ impl Statement {
                 Ok(())
             }
             Statement::Describe { name, kind } => {
-                write!(f, "DESCRIBE {}", name)?;
                 match kind {
-                    DescribeKind::Plain => {}
-
-                    DescribeKind::Fragments => {
-                        write!(f, " FRAGMENTS")?;
-                    }
+                    DescribeKind::Plain => write!(f, "DESCRIBE {}", name)?,
+                    DescribeKind::Extended => write!(f, "DESCRIBE EXTENDED {}", name)?,
+                    DescribeKind::Fragments => write!(f, "DESCRIBE FRAGMENTS {}", name)?,
                 }
                 Ok(())
             }
@@ -2073,6 +2083,7 @@ impl Statement {
                 on_conflict,
                 with_version_columns,
                 query,
+                with_no_data,
                 cdc_table_info,
                 include_column_options,
                 webhook_info,
@@ -2128,6 +2139,9 @@ impl Statement {
                 }
                 if let Some(query) = query {
                     write!(f, " AS {}", query)?;
+                    if *with_no_data {
+                        write!(f, " WITH NO DATA")?;
+                    }
                 }
                 if let Some(info) = cdc_table_info {
                     write!(f, " FROM {}", info.source_name)?;
@@ -2199,6 +2213,7 @@ impl Statement {
             Statement::CreateConnection { stmt } => write!(f, "CREATE CONNECTION {}", stmt,),
             Statement::DeclareCursor { stmt } => write!(f, "DECLARE {}", stmt,),
             Statement::FetchCursor { stmt } => write!(f, "FETCH {}", stmt),
+            Statement::MoveCursor { stmt } => write!(f, "MOVE {}", stmt),
             Statement::CloseCursor { stmt } => write!(f, "CLOSE {}", stmt),
             Statement::CreateSecret { stmt } => write!(f, "CREATE SECRET {}", stmt),
             Statement::AlterDatabase { name, operation } => {
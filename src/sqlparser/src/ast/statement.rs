@@ -742,10 +742,18 @@ pub struct FetchCursorStatement {
     pub with_properties: WithProperties,
 }
 
+impl FetchCursorStatement {
+    /// The `count` that represents `FETCH ALL`, i.e., fetching until the cursor is
+    /// exhausted.
+    pub const COUNT_ALL: u32 = u32::MAX;
+}
+
 impl ParseTo for FetchCursorStatement {
     fn parse_to(p: &mut Parser<'_>) -> ModalResult<Self> {
         let count = if p.parse_keyword(Keyword::NEXT) {
             1
+        } else if p.parse_keyword(Keyword::ALL) {
+            Self::COUNT_ALL
         } else {
             literal_u32(p)?
         };
@@ -766,6 +774,8 @@ impl fmt::Display for FetchCursorStatement {
         let mut v: Vec<String> = vec![];
         if self.count == 1 {
             v.push("NEXT ".to_owned());
+        } else if self.count == Self::COUNT_ALL {
+            v.push("ALL ".to_owned());
         } else {
             impl_fmt_display!(count, v, self);
         }
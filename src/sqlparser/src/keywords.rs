@@ -227,6 +227,7 @@ define_keywords!(
     EXISTS,
     EXP,
     EXPLAIN,
+    EXTENDED,
     EXTERNAL,
     EXTRACT,
     FALSE,
@@ -328,6 +329,7 @@ define_keywords!(
     MODIFIES,
     MODULE,
     MONTH,
+    MOVE,
     MULTISET,
     NATIONAL,
     NATIVE,
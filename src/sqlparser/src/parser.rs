@@ -245,6 +245,16 @@ impl Parser<'_> {
             })
     }
 
+    /// Parse a single expression from a string.
+    pub fn parse_expr_str(s: &str) -> Result<Expr, ParserError> {
+        let mut tokenizer = Tokenizer::new(s);
+        let tokens = tokenizer.tokenize_with_location()?;
+        let parser = Parser(&tokens);
+        Parser::parse_expr
+            .parse(parser)
+            .map_err(|e| ParserError::ParserError(e.inner().to_string()))
+    }
+
     /// Parse object name from a string.
     pub fn parse_object_name_str(s: &str) -> Result<ObjectName, ParserError> {
         let mut tokenizer = Tokenizer::new(s);
@@ -305,6 +315,7 @@ impl Parser<'_> {
                 }
                 Keyword::DECLARE => Ok(self.parse_declare()?),
                 Keyword::FETCH => Ok(self.parse_fetch_cursor()?),
+                Keyword::MOVE => Ok(self.parse_move_cursor()?),
                 Keyword::CLOSE => Ok(self.parse_close_cursor()?),
                 Keyword::TRUNCATE => Ok(self.parse_truncate()?),
                 Keyword::REFRESH => Ok(self.parse_refresh()?),
@@ -2282,6 +2293,13 @@ impl Parser<'_> {
         })
     }
 
+    pub fn parse_move_cursor(&mut self) -> ModalResult<Statement> {
+        // `MOVE` shares the syntax of `FETCH`, it just does not return the rows.
+        Ok(Statement::MoveCursor {
+            stmt: FetchCursorStatement::parse_to(self)?,
+        })
+    }
+
     pub fn parse_close_cursor(&mut self) -> ModalResult<Statement> {
         Ok(Statement::CloseCursor {
             stmt: CloseCursorStatement::parse_to(self)?,
@@ -2589,6 +2607,15 @@ impl Parser<'_> {
             None
         };
 
+        // Parse optional `WITH [NO] DATA` for `CREATE TABLE AS`
+        let with_no_data = if query.is_some() && self.parse_keyword(Keyword::WITH) {
+            let no_data = self.parse_keyword(Keyword::NO);
+            self.expect_keyword(Keyword::DATA)?;
+            no_data
+        } else {
+            false
+        };
+
         let cdc_table_info = if self.parse_keyword(Keyword::FROM) {
             let source_name = self.parse_object_name()?;
             self.expect_keyword(Keyword::TABLE)?;
@@ -2676,6 +2703,7 @@ impl Parser<'_> {
             on_conflict,
             with_version_columns,
             query,
+            with_no_data,
             cdc_table_info,
             include_column_options: include_options,
             webhook_info,
@@ -4806,11 +4834,16 @@ impl Parser<'_> {
     }
 
     pub fn parse_describe(&mut self) -> ModalResult<Statement> {
-        let kind = match self.parse_one_of_keywords(&[Keyword::FRAGMENT, Keyword::FRAGMENTS]) {
+        let kind = match self.parse_one_of_keywords(&[
+            Keyword::EXTENDED,
+            Keyword::FRAGMENT,
+            Keyword::FRAGMENTS,
+        ]) {
             Some(Keyword::FRAGMENT) => {
                 let fragment_id = self.parse_literal_u32()?;
                 return Ok(Statement::DescribeFragment { fragment_id });
             }
+            Some(Keyword::EXTENDED) => DescribeKind::Extended,
             Some(Keyword::FRAGMENTS) => DescribeKind::Fragments,
             None => DescribeKind::Plain,
             Some(_) => unreachable!(),
@@ -6308,6 +6341,11 @@ impl Parser<'_> {
                 let object_name = self.parse_object_name()?;
                 (CommentObject::Table, object_name)
             }
+            Token::Word(w) if w.keyword == Keyword::MATERIALIZED => {
+                self.expect_keyword(Keyword::VIEW)?;
+                let object_name = self.parse_object_name()?;
+                (CommentObject::MaterializedView, object_name)
+            }
             _ => self.expected_at(checkpoint, "comment object_type")?,
         };
 
@@ -1533,6 +1533,31 @@ fn parse_create_table_as() {
     }
 }
 
+#[test]
+fn parse_create_table_as_with_data() {
+    let sql = "CREATE TABLE t AS SELECT * FROM a WITH NO DATA";
+    match verified_stmt(sql) {
+        Statement::CreateTable {
+            name,
+            query,
+            with_no_data,
+            ..
+        } => {
+            assert_eq!(name.to_string(), "t".to_owned());
+            assert_eq!(query, Some(Box::new(verified_query("SELECT * FROM a"))));
+            assert!(with_no_data);
+        }
+        _ => unreachable!(),
+    }
+
+    // `WITH DATA` is the default and is not echoed back.
+    let sql = "CREATE TABLE t AS SELECT * FROM a WITH DATA";
+    match one_statement_parses_to(sql, "CREATE TABLE t AS SELECT * FROM a") {
+        Statement::CreateTable { with_no_data, .. } => assert!(!with_no_data),
+        _ => unreachable!(),
+    }
+}
+
 #[test]
 fn parse_create_table_with_on_delete_on_update_2in_any_order() -> Result<(), ParserError> {
     let sql = |options: &str| -> String {
@@ -102,6 +102,7 @@ mod test {
                             version: Pr13707,
                             system_column: None,
                             nullable: true,
+                            foreign_key: None,
                         },
                         ColumnDesc {
                             data_type: Int16,
@@ -115,6 +116,7 @@ mod test {
                             version: Pr13707,
                             system_column: None,
                             nullable: true,
+                            foreign_key: None,
                         },
                     ],
                     [
@@ -148,6 +150,7 @@ mod test {
                             version: Pr13707,
                             system_column: None,
                             nullable: true,
+                            foreign_key: None,
                         },
                         ColumnDesc {
                             data_type: Varchar,
@@ -161,6 +164,7 @@ mod test {
                             version: Pr13707,
                             system_column: None,
                             nullable: true,
+                            foreign_key: None,
                         },
                     ],
                     [
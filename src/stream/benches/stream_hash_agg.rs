@@ -85,7 +85,7 @@ fn setup_bench_hash_agg<S: StateStore>(store: S) -> Executor {
     //     Field::with_name(DataType::Int64, "sum"),
     // ];
 
-    let schema = Schema { fields };
+    let schema = Schema::new(fields);
 
     let group_key_indices = vec![0, 1];
 
@@ -2239,12 +2239,14 @@ mod tests {
                             .clone(),
                     ),
                     name: col.column_desc.as_ref().unwrap().name.clone(),
+                    ..Default::default()
                 })
                 .collect(),
             op: Some(PbSchemaChangeOp::AddColumns(PbSinkAddColumnsOp {
                 fields: vec![PbField {
                     data_type: Some(DataType::Int32.to_protobuf()),
                     name: "new_col".to_owned(),
+                    ..Default::default()
                 }],
             })),
         };
@@ -2365,6 +2367,7 @@ mod tests {
                             .clone(),
                     ),
                     name: col.column_desc.as_ref().unwrap().name.clone(),
+                    ..Default::default()
                 })
                 .collect(),
             op: Some(
@@ -2373,6 +2376,7 @@ mod tests {
                         fields: vec![PbField {
                             data_type: Some(DataType::Int32.to_protobuf()),
                             name: "age".to_owned(),
+                            ..Default::default()
                         }],
                     },
                 ),
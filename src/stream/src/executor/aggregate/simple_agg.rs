@@ -325,14 +325,12 @@ mod tests {
     }
 
     async fn test_simple_aggregation<S: StateStore>(store: S) {
-        let schema = Schema {
-            fields: vec![
-                Field::unnamed(DataType::Int64),
-                Field::unnamed(DataType::Int64),
-                // primary key column`
-                Field::unnamed(DataType::Int64),
-            ],
-        };
+        let schema = Schema::new(vec![
+            Field::unnamed(DataType::Int64),
+            Field::unnamed(DataType::Int64),
+            // primary key column`
+            Field::unnamed(DataType::Int64),
+        ]);
         let (mut tx, source) = MockSource::channel();
         let source = source.into_executor(schema, vec![2]);
         tx.push_barrier(test_epoch(1), false);
@@ -420,14 +418,12 @@ mod tests {
     #[tokio::test]
     async fn test_simple_aggregation_always_output_per_epoch() {
         let store = MemoryStateStore::new();
-        let schema = Schema {
-            fields: vec![
-                Field::unnamed(DataType::Int64),
-                Field::unnamed(DataType::Int64),
-                // primary key column`
-                Field::unnamed(DataType::Int64),
-            ],
-        };
+        let schema = Schema::new(vec![
+            Field::unnamed(DataType::Int64),
+            Field::unnamed(DataType::Int64),
+            // primary key column`
+            Field::unnamed(DataType::Int64),
+        ]);
         let (mut tx, source) = MockSource::channel();
         let source = source.into_executor(schema, vec![2]);
         // initial barrier
@@ -514,14 +510,12 @@ mod tests {
     #[tokio::test]
     async fn test_simple_aggregation_omit_noop_update() {
         let store = MemoryStateStore::new();
-        let schema = Schema {
-            fields: vec![
-                Field::unnamed(DataType::Int64),
-                Field::unnamed(DataType::Int64),
-                // primary key column`
-                Field::unnamed(DataType::Int64),
-            ],
-        };
+        let schema = Schema::new(vec![
+            Field::unnamed(DataType::Int64),
+            Field::unnamed(DataType::Int64),
+            // primary key column`
+            Field::unnamed(DataType::Int64),
+        ]);
         let (mut tx, source) = MockSource::channel();
         let source = source.into_executor(schema, vec![2]);
         // initial barrier
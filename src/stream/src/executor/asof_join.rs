@@ -1012,13 +1012,11 @@ mod tests {
         asof_desc: AsOfDesc,
         use_cache: bool,
     ) -> (MessageSender, MessageSender, BoxedMessageStream) {
-        let schema = Schema {
-            fields: vec![
-                Field::unnamed(DataType::Int64), // join key
-                Field::unnamed(DataType::Int64),
-                Field::unnamed(DataType::Int64),
-            ],
-        };
+        let schema = Schema::new(vec![
+            Field::unnamed(DataType::Int64), // join key
+            Field::unnamed(DataType::Int64),
+            Field::unnamed(DataType::Int64),
+        ]);
         let (tx_l, source_l) = MockSource::channel();
         let source_l = source_l.into_executor(schema.clone(), vec![1]);
         let (tx_r, source_r) = MockSource::channel();
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::collections::HashMap;
+use std::time::Duration;
 
 use either::Either;
 use futures::stream::{select_all, select_with_strategy};
@@ -22,7 +23,10 @@ use risingwave_common::array::{DataChunk, Op};
 use risingwave_common::bail;
 use risingwave_common::hash::{VirtualNode, VnodeBitmapExt};
 use risingwave_common::util::chunk_coalesce::DataChunkBuilder;
-use risingwave_common_rate_limit::{MonitoredRateLimiter, RateLimit, RateLimiter};
+use risingwave_common::util::epoch::Epoch;
+use risingwave_common_rate_limit::{
+    AdaptiveRateLimitConfig, MonitoredRateLimiter, RateLimit, RateLimiter,
+};
 use risingwave_pb::common::ThrottleType;
 use risingwave_storage::row_serde::value_serde::ValueRowSerde;
 use risingwave_storage::store::PrefetchOptions;
@@ -88,9 +92,11 @@ where
         metrics: Arc<StreamingMetrics>,
         chunk_size: usize,
         rate_limit: RateLimit,
+        adaptive_rate_limit_config: AdaptiveRateLimitConfig,
         fragment_id: FragmentId,
     ) -> Self {
-        let rate_limiter = RateLimiter::new(rate_limit).monitored(upstream_table.table_id());
+        let rate_limiter = RateLimiter::with_adaptive_config(rate_limit, adaptive_rate_limit_config)
+            .monitored(upstream_table.table_id());
         Self {
             upstream_table,
             upstream,
@@ -518,6 +524,13 @@ where
                 // Update snapshot read epoch.
                 snapshot_read_epoch = barrier.epoch.prev;
 
+                // Feed the observed barrier latency to the rate limiter, driving the
+                // AIMD controller of the adaptive policy. A no-op for other policies.
+                self.rate_limiter.report_latency(Duration::from_millis(
+                    Epoch::physical_now()
+                        .saturating_sub(barrier.get_curr_epoch().physical_time()),
+                ));
+
                 // TODO(kwannoel): Not sure if this holds for arrangement backfill.
                 // May need to revisit it.
                 // Need to check it after scale-in / scale-out.
@@ -386,9 +386,7 @@ mod tests {
             ColumnDesc::named("o_custkey", ColumnId::new(2), DataType::Int64),
             ColumnDesc::named("o_orderstatus", ColumnId::new(3), DataType::Varchar),
         ];
-        let rw_schema = Schema {
-            fields: columns.iter().map(Field::from).collect(),
-        };
+        let rw_schema = Schema::new(columns.iter().map(Field::from).collect());
         let props: HashMap<String, String> = convert_args!(hashmap!(
                 "hostname" => "localhost",
                 "port" => "8306",
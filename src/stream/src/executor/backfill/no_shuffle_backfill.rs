@@ -12,14 +12,18 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::time::Duration;
+
 use either::Either;
 use futures::stream;
 use futures::stream::select_with_strategy;
 use risingwave_common::array::{DataChunk, Op};
 use risingwave_common::hash::VnodeBitmapExt;
-use risingwave_common::util::epoch::EpochPair;
+use risingwave_common::util::epoch::{Epoch, EpochPair};
 use risingwave_common::{bail, row};
-use risingwave_common_rate_limit::{MonitoredRateLimiter, RateLimit, RateLimiter};
+use risingwave_common_rate_limit::{
+    AdaptiveRateLimitConfig, MonitoredRateLimiter, RateLimit, RateLimiter,
+};
 use risingwave_hummock_sdk::HummockReadEpoch;
 use risingwave_pb::common::ThrottleType;
 use risingwave_storage::store::PrefetchOptions;
@@ -105,10 +109,12 @@ where
         metrics: Arc<StreamingMetrics>,
         chunk_size: usize,
         rate_limit: RateLimit,
+        adaptive_rate_limit_config: AdaptiveRateLimitConfig,
         fragment_id: FragmentId,
     ) -> Self {
         let actor_id = progress.actor_id();
-        let rate_limiter = RateLimiter::new(rate_limit).monitored(upstream_table.table_id());
+        let rate_limiter = RateLimiter::with_adaptive_config(rate_limit, adaptive_rate_limit_config)
+            .monitored(upstream_table.table_id());
         Self {
             upstream_table,
             upstream,
@@ -427,6 +433,13 @@ where
                 // Update snapshot read epoch.
                 snapshot_read_epoch = barrier.epoch.prev;
 
+                // Feed the observed barrier latency to the rate limiter, driving the
+                // AIMD controller of the adaptive policy. A no-op for other policies.
+                self.rate_limiter.report_latency(Duration::from_millis(
+                    Epoch::physical_now()
+                        .saturating_sub(barrier.get_curr_epoch().physical_time()),
+                ));
+
                 self.progress.update(
                     barrier.epoch,
                     snapshot_read_epoch,
@@ -861,7 +861,9 @@ pub fn create_builder(
     data_types: Vec<DataType>,
 ) -> DataChunkBuilder {
     let batch_size = match rate_limit {
-        RateLimit::Disabled | RateLimit::Pause => chunk_size,
+        // The adaptive rate is dynamic, so the builder keeps the default chunk size and
+        // the limiter throttles per chunk.
+        RateLimit::Disabled | RateLimit::Adaptive | RateLimit::Pause => chunk_size,
         RateLimit::Fixed(limit) => min(limit.get() as usize, chunk_size),
     };
     // Ensure that the batch size is at least 2, to have enough space for two rows in a single update.
@@ -0,0 +1,620 @@
+// Copyright 2025 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::ops::Bound::{self, *};
+
+use futures::stream;
+use risingwave_common::array::Op;
+use risingwave_common::bitmap::{Bitmap, BitmapBuilder};
+use risingwave_common::hash::VnodeBitmapExt;
+use risingwave_common::row::once;
+use risingwave_common::types::{DefaultOrd, ToOwnedDatum};
+use risingwave_common::util::iter_util::ZipEqDebug;
+use risingwave_storage::store::PrefetchOptions;
+
+use super::barrier_align::*;
+use crate::common::table::state_table::StateTable;
+use crate::consistency::consistency_panic;
+use crate::executor::prelude::*;
+
+/// A variant of [`DynamicFilterExecutor`](super::DynamicFilterExecutor) for band
+/// predicates like `price BETWEEN (SELECT min_p FROM t) AND (SELECT max_p FROM t)`.
+///
+/// Planning such a predicate as two stacked dynamic filters keeps two copies of the left
+/// state. This executor instead takes both bounds from a single one-row, two-column right
+/// input `(lower, upper)` and keeps one copy of the left state ordered by the filtered
+/// column. When the bounds change at a barrier, only the delta rows entering or leaving
+/// the band are emitted, each computed with a range scan over the left state.
+///
+/// Both bounds are inclusive. A `NULL` bound is treated as unbounded on that side, and a
+/// crossed band (`lower > upper`) matches no rows.
+pub struct BandedDynamicFilterExecutor<S: StateStore> {
+    ctx: ActorContextRef,
+
+    schema: Schema,
+    source_l: Option<Executor>,
+    source_r: Option<Executor>,
+    key_l: usize,
+    left_table: StateTable<S>,
+    right_table: StateTable<S>,
+    metrics: Arc<StreamingMetrics>,
+    /// The maximum size of the chunk produced by executor at a time.
+    chunk_size: usize,
+}
+
+/// The band committed or staged on the right side, with `None` meaning unbounded on that
+/// side. `Empty` covers both the absence of a right row and a crossed band.
+#[derive(Clone, Debug, PartialEq)]
+enum Band {
+    Empty,
+    Range(Datum, Datum),
+}
+
+impl Band {
+    fn from_row(row: Option<&OwnedRow>) -> Self {
+        match row {
+            Some(row) => Self::from_bounds(row[0].clone(), row[1].clone()),
+            None => Self::Empty,
+        }
+    }
+
+    fn from_bounds(lower: Datum, upper: Datum) -> Self {
+        match (&lower, &upper) {
+            // A crossed band matches no rows.
+            (Some(l), Some(u)) if l.default_cmp(u).is_gt() => Self::Empty,
+            _ => Self::Range(lower, upper),
+        }
+    }
+
+    fn contains(&self, value: &ScalarImpl) -> bool {
+        match self {
+            Self::Empty => false,
+            Self::Range(lower, upper) => {
+                lower.as_ref().is_none_or(|l| l.default_cmp(value).is_le())
+                    && upper.as_ref().is_none_or(|u| value.default_cmp(u).is_le())
+            }
+        }
+    }
+}
+
+/// A range of left keys to scan, together with whether the covered rows enter
+/// (`Op::Insert`) or leave (`Op::Delete`) the band.
+type BandDelta = ((Bound<ScalarImpl>, Bound<ScalarImpl>), Op);
+
+/// Computes the ranges of left keys affected by a band change, such that emitting them
+/// with the associated op turns the output from `prev` into `curr`.
+///
+/// Disjoint bands are handled as a full delete of `prev` plus a full insert of `curr`;
+/// overlapping bands yield at most one delta per end.
+fn band_delta_ranges(prev: &Band, curr: &Band) -> Vec<BandDelta> {
+    let to_range = |lower: &Datum, upper: &Datum| {
+        (
+            lower.clone().map_or(Unbounded, Included),
+            upper.clone().map_or(Unbounded, Included),
+        )
+    };
+
+    match (prev, curr) {
+        (Band::Empty, Band::Empty) => vec![],
+        (Band::Empty, Band::Range(lower, upper)) => vec![(to_range(lower, upper), Op::Insert)],
+        (Band::Range(lower, upper), Band::Empty) => vec![(to_range(lower, upper), Op::Delete)],
+        (Band::Range(prev_lower, prev_upper), Band::Range(curr_lower, curr_upper)) => {
+            let overlaps = match (prev_lower, prev_upper, curr_lower, curr_upper) {
+                (Some(pl), _, _, Some(cu)) if pl.default_cmp(cu).is_gt() => false,
+                (_, Some(pu), Some(cl), _) if cl.default_cmp(pu).is_gt() => false,
+                _ => true,
+            };
+            if !overlaps {
+                return vec![
+                    (to_range(prev_lower, prev_upper), Op::Delete),
+                    (to_range(curr_lower, curr_upper), Op::Insert),
+                ];
+            }
+
+            let mut deltas = vec![];
+            // Lower ends: the rows between the two lower bounds enter or leave depending
+            // on which direction the bound moved. `None` is unbounded, i.e. the lowest.
+            match (prev_lower, curr_lower) {
+                (None, Some(c)) => deltas.push(((Unbounded, Excluded(c.clone())), Op::Delete)),
+                (Some(p), None) => deltas.push(((Unbounded, Excluded(p.clone())), Op::Insert)),
+                (Some(p), Some(c)) => match c.default_cmp(p) {
+                    std::cmp::Ordering::Less => {
+                        deltas.push(((Included(c.clone()), Excluded(p.clone())), Op::Insert))
+                    }
+                    std::cmp::Ordering::Greater => {
+                        deltas.push(((Included(p.clone()), Excluded(c.clone())), Op::Delete))
+                    }
+                    std::cmp::Ordering::Equal => {}
+                },
+                (None, None) => {}
+            }
+            // Upper ends, symmetrically. `None` is unbounded, i.e. the highest.
+            match (prev_upper, curr_upper) {
+                (None, Some(c)) => deltas.push(((Excluded(c.clone()), Unbounded), Op::Delete)),
+                (Some(p), None) => deltas.push(((Excluded(p.clone()), Unbounded), Op::Insert)),
+                (Some(p), Some(c)) => match c.default_cmp(p) {
+                    std::cmp::Ordering::Less => {
+                        deltas.push(((Excluded(c.clone()), Included(p.clone())), Op::Delete))
+                    }
+                    std::cmp::Ordering::Greater => {
+                        deltas.push(((Excluded(p.clone()), Included(c.clone())), Op::Insert))
+                    }
+                    std::cmp::Ordering::Equal => {}
+                },
+                (None, None) => {}
+            }
+            deltas
+        }
+    }
+}
+
+impl<S: StateStore> BandedDynamicFilterExecutor<S> {
+    #[expect(clippy::too_many_arguments)]
+    pub fn new(
+        ctx: ActorContextRef,
+        schema: Schema,
+        source_l: Executor,
+        source_r: Executor,
+        key_l: usize,
+        state_table_l: StateTable<S>,
+        state_table_r: StateTable<S>,
+        metrics: Arc<StreamingMetrics>,
+        chunk_size: usize,
+    ) -> Self {
+        Self {
+            ctx,
+            schema,
+            source_l: Some(source_l),
+            source_r: Some(source_r),
+            key_l,
+            left_table: state_table_l,
+            right_table: state_table_r,
+            metrics,
+            chunk_size,
+        }
+    }
+
+    /// Filters a left chunk against the committed band and maintains the left state,
+    /// mirroring `DynamicFilterExecutor::apply_batch`.
+    fn apply_batch(&mut self, chunk: &StreamChunk, band: &Band) -> (Vec<Op>, Bitmap) {
+        let mut new_ops = Vec::with_capacity(chunk.capacity());
+        let mut new_visibility = BitmapBuilder::with_capacity(chunk.capacity());
+        let mut last_res = false;
+
+        for (op, row) in chunk.rows() {
+            let left_val = row.datum_at(self.key_l).to_owned_datum();
+
+            let satisfied = left_val.as_ref().is_some_and(|val| band.contains(val));
+
+            match op {
+                Op::Insert | Op::Delete => {
+                    new_ops.push(op);
+                    new_visibility.append(satisfied);
+                }
+                Op::UpdateDelete => {
+                    last_res = satisfied;
+                }
+                Op::UpdateInsert => match (last_res, satisfied) {
+                    (true, false) => {
+                        new_ops.push(Op::Delete);
+                        new_ops.push(Op::UpdateInsert);
+                        new_visibility.append(true);
+                        new_visibility.append(false);
+                    }
+                    (false, true) => {
+                        new_ops.push(Op::UpdateDelete);
+                        new_ops.push(Op::Insert);
+                        new_visibility.append(false);
+                        new_visibility.append(true);
+                    }
+                    (true, true) => {
+                        new_ops.push(Op::UpdateDelete);
+                        new_ops.push(Op::UpdateInsert);
+                        new_visibility.append(true);
+                        new_visibility.append(true);
+                    }
+                    (false, false) => {
+                        new_ops.push(Op::UpdateDelete);
+                        new_ops.push(Op::UpdateInsert);
+                        new_visibility.append(false);
+                        new_visibility.append(false);
+                    }
+                },
+            }
+
+            // Rows with a NULL key can never satisfy the band and need no state.
+            if left_val.is_some() {
+                match op {
+                    Op::Insert | Op::UpdateInsert => {
+                        self.left_table.insert(row);
+                    }
+                    Op::Delete | Op::UpdateDelete => {
+                        self.left_table.delete(row);
+                    }
+                }
+            }
+        }
+
+        (new_ops, new_visibility.finish())
+    }
+
+    fn to_row_bound(bound: Bound<ScalarImpl>) -> Bound<impl Row> {
+        bound.map(|s| once(Some(s)))
+    }
+
+    #[try_stream(ok = Message, error = StreamExecutorError)]
+    async fn execute_inner(mut self) {
+        let input_l = self.source_l.take().unwrap();
+        let input_r = self.source_r.take().unwrap();
+
+        // The types are aligned by frontend.
+        let l_data_type = input_l.schema().data_types()[self.key_l].clone();
+        assert_eq!(l_data_type, input_r.schema().data_types()[0]);
+        assert_eq!(l_data_type, input_r.schema().data_types()[1]);
+
+        let aligned_stream = barrier_align(
+            input_l.execute(),
+            input_r.execute(),
+            self.ctx.id,
+            self.ctx.fragment_id,
+            self.metrics.clone(),
+            "Banded Dynamic Filter",
+        );
+
+        pin_mut!(aligned_stream);
+
+        let barrier = expect_first_barrier_from_aligned_stream(&mut aligned_stream).await?;
+        let first_epoch = barrier.epoch;
+        // The first barrier message should be propagated.
+        yield Message::Barrier(barrier);
+        self.right_table.init_epoch(first_epoch).await?;
+        self.left_table.init_epoch(first_epoch).await?;
+
+        let recovered_rhs = self.right_table.get_from_one_row_table().await?;
+        // At the beginning of an epoch, the committed band equals the staging band.
+        let mut committed_band = Band::from_row(recovered_rhs.as_ref());
+        let mut committed_rhs_row = recovered_rhs.clone();
+        let mut staging_rhs_row = recovered_rhs;
+
+        let mut stream_chunk_builder =
+            StreamChunkBuilder::new(self.chunk_size, self.schema.data_types());
+
+        #[for_await]
+        for msg in aligned_stream {
+            match msg? {
+                AlignedMessage::Left(chunk) => {
+                    let chunk = chunk.compact_vis();
+
+                    let (new_ops, new_visibility) = self.apply_batch(&chunk, &committed_band);
+                    self.left_table.try_flush().await?;
+
+                    let columns = chunk.into_parts().0.into_parts().0;
+
+                    if new_visibility.count_ones() > 0 {
+                        let new_chunk =
+                            StreamChunk::with_visibility(new_ops, columns, new_visibility);
+                        yield Message::Chunk(new_chunk)
+                    }
+                }
+                AlignedMessage::Right(chunk) => {
+                    // Record the latest update to the one-row right side.
+                    let chunk = chunk.compact_vis();
+                    let (data_chunk, ops) = chunk.into_parts();
+
+                    for (row, op) in data_chunk.rows().zip_eq_debug(ops.iter()) {
+                        match *op {
+                            Op::UpdateInsert | Op::Insert => {
+                                staging_rhs_row = Some(row.into_owned_row());
+                            }
+                            Op::UpdateDelete | Op::Delete => {
+                                // To be consistent, the deleted row must match the
+                                // staging one-row state.
+                                let row = row.into_owned_row();
+                                if staging_rhs_row.as_ref() != Some(&row) {
+                                    consistency_panic!(
+                                        current = ?staging_rhs_row,
+                                        to_delete = ?row,
+                                        "inconsistent delete",
+                                    );
+                                }
+                                staging_rhs_row = None;
+                            }
+                        }
+                    }
+                }
+                AlignedMessage::WatermarkLeft(_) | AlignedMessage::WatermarkRight(_) => {
+                    // The band moves in both directions, so watermarks can neither clean
+                    // state nor be propagated.
+                }
+                AlignedMessage::Barrier(barrier) => {
+                    // Emit the delta rows entering or leaving the band. Like
+                    // `DynamicFilterExecutor`, this block is idempotent: `committed_band`
+                    // is reset to the staging band at the end, so replaying the barrier
+                    // without new input produces no chunks.
+                    let staging_band = Band::from_row(staging_rhs_row.as_ref());
+                    for (range, op) in band_delta_ranges(&committed_band, &staging_band) {
+                        let range = (Self::to_row_bound(range.0), Self::to_row_bound(range.1));
+                        let streams = futures::future::try_join_all(
+                            self.left_table.vnodes().iter_vnodes().map(|vnode| {
+                                self.left_table.iter_with_vnode(
+                                    vnode,
+                                    &range,
+                                    PrefetchOptions::prefetch_for_small_range_scan(),
+                                )
+                            }),
+                        )
+                        .await?
+                        .into_iter()
+                        .map(Box::pin);
+
+                        #[for_await]
+                        for res in stream::select_all(streams) {
+                            let row = res?;
+                            if let Some(chunk) = stream_chunk_builder.append_row(op, row.as_ref()) {
+                                yield Message::Chunk(chunk);
+                            }
+                        }
+                    }
+                    if let Some(chunk) = stream_chunk_builder.take() {
+                        yield Message::Chunk(chunk);
+                    }
+
+                    // Update the committed row on RHS if it has changed, only writing
+                    // from the actor in charge of vnode 0 on LHS.
+                    if committed_rhs_row != staging_rhs_row && self.left_table.vnodes().is_set(0) {
+                        if let Some(old_row) = committed_rhs_row.take() {
+                            self.right_table.delete(old_row);
+                        }
+                        if let Some(row) = &staging_rhs_row {
+                            self.right_table.insert(row);
+                        }
+                    }
+
+                    let left_post_commit = self.left_table.commit(barrier.epoch).await?;
+                    self.right_table
+                        .commit_assert_no_update_vnode_bitmap(barrier.epoch)
+                        .await?;
+
+                    committed_rhs_row.clone_from(&staging_rhs_row);
+                    committed_band = staging_band;
+
+                    let update_vnode_bitmap = barrier.as_update_vnode_bitmap(self.ctx.id);
+                    yield Message::Barrier(barrier);
+
+                    // Update the vnode bitmap for the left state table if asked.
+                    left_post_commit
+                        .post_yield_barrier(update_vnode_bitmap)
+                        .await?;
+                }
+            }
+        }
+    }
+}
+
+impl<S: StateStore> Execute for BandedDynamicFilterExecutor<S> {
+    fn execute(self: Box<Self>) -> BoxedMessageStream {
+        self.execute_inner().boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::array::*;
+    use risingwave_common::catalog::{ColumnDesc, ColumnId, Field, TableId};
+    use risingwave_common::util::epoch::test_epoch;
+    use risingwave_common::util::sort_util::OrderType;
+    use risingwave_storage::memory::MemoryStateStore;
+
+    use super::*;
+    use crate::common::table::test_utils::gen_pbtable;
+    use crate::executor::test_utils::{MessageSender, MockSource, StreamExecutorTestExt};
+
+    async fn create_in_memory_state_table(
+        mem_state: MemoryStateStore,
+    ) -> (StateTable<MemoryStateStore>, StateTable<MemoryStateStore>) {
+        let column_descs = vec![ColumnDesc::unnamed(ColumnId::new(0), DataType::Int64)];
+        let state_table_l = StateTable::from_table_catalog(
+            &gen_pbtable(
+                TableId::new(0),
+                column_descs,
+                vec![OrderType::ascending()],
+                vec![0],
+                0,
+            ),
+            mem_state.clone(),
+            None,
+        )
+        .await;
+        let column_descs = vec![
+            ColumnDesc::unnamed(ColumnId::new(0), DataType::Int64),
+            ColumnDesc::unnamed(ColumnId::new(1), DataType::Int64),
+        ];
+        let state_table_r = StateTable::from_table_catalog(
+            &gen_pbtable(TableId::new(1), column_descs, vec![], vec![], 0),
+            mem_state,
+            None,
+        )
+        .await;
+        (state_table_l, state_table_r)
+    }
+
+    async fn create_executor(
+        store: MemoryStateStore,
+    ) -> (MessageSender, MessageSender, BoxedMessageStream) {
+        let (mem_state_l, mem_state_r) = create_in_memory_state_table(store).await;
+        let schema_l = Schema::new(vec![Field::unnamed(DataType::Int64)]);
+        let schema_r = Schema::new(vec![
+            Field::unnamed(DataType::Int64),
+            Field::unnamed(DataType::Int64),
+        ]);
+        let (tx_l, source_l) = MockSource::channel();
+        let source_l = source_l.into_executor(schema_l, vec![0]);
+        let (tx_r, source_r) = MockSource::channel();
+        let source_r = source_r.into_executor(schema_r, vec![]);
+
+        let executor = BandedDynamicFilterExecutor::<MemoryStateStore>::new(
+            ActorContext::for_test(123),
+            source_l.schema().clone(),
+            source_l,
+            source_r,
+            0,
+            mem_state_l,
+            mem_state_r,
+            Arc::new(StreamingMetrics::unused()),
+            1024,
+        );
+        (tx_l, tx_r, executor.boxed().execute())
+    }
+
+    #[tokio::test]
+    async fn test_banded_dynamic_filter_band_moves() -> StreamExecutorResult<()> {
+        let mem_store = MemoryStateStore::new();
+        let (mut tx_l, mut tx_r, mut banded_filter) = create_executor(mem_store).await;
+
+        // push the init barrier for left and right
+        tx_l.push_barrier(test_epoch(1), false);
+        tx_r.push_barrier(test_epoch(1), false);
+        banded_filter.next_unwrap_ready_barrier()?;
+
+        tx_l.push_chunk(StreamChunk::from_pretty(
+            "  I
+             + 1
+             + 2
+             + 3
+             + 4
+             + 5",
+        ));
+        tx_r.push_chunk(StreamChunk::from_pretty(
+            "  I I
+             + 2 4",
+        ));
+        tx_l.push_barrier(test_epoch(2), false);
+        tx_r.push_barrier(test_epoch(2), false);
+
+        // The band becomes [2, 4], so the covered rows enter the output.
+        let chunk = banded_filter.next_unwrap_ready_chunk()?.compact_vis();
+        assert_eq!(
+            chunk,
+            StreamChunk::from_pretty(
+                " I
+                + 2
+                + 3
+                + 4"
+            )
+        );
+        banded_filter.next_unwrap_ready_barrier()?;
+
+        // Move both bounds simultaneously: [2, 4] -> [3, 5]. Only the delta rows at the
+        // two ends are emitted.
+        tx_r.push_chunk(StreamChunk::from_pretty(
+            "  I I
+             - 2 4
+             + 3 5",
+        ));
+        tx_l.push_barrier(test_epoch(3), false);
+        tx_r.push_barrier(test_epoch(3), false);
+
+        let chunk = banded_filter.next_unwrap_ready_chunk()?.compact_vis();
+        assert_eq!(
+            chunk,
+            StreamChunk::from_pretty(
+                " I
+                - 2
+                + 5"
+            )
+        );
+        banded_filter.next_unwrap_ready_barrier()?;
+
+        // Left changes are filtered against the committed band [3, 5].
+        tx_l.push_chunk(StreamChunk::from_pretty(
+            "  I
+             + 6
+             - 4",
+        ));
+        let chunk = banded_filter.next_unwrap_ready_chunk()?.compact_vis();
+        assert_eq!(
+            chunk,
+            StreamChunk::from_pretty(
+                " I
+                - 4"
+            )
+        );
+
+        // A crossed band (lower > upper) matches no rows, so everything leaves.
+        tx_r.push_chunk(StreamChunk::from_pretty(
+            "  I I
+             - 3 5
+             + 5 3",
+        ));
+        tx_l.push_barrier(test_epoch(4), false);
+        tx_r.push_barrier(test_epoch(4), false);
+
+        let chunk = banded_filter.next_unwrap_ready_chunk()?.compact_vis();
+        assert_eq!(
+            chunk,
+            StreamChunk::from_pretty(
+                " I
+                - 3
+                - 5"
+            )
+        );
+        banded_filter.next_unwrap_ready_barrier()?;
+
+        // A NULL bound is unbounded on that side: (-inf, 2] covers rows 1 and 2.
+        tx_r.push_chunk(StreamChunk::from_pretty(
+            "  I I
+             - 5 3
+             + . 2",
+        ));
+        tx_l.push_barrier(test_epoch(5), false);
+        tx_r.push_barrier(test_epoch(5), false);
+
+        let chunk = banded_filter.next_unwrap_ready_chunk()?.compact_vis();
+        assert_eq!(
+            chunk,
+            StreamChunk::from_pretty(
+                " I
+                + 1
+                + 2"
+            )
+        );
+        banded_filter.next_unwrap_ready_barrier()?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_band_delta_ranges_disjoint() {
+        let band = |l: i64, u: i64| Band::from_bounds(Some(l.into()), Some(u.into()));
+
+        // Disjoint bands fall back to a full delete plus a full insert.
+        let deltas = band_delta_ranges(&band(1, 2), &band(10, 20));
+        assert_eq!(
+            deltas,
+            vec![
+                (
+                    (Included(ScalarImpl::from(1i64)), Included(2i64.into())),
+                    Op::Delete
+                ),
+                (
+                    (Included(ScalarImpl::from(10i64)), Included(20i64.into())),
+                    Op::Insert
+                ),
+            ]
+        );
+
+        // An unchanged band yields no deltas.
+        assert!(band_delta_ranges(&band(1, 2), &band(1, 2)).is_empty());
+    }
+}
@@ -138,6 +138,14 @@ impl<S: StateStore> AppendOnlyDedupExecutor<S> {
                 }
 
                 Message::Watermark(watermark) => {
+                    // If the watermark is on the state-cleaning column of the state table,
+                    // i.e. the first dedup column, dedup keys below the watermark are
+                    // cleaned with a range delete when committing. Deduplication remains
+                    // exact within the watermark-defined retention window, and best-effort
+                    // beyond it as long as the cleaned keys stay in the cache.
+                    if self.state_table.clean_watermark_index == Some(watermark.col_idx) {
+                        self.state_table.update_watermark(watermark.val.clone());
+                    }
                     yield Message::Watermark(watermark);
                 }
             }
@@ -272,4 +280,86 @@ mod tests {
             )
         );
     }
+
+    #[tokio::test]
+    async fn test_dedup_executor_state_cleaning() {
+        let table_id = TableId::new(1);
+        let column_descs = vec![
+            ColumnDesc::unnamed(ColumnId::new(0), DataType::Int64),
+            ColumnDesc::unnamed(ColumnId::new(1), DataType::Int64),
+        ];
+        let schema = Schema::new(vec![
+            Field::unnamed(DataType::Int64),
+            Field::unnamed(DataType::Int64),
+        ]);
+        let dedup_col_indices = vec![0];
+        let pk_indices = dedup_col_indices.clone();
+        let order_types = vec![OrderType::ascending()];
+
+        let state_store = MemoryStateStore::new();
+        let state_table = StateTable::from_table_catalog(
+            &gen_pbtable(table_id, column_descs, order_types, pk_indices.clone(), 0),
+            state_store,
+            None,
+        )
+        .await;
+
+        let (mut tx, source) = MockSource::channel();
+        let source = source.into_executor(schema, pk_indices);
+        let mut dedup_executor = AppendOnlyDedupExecutor::new(
+            ActorContext::for_test(123),
+            source,
+            dedup_col_indices,
+            state_table,
+            Arc::new(AtomicU64::new(0)),
+            Arc::new(StreamingMetrics::unused()),
+        )
+        .boxed()
+        .execute();
+
+        tx.push_barrier(test_epoch(1), false);
+        dedup_executor.next().await.unwrap().unwrap();
+
+        let chunk = StreamChunk::from_pretty(
+            " I I
+            + 1 1
+            + 2 2",
+        );
+        tx.push_chunk(chunk);
+        let msg = dedup_executor.next().await.unwrap().unwrap();
+        assert_eq!(
+            msg.into_chunk().unwrap(),
+            StreamChunk::from_pretty(
+                " I I
+                + 1 1
+                + 2 2",
+            )
+        );
+
+        // A watermark on the dedup column triggers state cleaning on the next commit.
+        tx.push_watermark(0, DataType::Int64, 10i64.into());
+        let msg = dedup_executor.next().await.unwrap().unwrap();
+        assert!(matches!(msg, Message::Watermark(_)));
+
+        tx.push_barrier(test_epoch(2), false);
+        dedup_executor.next().await.unwrap().unwrap();
+
+        // Late duplicates below the watermark are still dropped as long as their keys
+        // remain in the cache, even though the state may have been cleaned.
+        let chunk = StreamChunk::from_pretty(
+            " I I
+            + 1 5
+            + 11 6",
+        );
+        tx.push_chunk(chunk);
+        let msg = dedup_executor.next().await.unwrap().unwrap();
+        assert_eq!(
+            msg.into_chunk().unwrap(),
+            StreamChunk::from_pretty(
+                " I I
+                + 1 5 D
+                + 11 6",
+            )
+        );
+    }
 }
@@ -1374,7 +1374,7 @@ mod tests {
 
     #[tokio::test]
     async fn test_configuration_change() {
-        let _schema = Schema { fields: vec![] };
+        let _schema = Schema::new(vec![]);
         let (tx, rx) = channel_for_test();
         let actor_id = 233.into();
         let barrier_test_env = LocalBarrierTestEnv::for_test().await;
@@ -1663,12 +1663,10 @@ mod tests {
         // This test only works when vnode count is 256.
         assert_eq!(VirtualNode::COUNT_FOR_TEST, 256);
 
-        let schema = Schema {
-            fields: vec![
-                Field::unnamed(DataType::Int64),
-                Field::unnamed(DataType::Int64),
-            ],
-        };
+        let schema = Schema::new(vec![
+            Field::unnamed(DataType::Int64),
+            Field::unnamed(DataType::Int64),
+        ]);
         let (mut tx, source) = MockSource::channel();
         let source = source.into_executor(schema, vec![0]);
 
@@ -1724,12 +1722,10 @@ mod tests {
 
     #[tokio::test]
     async fn test_hash_dispatcher_missing_update_delete_after_project() {
-        let schema = Schema {
-            fields: vec![
-                Field::unnamed(DataType::Int64),
-                Field::unnamed(DataType::Int64),
-            ],
-        };
+        let schema = Schema::new(vec![
+            Field::unnamed(DataType::Int64),
+            Field::unnamed(DataType::Int64),
+        ]);
         let (mut tx, source) = MockSource::channel();
         let source = source.into_executor(schema, vec![0]);
 
@@ -597,12 +597,10 @@ mod tests {
         };
         let (mut input_tx, source) = MockSource::channel();
         let input = source.into_executor(
-            Schema {
-                fields: vec![
-                    Field::unnamed(DataType::Int64),
-                    Field::unnamed(DataType::Varchar),
-                ],
-            },
+            Schema::new(vec![
+                Field::unnamed(DataType::Int64),
+                Field::unnamed(DataType::Varchar),
+            ]),
             vec![0],
         );
 
@@ -421,6 +421,13 @@ async fn apply_dml_rate_limit(
                         yield TxnMsg::Data(txn_id, chunk);
                         continue;
                     }
+                    // The adaptive policy's effective rate changes over time, so the
+                    // chunk is not split; just wait for its permits.
+                    RateLimit::Adaptive => {
+                        rate_limiter.wait_chunk(&chunk).await;
+                        yield TxnMsg::Data(txn_id, chunk);
+                        continue;
+                    }
                     RateLimit::Fixed(limit) => {
                         let max_permits = limit.get();
                         let required_permits = chunk.rate_limit_permits();
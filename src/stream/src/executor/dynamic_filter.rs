@@ -546,9 +546,7 @@ mod tests {
         cleaned_by_watermark: bool,
     ) -> (MessageSender, MessageSender, BoxedMessageStream) {
         let (mem_state_l, mem_state_r) = create_in_memory_state_table(store).await;
-        let schema = Schema {
-            fields: vec![Field::unnamed(DataType::Int64)],
-        };
+        let schema = Schema::new(vec![Field::unnamed(DataType::Int64)]);
         let (tx_l, source_l) = MockSource::channel();
         let source_l = source_l.into_executor(schema.clone(), vec![0]);
         let (tx_r, source_r) = MockSource::channel();
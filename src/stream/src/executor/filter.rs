@@ -224,12 +224,10 @@ mod tests {
             U- 3 5  // false -> false
             U+ 4 6  // expect nothing",
         );
-        let schema = Schema {
-            fields: vec![
-                Field::unnamed(DataType::Int64),
-                Field::unnamed(DataType::Int64),
-            ],
-        };
+        let schema = Schema::new(vec![
+            Field::unnamed(DataType::Int64),
+            Field::unnamed(DataType::Int64),
+        ]);
         let stream_key = StreamKey::new();
         let source = MockSource::with_chunks(vec![chunk1, chunk2])
             .into_executor(schema.clone(), stream_key.clone());
@@ -285,12 +283,10 @@ mod tests {
             - 30 .
             ",
         );
-        let schema = Schema {
-            fields: vec![
-                Field::unnamed(DataType::Int64),
-                Field::unnamed(DataType::Int64),
-            ],
-        };
+        let schema = Schema::new(vec![
+            Field::unnamed(DataType::Int64),
+            Field::unnamed(DataType::Int64),
+        ]);
         let stream_key = vec![0];
         let source =
             MockSource::with_chunks(vec![chunk]).into_executor(schema.clone(), stream_key.clone());
@@ -1522,12 +1522,10 @@ mod tests {
         condition_text: Option<String>,
         inequality_pairs: Vec<InequalityPairInfo>,
     ) -> (MessageSender, MessageSender, BoxedMessageStream) {
-        let schema = Schema {
-            fields: vec![
-                Field::unnamed(DataType::Int64), // join key
-                Field::unnamed(DataType::Int64),
-            ],
-        };
+        let schema = Schema::new(vec![
+            Field::unnamed(DataType::Int64), // join key
+            Field::unnamed(DataType::Int64),
+        ]);
         let (tx_l, source_l) = MockSource::channel();
         let source_l = source_l.into_executor(schema.clone(), vec![1]);
         let (tx_r, source_r) = MockSource::channel();
@@ -1638,13 +1636,11 @@ mod tests {
     async fn create_append_only_executor<const T: JoinTypePrimitive>(
         with_condition: bool,
     ) -> (MessageSender, MessageSender, BoxedMessageStream) {
-        let schema = Schema {
-            fields: vec![
-                Field::unnamed(DataType::Int64),
-                Field::unnamed(DataType::Int64),
-                Field::unnamed(DataType::Int64),
-            ],
-        };
+        let schema = Schema::new(vec![
+            Field::unnamed(DataType::Int64),
+            Field::unnamed(DataType::Int64),
+            Field::unnamed(DataType::Int64),
+        ]);
         let (tx_l, source_l) = MockSource::channel();
         let source_l = source_l.into_executor(schema.clone(), vec![0]);
         let (tx_r, source_r) = MockSource::channel();
@@ -3720,12 +3716,10 @@ mod tests {
     async fn create_executor_with_evict_interval<const T: JoinTypePrimitive>(
         evict_interval: u32,
     ) -> (MessageSender, MessageSender, BoxedMessageStream) {
-        let schema = Schema {
-            fields: vec![
-                Field::unnamed(DataType::Int64), // join key
-                Field::unnamed(DataType::Int64),
-            ],
-        };
+        let schema = Schema::new(vec![
+            Field::unnamed(DataType::Int64), // join key
+            Field::unnamed(DataType::Int64),
+        ]);
         let (tx_l, source_l) = MockSource::channel();
         let source_l = source_l.into_executor(schema.clone(), vec![1]);
         let (tx_r, source_r) = MockSource::channel();
@@ -48,6 +48,8 @@ async fn test_merger_sum_aggr() {
     let expr_context = ExprContext {
         time_zone: String::from("UTC"),
         strict_mode: false,
+        jsonb_decimal_as_string: false,
+        jsonb_cast_case_insensitive: false,
     };
 
     let barrier_test_env = LocalBarrierTestEnv::for_test().await;
@@ -63,9 +65,7 @@ async fn test_merger_sum_aggr() {
     let mut make_actor = |input_rx| {
         let actor_id = gen_next_actor_id();
         let actor_ctx = ActorContext::for_test(actor_id);
-        let input_schema = Schema {
-            fields: vec![Field::unnamed(DataType::Int64)],
-        };
+        let input_schema = Schema::new(vec![Field::unnamed(DataType::Int64)]);
         let local_barrier_manager = barrier_test_env.local_barrier_manager.clone();
         let expr_context = expr_context.clone();
         let (tx, rx) = channel_for_test();
@@ -421,12 +421,10 @@ mod tests {
              + 1 1",
         );
 
-        let schema = Schema {
-            fields: vec![
-                Field::unnamed(DataType::Int64), // join key
-                Field::unnamed(DataType::Int64),
-            ],
-        };
+        let schema = Schema::new(vec![
+            Field::unnamed(DataType::Int64), // join key
+            Field::unnamed(DataType::Int64),
+        ]);
         let (mut tx_l, source_l) = MockSource::channel();
         let source_l = source_l
             .stop_on_finish(false)
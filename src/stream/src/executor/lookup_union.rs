@@ -129,9 +129,7 @@ mod tests {
 
     #[tokio::test]
     async fn lookup_union() {
-        let schema = Schema {
-            fields: vec![Field::unnamed(DataType::Int64)],
-        };
+        let schema = Schema::new(vec![Field::unnamed(DataType::Int64)]);
         let source0 = MockSource::with_messages(vec![
             Message::Chunk(StreamChunk::from_pretty("I\n + 1")),
             Message::Barrier(Barrier::new_test_barrier(test_epoch(1))),
@@ -75,6 +75,7 @@ pub mod monitor;
 pub mod aggregate;
 pub mod asof_join;
 mod backfill;
+mod banded_dynamic_filter;
 mod barrier_recv;
 mod batch_query;
 mod chain;
@@ -140,6 +141,7 @@ pub use backfill::cdc::{
 };
 pub use backfill::no_shuffle_backfill::*;
 pub use backfill::snapshot_backfill::*;
+pub use banded_dynamic_filter::BandedDynamicFilterExecutor;
 pub use barrier_recv::BarrierRecvExecutor;
 pub use batch_query::BatchQueryExecutor;
 pub use chain::ChainExecutor;
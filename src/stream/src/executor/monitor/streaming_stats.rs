@@ -120,6 +120,7 @@ pub struct StreamingMetrics {
     group_top_n_cache_miss_count: LabelGuardedIntCounterVec,
     group_top_n_total_query_cache_count: LabelGuardedIntCounterVec,
     group_top_n_cached_entry_count: LabelGuardedIntGaugeVec,
+    group_top_n_evicted_group_count: LabelGuardedIntCounterVec,
     // TODO(rc): why not just use the above three?
     group_top_n_appendonly_cache_miss_count: LabelGuardedIntCounterVec,
     group_top_n_appendonly_total_query_cache_count: LabelGuardedIntCounterVec,
@@ -684,6 +685,14 @@ impl StreamingMetrics {
         )
         .unwrap();
 
+        let group_top_n_evicted_group_count = register_guarded_int_counter_vec_with_registry!(
+            "stream_group_top_n_evicted_group_count",
+            "Count of emptied groups evicted from group top n executor cache",
+            &["table_id", "actor_id", "fragment_id"],
+            registry
+        )
+        .unwrap();
+
         let group_top_n_appendonly_cache_miss_count =
             register_guarded_int_counter_vec_with_registry!(
                 "stream_group_top_n_appendonly_cache_miss_count",
@@ -1355,6 +1364,7 @@ impl StreamingMetrics {
             group_top_n_cache_miss_count,
             group_top_n_total_query_cache_count,
             group_top_n_cached_entry_count,
+            group_top_n_evicted_group_count,
             group_top_n_appendonly_cache_miss_count,
             group_top_n_appendonly_total_query_cache_count,
             group_top_n_appendonly_cached_entry_count,
@@ -1538,6 +1548,9 @@ impl StreamingMetrics {
             group_top_n_cached_entry_count: self
                 .group_top_n_cached_entry_count
                 .with_guarded_label_values(label_list),
+            group_top_n_evicted_group_count: self
+                .group_top_n_evicted_group_count
+                .with_guarded_label_values(label_list),
         }
     }
 
@@ -1563,6 +1576,10 @@ impl StreamingMetrics {
             group_top_n_cached_entry_count: self
                 .group_top_n_appendonly_cached_entry_count
                 .with_guarded_label_values(label_list),
+            // Append-only group top-n never deletes rows, so no group ever empties out.
+            group_top_n_evicted_group_count: self
+                .group_top_n_evicted_group_count
+                .with_guarded_label_values(label_list),
         }
     }
 
@@ -1863,6 +1880,7 @@ pub struct GroupTopNMetrics {
     pub group_top_n_cache_miss_count: LabelGuardedIntCounter,
     pub group_top_n_total_query_cache_count: LabelGuardedIntCounter,
     pub group_top_n_cached_entry_count: LabelGuardedIntGauge,
+    pub group_top_n_evicted_group_count: LabelGuardedIntCounter,
 }
 
 pub struct LookupExecutorMetrics {
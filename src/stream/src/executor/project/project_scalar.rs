@@ -245,12 +245,10 @@ mod tests {
             + 7 8
             - 3 6",
         );
-        let schema = Schema {
-            fields: vec![
-                Field::unnamed(DataType::Int64),
-                Field::unnamed(DataType::Int64),
-            ],
-        };
+        let schema = Schema::new(vec![
+            Field::unnamed(DataType::Int64),
+            Field::unnamed(DataType::Int64),
+        ]);
         let stream_key = vec![0];
         let (mut tx, source) = MockSource::channel();
         let source = source.into_executor(schema, stream_key);
@@ -326,12 +324,10 @@ mod tests {
 
     #[tokio::test]
     async fn test_watermark_projection() {
-        let schema = Schema {
-            fields: vec![
-                Field::unnamed(DataType::Int64),
-                Field::unnamed(DataType::Int64),
-            ],
-        };
+        let schema = Schema::new(vec![
+            Field::unnamed(DataType::Int64),
+            Field::unnamed(DataType::Int64),
+        ]);
         let (mut tx, source) = MockSource::channel();
         let source = source.into_executor(schema, StreamKey::new());
 
@@ -1298,9 +1298,7 @@ mod tests {
     #[tokio::test]
     async fn test_source_executor() {
         let source_id = 0.into();
-        let schema = Schema {
-            fields: vec![Field::with_name(DataType::Int32, "sequence_int")],
-        };
+        let schema = Schema::new(vec![Field::with_name(DataType::Int32, "sequence_int")]);
         let row_id_index = None;
         let source_info = StreamSourceInfo {
             row_format: PbRowFormatType::Native as i32,
@@ -1385,9 +1383,7 @@ mod tests {
     #[tokio::test]
     async fn test_split_change_mutation() {
         let source_id = SourceId::new(0);
-        let schema = Schema {
-            fields: vec![Field::with_name(DataType::Int32, "v1")],
-        };
+        let schema = Schema::new(vec![Field::with_name(DataType::Int32, "v1")]);
         let row_id_index = None;
         let source_info = StreamSourceInfo {
             row_format: PbRowFormatType::Native as i32,
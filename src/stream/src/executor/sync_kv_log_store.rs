@@ -1367,7 +1367,7 @@ mod tests {
             .into_iter()
             .map(|desc| Field::new(desc.name.clone(), desc.data_type))
             .collect_vec();
-        let schema = Schema { fields };
+        let schema = Schema::new(fields);
         let stream_key = vec![0];
         let (mut tx, source) = MockSource::channel();
         let source = source.into_executor(schema.clone(), stream_key.clone());
@@ -1462,7 +1462,7 @@ mod tests {
             .into_iter()
             .map(|desc| Field::new(desc.name.clone(), desc.data_type))
             .collect_vec();
-        let schema = Schema { fields };
+        let schema = Schema::new(fields);
         let stream_key = vec![0];
         let (mut tx, source) = MockSource::channel();
         let source = source.into_executor(schema.clone(), stream_key.clone());
@@ -1554,7 +1554,7 @@ mod tests {
             .into_iter()
             .map(|desc| Field::new(desc.name.clone(), desc.data_type))
             .collect_vec();
-        let schema = Schema { fields };
+        let schema = Schema::new(fields);
         let stream_key = vec![0];
         let (mut tx, source) = MockSource::channel();
         let source = source.into_executor(schema.clone(), stream_key.clone());
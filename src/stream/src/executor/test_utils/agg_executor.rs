@@ -54,7 +54,7 @@ pub fn generate_agg_schema(
         aggs.collect()
     };
 
-    Schema { fields }
+    Schema::new(fields)
 }
 
 /// Create state storage for the given agg call.
@@ -135,7 +135,7 @@ pub async fn setup_bench_stream_hash_join(
         .collect();
     let schema_len = schema.len();
     let info = ExecutorInfo::for_test(
-        Schema { fields: schema },
+        Schema::new(schema),
         vec![0, 1, 3, 4],
         "HashJoinExecutor".to_owned(),
         0,
@@ -220,6 +220,15 @@ where
                             staging,
                         )
                         .await?;
+
+                    // If the group has no rows left, drop its cache entry eagerly instead
+                    // of retaining an empty cache that will never be hit again until LRU
+                    // eviction catches up.
+                    if cache.is_table_empty() {
+                        drop(cache);
+                        self.caches.remove(group_cache_key);
+                        self.metrics.group_top_n_evicted_group_count.inc();
+                    }
                 }
             }
         }
@@ -278,24 +287,26 @@ mod tests {
     use std::sync::atomic::AtomicU64;
 
     use risingwave_common::array::stream_chunk::StreamChunkTestExt;
-    use risingwave_common::catalog::Field;
+    use risingwave_common::catalog::{ColumnDesc, ColumnId, Field, TableId};
     use risingwave_common::hash::SerializedKey;
     use risingwave_common::util::epoch::test_epoch;
     use risingwave_common::util::sort_util::OrderType;
+    use risingwave_hummock_sdk::HummockReadEpoch;
     use risingwave_storage::memory::MemoryStateStore;
+    use risingwave_storage::table::batch_table::BatchTable;
 
     use super::*;
-    use crate::executor::test_utils::top_n_executor::create_in_memory_state_table;
+    use crate::executor::test_utils::top_n_executor::{
+        create_in_memory_state_table, create_in_memory_state_table_from_state_store,
+    };
     use crate::executor::test_utils::{MockSource, StreamExecutorTestExt};
 
     fn create_schema() -> Schema {
-        Schema {
-            fields: vec![
-                Field::unnamed(DataType::Int64),
-                Field::unnamed(DataType::Int64),
-                Field::unnamed(DataType::Int64),
-            ],
-        }
+        Schema::new(vec![
+            Field::unnamed(DataType::Int64),
+            Field::unnamed(DataType::Int64),
+            Field::unnamed(DataType::Int64),
+        ])
     }
 
     fn storage_key() -> Vec<ColumnOrder> {
@@ -720,4 +731,132 @@ mod tests {
         // no output chunk for the last input chunk
         top_n.expect_barrier().await;
     }
+
+    async fn in_table(table: &BatchTable<MemoryStateStore>, pk: [i64; 3]) -> bool {
+        let row = table
+            .get_row(
+                &OwnedRow::new(pk.iter().map(|&x| Some(x.into())).collect()),
+                HummockReadEpoch::NoWait(u64::MAX),
+            )
+            .await
+            .unwrap();
+        row.is_some()
+    }
+
+    #[tokio::test]
+    async fn test_delete_whole_group_cleans_state() {
+        let schema = create_schema();
+        let source = MockSource::with_messages(vec![
+            Message::Barrier(Barrier::new_test_barrier(test_epoch(1))),
+            Message::Chunk(StreamChunk::from_pretty(
+                "  I I I
+                + 10 9 1
+                +  8 8 2
+                +  9 1 1",
+            )),
+            Message::Barrier(Barrier::new_test_barrier(test_epoch(2))),
+            // Delete all rows of groups 9 and 1: their cache entries and state-table rows
+            // must be dropped.
+            Message::Chunk(StreamChunk::from_pretty(
+                "  I I I
+                - 10 9 1
+                -  9 1 1",
+            )),
+            Message::Barrier(Barrier::new_test_barrier(test_epoch(3))),
+            // Re-inserting into an emptied group goes through a fresh cache miss and must
+            // not see any stale rows.
+            Message::Chunk(StreamChunk::from_pretty(
+                " I I I
+                + 5 9 2",
+            )),
+            Message::Barrier(Barrier::new_test_barrier(test_epoch(4))),
+        ])
+        .into_executor(schema.clone(), stream_key());
+
+        let store = MemoryStateStore::new();
+        let state_table = create_in_memory_state_table_from_state_store(
+            &schema.data_types(),
+            &[
+                OrderType::ascending(),
+                OrderType::ascending(),
+                OrderType::ascending(),
+            ],
+            &stream_key(),
+            store.clone(),
+        )
+        .await;
+        // Reads the state table written by the executor; the pk is (group key, order key,
+        // remaining stream key), i.e. columns (1, 2, 0).
+        let table = BatchTable::for_test(
+            store,
+            TableId::new(0),
+            (0..3)
+                .map(|id| ColumnDesc::unnamed(ColumnId::new(id), DataType::Int64))
+                .collect(),
+            vec![
+                OrderType::ascending(),
+                OrderType::ascending(),
+                OrderType::ascending(),
+            ],
+            stream_key(),
+            (0..3).collect(),
+        );
+
+        let top_n = GroupTopNExecutor::<SerializedKey, MemoryStateStore, false>::new(
+            source,
+            ActorContext::for_test(0),
+            schema,
+            storage_key(),
+            (0, 2),
+            order_by_1(),
+            vec![1],
+            state_table,
+            Arc::new(AtomicU64::new(0)),
+        )
+        .unwrap();
+        let mut top_n = top_n.boxed().execute();
+
+        // consume the init barrier
+        top_n.expect_barrier().await;
+        assert_eq!(
+            top_n.expect_chunk().await.sort_rows(),
+            StreamChunk::from_pretty(
+                "  I I I
+                + 10 9 1
+                +  8 8 2
+                +  9 1 1",
+            )
+            .sort_rows(),
+        );
+        top_n.expect_barrier().await;
+
+        assert_eq!(
+            top_n.expect_chunk().await.sort_rows(),
+            StreamChunk::from_pretty(
+                "  I I I
+                - 10 9 1
+                -  9 1 1",
+            )
+            .sort_rows(),
+        );
+        top_n.expect_barrier().await;
+
+        // The emptied groups left nothing behind in the state table, while the surviving
+        // group is untouched.
+        assert!(!in_table(&table, [9, 1, 10]).await);
+        assert!(!in_table(&table, [1, 1, 9]).await);
+        assert!(in_table(&table, [8, 2, 8]).await);
+
+        assert_eq!(
+            top_n.expect_chunk().await.sort_rows(),
+            StreamChunk::from_pretty(
+                " I I I
+                + 5 9 2",
+            )
+            .sort_rows(),
+        );
+        top_n.expect_barrier().await;
+
+        assert!(in_table(&table, [9, 2, 5]).await);
+    }
 }
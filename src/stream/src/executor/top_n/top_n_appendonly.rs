@@ -204,12 +204,10 @@ mod tests {
     }
 
     fn create_schema() -> Schema {
-        Schema {
-            fields: vec![
-                Field::unnamed(DataType::Int64),
-                Field::unnamed(DataType::Int64),
-            ],
-        }
+        Schema::new(vec![
+            Field::unnamed(DataType::Int64),
+            Field::unnamed(DataType::Int64),
+        ])
     }
 
     fn storage_key() -> Vec<ColumnOrder> {
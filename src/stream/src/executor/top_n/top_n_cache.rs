@@ -49,8 +49,18 @@ const TOPN_CACHE_MIN_CAPACITY: usize = 10;
 /// `OFFSET m FETCH FIRST n ROWS WITH TIES` and `m <= RANK() <= n` are not supported now,
 /// since they have different semantics.
 pub struct TopNCache<const WITH_TIES: bool> {
-    /// Rows in the range `[0, offset)`. Should always be synced with state table.
+    /// Cache of the ending rows in the range `[0, offset)`. Rows only ever move between
+    /// `low` and `middle` at the boundary, so it suffices to keep the largest rows of
+    /// the range resident, at most [`Self::low_cache_capacity`] of them. For high
+    /// offsets (e.g. `rn BETWEEN 1000 AND 1010`), the preceding rows are spilled to the
+    /// state table and reloaded only when the cache underflows.
+    ///
+    /// `None` if `offset == 0`.
     pub low: Option<Cache>,
+    pub low_cache_capacity: usize,
+    /// Number of rows in the range `[0, offset)` of the state table, i.e. rows logically
+    /// belonging to `low`, including those spilled out of the cache.
+    low_table_row_count: usize,
 
     /// Rows in the range `[offset, offset+limit)`. Should always be synced with state table.
     ///
@@ -102,8 +112,8 @@ impl<const WITH_TIES: bool> Debug for TopNCache<WITH_TIES> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "TopNCache {{\n  offset: {}, limit: {}, high_cache_capacity: {},\n",
-            self.offset, self.limit, self.high_cache_capacity
+            "TopNCache {{\n  offset: {}, limit: {}, low_cache_capacity: {}, high_cache_capacity: {},\n",
+            self.offset, self.limit, self.low_cache_capacity, self.high_cache_capacity
         )?;
 
         fn format_cache(
@@ -197,8 +207,18 @@ impl<const WITH_TIES: bool> TopNCache<WITH_TIES> {
             .and_then(|v| v.checked_mul(TOPN_CACHE_HIGH_CAPACITY_FACTOR))
             .unwrap_or(usize::MAX)
             .max(min_capacity);
+        // Only the largest rows of the `[0, offset)` range need to stay resident (see
+        // `Self::low`), so the low cache is capped similarly to the high cache. This
+        // bounds the per-group memory usage even for high offsets.
+        let low_cache_capacity = offset.min(
+            limit
+                .saturating_mul(TOPN_CACHE_HIGH_CAPACITY_FACTOR)
+                .max(TOPN_CACHE_MIN_CAPACITY),
+        );
         Self {
             low: if offset > 0 { Some(Cache::new()) } else { None },
+            low_cache_capacity,
+            low_table_row_count: 0,
             middle: Cache::new(),
             high: Cache::new(),
             high_cache_capacity,
@@ -213,23 +233,28 @@ impl<const WITH_TIES: bool> TopNCache<WITH_TIES> {
     #[allow(dead_code)]
     pub fn clear(&mut self) {
         self.low.as_mut().map(Cache::clear);
+        self.low_table_row_count = 0;
         self.middle.clear();
         self.high.clear();
     }
 
-    /// Get total count of entries in the cache.
-    pub fn len(&self) -> usize {
-        self.low.as_ref().map(Cache::len).unwrap_or(0) + self.middle.len() + self.high.len()
-    }
-
     pub(super) fn update_table_row_count(&mut self, table_row_count: usize) {
         self.table_row_count = Some(table_row_count)
     }
 
+    /// Returns whether the group is known to have no rows left in the state table, i.e.
+    /// all its rows have been deleted. Returns `false` when the row count is unknown.
+    pub(super) fn is_table_empty(&self) -> bool {
+        self.table_row_count == Some(0)
+    }
+
     pub fn low_is_full(&self) -> bool {
         if let Some(low) = &self.low {
-            assert!(low.len() <= self.offset);
-            let full = low.len() == self.offset;
+            // The low cache may keep only the tail of the range, so fullness is judged
+            // by the row count of the range rather than the cache length.
+            assert!(low.len() <= self.low_table_row_count);
+            assert!(self.low_table_row_count <= self.offset);
+            let full = self.low_table_row_count == self.offset;
             if !full {
                 assert!(self.middle.is_empty());
                 assert!(self.high.is_empty());
@@ -240,6 +265,19 @@ impl<const WITH_TIES: bool> TopNCache<WITH_TIES> {
         }
     }
 
+    /// Insert a row into the low part during cache initialization, keeping only the
+    /// largest [`Self::low_cache_capacity`] rows resident. Returns `true` if the
+    /// `[0, offset)` range is full after the insertion.
+    pub(super) fn insert_low_on_init(&mut self, cache_key: CacheKey, row: CompactedRow) -> bool {
+        let low = self.low.as_mut().expect("low cache should exist");
+        low.insert(cache_key, row);
+        if low.len() > self.low_cache_capacity {
+            low.pop_first();
+        }
+        self.low_table_row_count += 1;
+        self.low_table_row_count == self.offset
+    }
+
     pub fn middle_is_full(&self) -> bool {
         // For WITH_TIES, the middle cache can exceed the capacity.
         if !WITH_TIES {
@@ -272,9 +310,10 @@ impl<const WITH_TIES: bool> TopNCache<WITH_TIES> {
         if !self.high.is_empty() {
             true
         } else {
-            // check if table row count matches
+            // check if table row count matches; note that some rows of the low range
+            // may be spilled out of the cache, so count them logically
             self.table_row_count
-                .map(|n| n == self.len())
+                .map(|n| n == self.low_table_row_count + self.middle.len() + self.high.len())
                 .unwrap_or(false)
         }
     }
@@ -304,11 +343,18 @@ impl TopNCacheTrait for TopNCache<false> {
             // try insert into low cache
 
             if !low_is_full {
+                self.low_table_row_count += 1;
                 low.insert(to_insert.0, to_insert.1);
+                if low.len() > self.low_cache_capacity {
+                    // spill the smallest entry to keep the cache bounded, it can be
+                    // reloaded from the state table when needed
+                    low.pop_first();
+                }
                 return;
             }
 
-            // low cache is full
+            // the low range is full; the cache keeps its largest rows, so the boundary
+            // comparison below is exact
             let low_last = low.last_entry().unwrap();
             if &to_insert.0 < low_last.key() {
                 // make space for the new entry
@@ -440,13 +486,28 @@ impl TopNCacheTrait for TopNCache<false> {
         } else {
             // the row is in low
             let low = self.low.as_mut().unwrap();
+            // the cache covers the whole range iff no row has been spilled out of it
+            let low_is_synced = low.len() == self.low_table_row_count;
             let removed = low.remove(&cache_key);
 
             if removed.is_none() {
-                // the low cache should always be synced, if the key is not found, then it also doesn't
-                // exist in the state table
-                consistency_error!(?group_key, ?cache_key, "cache key not found in low cache");
-                return Ok(());
+                if low_is_synced {
+                    // the key is not found in the cache, then it also doesn't exist in
+                    // the state table
+                    consistency_error!(?group_key, ?cache_key, "cache key not found in low cache");
+                    return Ok(());
+                }
+                // otherwise, the row was spilled out of the cache and is deleted from
+                // the state table only
+            }
+            if self.low_table_row_count == 0 {
+                consistency_error!(
+                    ?group_key,
+                    ?cache_key,
+                    "low range is empty, but we receive a DELETE operation"
+                );
+            } else {
+                self.low_table_row_count -= 1;
             }
 
             // bring one element, if any, from middle cache to low cache
@@ -454,13 +515,18 @@ impl TopNCacheTrait for TopNCache<false> {
                 let middle_first = self.middle.pop_first().unwrap();
                 staging.delete(middle_first.0.clone(), middle_first.1.clone());
                 low.insert(middle_first.0, middle_first.1);
+                if low.len() > self.low_cache_capacity {
+                    // can happen when the deleted row was spilled out of the cache
+                    low.pop_first();
+                }
+                self.low_table_row_count += 1;
 
                 // fill the high cache if it's not synced
                 if !self.high_is_synced() {
                     self.high.clear();
                     managed_state
                         .fill_high_cache(
-                            group_key,
+                            group_key.as_ref(),
                             self,
                             self.last_cache_key_before_high().cloned(),
                             self.high_cache_capacity,
@@ -476,6 +542,15 @@ impl TopNCacheTrait for TopNCache<false> {
                     staging.insert(high_first.0, high_first.1);
                 }
             }
+
+            // if the low cache is drained but the range still has spilled rows, refill
+            // it from the state table so that the boundary comparisons stay exact
+            if self.low.as_ref().unwrap().is_empty() && self.low_table_row_count > 0 {
+                let end_key = self.middle.first_key_value().map(|(k, _)| k.clone());
+                managed_state
+                    .fill_low_cache(group_key, self, end_key, self.low_cache_capacity)
+                    .await?;
+            }
         }
 
         Ok(())
@@ -704,11 +779,18 @@ impl AppendOnlyTopNCacheTrait for TopNCache<false> {
             // try insert into low cache
 
             if !low_is_full {
+                self.low_table_row_count += 1;
                 low.insert(to_insert.0, to_insert.1);
+                if low.len() > self.low_cache_capacity {
+                    // spill the smallest entry to keep the cache bounded, it can be
+                    // reloaded from the state table when needed
+                    low.pop_first();
+                }
                 return Ok(());
             }
 
-            // low cache is full
+            // the low range is full; the cache keeps its largest rows, so the boundary
+            // comparison below is exact
             let low_last = low.last_entry().unwrap();
             if &to_insert.0 < low_last.key() {
                 // make space for the new entry
@@ -906,6 +988,33 @@ mod tests {
         assert_eq!(cache.high_cache_capacity, expected_capacity);
     }
 
+    #[test]
+    fn test_topn_cache_low_capacity_bounded() {
+        // for high offsets, the low cache capacity is decoupled from the offset
+        let cache = TopNCache::<false>::new(1000, 10, vec![DataType::Int32]);
+        assert_eq!(cache.low_cache_capacity, 10 * TOPN_CACHE_HIGH_CAPACITY_FACTOR);
+
+        // for small offsets, the whole low range fits in the cache
+        let cache = TopNCache::<false>::new(3, 10, vec![DataType::Int32]);
+        assert_eq!(cache.low_cache_capacity, 3);
+    }
+
+    #[test]
+    fn test_topn_cache_low_cache_eviction() {
+        let mut cache = TopNCache::<false>::new(1000, 10, vec![DataType::Int64]);
+        let mut staging = TopNStaging::new();
+        for i in 0..100u8 {
+            cache.insert((vec![i], vec![]), crate::row_nonnull![1i64], &mut staging);
+        }
+        // all rows are in the low range, of which only the largest stay resident
+        assert!(staging.is_empty());
+        assert_eq!(cache.low.as_ref().unwrap().len(), cache.low_cache_capacity);
+        assert_eq!(
+            cache.low.as_ref().unwrap().first_key_value().unwrap().0,
+            &(vec![100 - cache.low_cache_capacity as u8], vec![])
+        );
+    }
+
     #[test]
     fn test_topn_cache_min_capacity_takes_precedence_when_larger() {
         let large_min_capacity = 100;
@@ -255,12 +255,10 @@ mod tests {
         }
 
         fn create_schema() -> Schema {
-            Schema {
-                fields: vec![
-                    Field::unnamed(DataType::Int64),
-                    Field::unnamed(DataType::Int64),
-                ],
-            }
+            Schema::new(vec![
+                Field::unnamed(DataType::Int64),
+                Field::unnamed(DataType::Int64),
+            ])
         }
 
         fn storage_key() -> Vec<ColumnOrder> {
@@ -652,14 +650,12 @@ mod tests {
                 +  1 0 2 1006",
                 ),
             ];
-            let schema = Schema {
-                fields: vec![
-                    Field::unnamed(DataType::Int64),
-                    Field::unnamed(DataType::Int64),
-                    Field::unnamed(DataType::Int64),
-                    Field::unnamed(DataType::Int64),
-                ],
-            };
+            let schema = Schema::new(vec![
+                Field::unnamed(DataType::Int64),
+                Field::unnamed(DataType::Int64),
+                Field::unnamed(DataType::Int64),
+                Field::unnamed(DataType::Int64),
+            ]);
             MockSource::with_messages(vec![
                 Message::Barrier(Barrier::new_test_barrier(test_epoch(1))),
                 Message::Chunk(std::mem::take(&mut chunks[0])),
@@ -682,14 +678,12 @@ mod tests {
                 +  5 1 4 1002 ",
                 ),
             ];
-            let schema = Schema {
-                fields: vec![
-                    Field::unnamed(DataType::Int64),
-                    Field::unnamed(DataType::Int64),
-                    Field::unnamed(DataType::Int64),
-                    Field::unnamed(DataType::Int64),
-                ],
-            };
+            let schema = Schema::new(vec![
+                Field::unnamed(DataType::Int64),
+                Field::unnamed(DataType::Int64),
+                Field::unnamed(DataType::Int64),
+                Field::unnamed(DataType::Int64),
+            ]);
             MockSource::with_messages(vec![
                 Message::Barrier(Barrier::new_test_barrier(test_epoch(1))),
                 Message::Chunk(std::mem::take(&mut chunks[0])),
@@ -712,14 +706,12 @@ mod tests {
                 +  1 0 2 1006",
                 ),
             ];
-            let schema = Schema {
-                fields: vec![
-                    Field::unnamed(DataType::Int64),
-                    Field::unnamed(DataType::Int64),
-                    Field::unnamed(DataType::Int64),
-                    Field::unnamed(DataType::Int64),
-                ],
-            };
+            let schema = Schema::new(vec![
+                Field::unnamed(DataType::Int64),
+                Field::unnamed(DataType::Int64),
+                Field::unnamed(DataType::Int64),
+                Field::unnamed(DataType::Int64),
+            ]);
             MockSource::with_messages(vec![
                 Message::Barrier(Barrier::new_test_barrier(test_epoch(2))),
                 Message::Chunk(std::mem::take(&mut chunks[0])),
@@ -945,12 +937,10 @@ mod tests {
                     - 1 8",
                 ),
             ];
-            let schema = Schema {
-                fields: vec![
-                    Field::unnamed(DataType::Int64),
-                    Field::unnamed(DataType::Int64),
-                ],
-            };
+            let schema = Schema::new(vec![
+                Field::unnamed(DataType::Int64),
+                Field::unnamed(DataType::Int64),
+            ]);
             MockSource::with_messages(vec![
                 Message::Barrier(Barrier::new_test_barrier(test_epoch(1))),
                 Message::Chunk(std::mem::take(&mut chunks[0])),
@@ -1068,12 +1058,10 @@ mod tests {
                     + 10 10",
                 ),
             ];
-            let schema = Schema {
-                fields: vec![
-                    Field::unnamed(DataType::Int64),
-                    Field::unnamed(DataType::Int64),
-                ],
-            };
+            let schema = Schema::new(vec![
+                Field::unnamed(DataType::Int64),
+                Field::unnamed(DataType::Int64),
+            ]);
             MockSource::with_messages(vec![
                 Message::Barrier(Barrier::new_test_barrier(test_epoch(1))),
                 Message::Chunk(std::mem::take(&mut chunks[0])),
@@ -1094,12 +1082,10 @@ mod tests {
                     - 1 8",
                 ),
             ];
-            let schema = Schema {
-                fields: vec![
-                    Field::unnamed(DataType::Int64),
-                    Field::unnamed(DataType::Int64),
-                ],
-            };
+            let schema = Schema::new(vec![
+                Field::unnamed(DataType::Int64),
+                Field::unnamed(DataType::Int64),
+            ]);
             MockSource::with_messages(vec![
                 Message::Barrier(Barrier::new_test_barrier(test_epoch(2))),
                 Message::Chunk(std::mem::take(&mut chunks[0])),
@@ -198,6 +198,48 @@ impl<S: StateStore> ManagedTopNState<S> {
         Ok(())
     }
 
+    /// Fill the low cache of `topn_cache` with the largest rows of the `[0, offset)`
+    /// range, i.e. those just below `end_key`, by scanning the state table in reverse.
+    ///
+    /// # Arguments
+    ///
+    /// * `group_key` - Used as the prefix of the key to scan. Only for group TopN.
+    /// * `end_key` - The end point (exclusive) of the key to scan. It should be the
+    ///   first key of the middle cache, or `None` if the middle cache is empty. It
+    ///   doesn't contain the group key.
+    pub async fn fill_low_cache<const WITH_TIES: bool>(
+        &self,
+        group_key: Option<impl GroupKey>,
+        topn_cache: &mut TopNCache<WITH_TIES>,
+        end_key: Option<CacheKey>,
+        cache_size_limit: usize,
+    ) -> StreamExecutorResult<()> {
+        let low_cache = topn_cache.low.as_mut().expect("low cache should exist");
+        assert!(low_cache.is_empty());
+
+        let sub_range: &(Bound<OwnedRow>, Bound<OwnedRow>) = &(Bound::Unbounded, Bound::Unbounded);
+        let state_table_iter = self
+            .state_table
+            .rev_iter_with_prefix(&group_key, sub_range, PrefetchOptions::default())
+            .await?;
+        pin_mut!(state_table_iter);
+
+        while let Some(item) = state_table_iter.next().await {
+            let topn_row = self.get_topn_row(item?.into_owned_row(), group_key.len());
+            if let Some(end_key) = end_key.as_ref()
+                && &topn_row.cache_key >= end_key
+            {
+                continue;
+            }
+            low_cache.insert(topn_row.cache_key, (&topn_row.row).into());
+            if low_cache.len() == cache_size_limit {
+                break;
+            }
+        }
+
+        Ok(())
+    }
+
     pub async fn init_topn_cache_inner<const WITH_TIES: bool>(
         &self,
         group_key: Option<impl GroupKey>,
@@ -224,12 +266,11 @@ impl<S: StateStore> ManagedTopNState<S> {
 
         let mut group_row_count = 0;
 
-        if let Some(low) = &mut topn_cache.low {
+        if topn_cache.low.is_some() {
             while let Some(item) = state_table_iter.next().await {
                 group_row_count += 1;
                 let topn_row = self.get_topn_row(item?.into_owned_row(), group_key.len());
-                low.insert(topn_row.cache_key, (&topn_row.row).into());
-                if low.len() == topn_cache.offset {
+                if topn_cache.insert_low_on_init(topn_row.cache_key, (&topn_row.row).into()) {
                     break;
                 }
             }
@@ -490,6 +531,137 @@ mod tests {
         assert_eq!(cache.high.last_key_value().unwrap().0, &ordered_rows[4]);
     }
 
+    #[tokio::test]
+    async fn test_managed_top_n_state_fill_low_cache() {
+        let data_types = vec![DataType::Varchar, DataType::Int64];
+        let state_table = {
+            let mut tb = create_in_memory_state_table(
+                &data_types,
+                &[OrderType::ascending(), OrderType::ascending()],
+                &[0, 1],
+            )
+            .await;
+            tb.init_epoch(EpochPair::new_test_epoch(test_epoch(1)))
+                .await
+                .unwrap();
+            tb
+        };
+
+        let cache_key_serde = cache_key_serde();
+        let mut managed_state = ManagedTopNState::new(state_table, cache_key_serde.clone());
+
+        let row1 = row_nonnull!["abc", 2i64];
+        let row2 = row_nonnull!["abc", 3i64];
+        let row3 = row_nonnull!["abd", 3i64];
+        let row4 = row_nonnull!["ab", 4i64];
+        let row5 = row_nonnull!["abcd", 5i64];
+
+        let row1_bytes = serialize_pk_to_cache_key(row1.clone(), &cache_key_serde);
+        let row2_bytes = serialize_pk_to_cache_key(row2.clone(), &cache_key_serde);
+        let row3_bytes = serialize_pk_to_cache_key(row3.clone(), &cache_key_serde);
+        let row4_bytes = serialize_pk_to_cache_key(row4.clone(), &cache_key_serde);
+        let row5_bytes = serialize_pk_to_cache_key(row5.clone(), &cache_key_serde);
+        let rows = [row1, row2, row3, row4, row5];
+        let ordered_rows = [row1_bytes, row2_bytes, row3_bytes, row4_bytes, row5_bytes];
+
+        let mut cache = TopNCache::<false>::new(1, 1, data_types);
+
+        managed_state.insert(rows[3].clone());
+        managed_state.insert(rows[1].clone());
+        managed_state.insert(rows[2].clone());
+        managed_state.insert(rows[4].clone());
+
+        // rows below ("abd", 3), scanned in reverse and capped at 2, are
+        // ("abcd", 5) and ("abc", 3)
+        managed_state
+            .fill_low_cache(NO_GROUP_KEY, &mut cache, Some(ordered_rows[2].clone()), 2)
+            .await
+            .unwrap();
+        let low = cache.low.as_ref().unwrap();
+        assert_eq!(low.len(), 2);
+        assert_eq!(low.first_key_value().unwrap().0, &ordered_rows[1]);
+        assert_eq!(low.last_key_value().unwrap().0, &ordered_rows[4]);
+    }
+
+    #[tokio::test]
+    async fn test_top_n_cache_high_offset_spill_and_refill() {
+        let data_types = vec![DataType::Varchar, DataType::Int64];
+        let state_table = {
+            let mut tb = create_in_memory_state_table(
+                &data_types,
+                &[OrderType::ascending(), OrderType::ascending()],
+                &[0, 1],
+            )
+            .await;
+            tb.init_epoch(EpochPair::new_test_epoch(test_epoch(1)))
+                .await
+                .unwrap();
+            tb
+        };
+
+        let cache_key_serde = cache_key_serde();
+        let mut managed_state = ManagedTopNState::new(state_table, cache_key_serde.clone());
+
+        // OFFSET 12 LIMIT 1, with a low cache capacity of 10, so the first rows of the
+        // low range are spilled out of the cache.
+        let mut cache = TopNCache::<false>::new(12, 1, data_types);
+        assert_eq!(cache.low_cache_capacity, 10);
+
+        let rows = (0..14)
+            .map(|i| row_nonnull![format!("key-{:02}", i), i as i64])
+            .collect::<Vec<_>>();
+        let ordered_rows = rows
+            .iter()
+            .map(|row| serialize_pk_to_cache_key(row.clone(), &cache_key_serde))
+            .collect::<Vec<_>>();
+        for (row, cache_key) in rows.iter().zip(ordered_rows.iter()) {
+            managed_state.insert(row.clone());
+            cache.insert(cache_key.clone(), row.clone(), &mut TopNStaging::new());
+        }
+        // rows 0 and 1 are spilled, rows 2..=11 stay resident
+        let low = cache.low.as_ref().unwrap();
+        assert_eq!(low.len(), 10);
+        assert_eq!(low.first_key_value().unwrap().0, &ordered_rows[2]);
+
+        // Drain the resident part of the low range, then the rows brought down from
+        // middle and high. Deleting row 12 empties the low cache while the spilled
+        // rows 0 and 1 are still in the low range, triggering a refill from the state
+        // table.
+        for i in (2..=11).rev().chain([13, 12]) {
+            managed_state.delete(rows[i].clone());
+            cache
+                .delete(
+                    NO_GROUP_KEY,
+                    &mut managed_state,
+                    ordered_rows[i].clone(),
+                    rows[i].clone(),
+                    &mut TopNStaging::new(),
+                )
+                .await
+                .unwrap();
+        }
+
+        let low = cache.low.as_ref().unwrap();
+        assert_eq!(low.len(), 2);
+        assert_eq!(low.first_key_value().unwrap().0, &ordered_rows[0]);
+        assert_eq!(low.last_key_value().unwrap().0, &ordered_rows[1]);
+
+        for i in [0, 1] {
+            managed_state.delete(rows[i].clone());
+            cache
+                .delete(
+                    NO_GROUP_KEY,
+                    &mut managed_state,
+                    ordered_rows[i].clone(),
+                    rows[i].clone(),
+                    &mut TopNStaging::new(),
+                )
+                .await
+                .unwrap();
+        }
+        assert!(cache.low.as_ref().unwrap().is_empty());
+    }
+
     #[tokio::test]
     async fn test_top_n_cache_limit_1() {
         let data_types = vec![DataType::Varchar, DataType::Int64];
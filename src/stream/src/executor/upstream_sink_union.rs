@@ -565,12 +565,10 @@ mod tests {
         test_env.inject_barrier(&b1, [actor_id.into()]);
         test_env.flush_all_events().await;
 
-        let schema = Schema {
-            fields: vec![
-                Field::unnamed(DataType::Int64),
-                Field::unnamed(DataType::Int64),
-            ],
-        };
+        let schema = Schema::new(vec![
+            Field::unnamed(DataType::Int64),
+            Field::unnamed(DataType::Int64),
+        ]);
 
         let (tx1, rx1) = channel_for_test();
         let (tx2, rx2) = channel_for_test();
@@ -471,12 +471,10 @@ mod tests {
     async fn create_watermark_filter_executor(
         mem_state: MemoryStateStore,
     ) -> (Box<dyn Execute>, MessageSender) {
-        let schema = Schema {
-            fields: vec![
-                Field::unnamed(DataType::Int16),        // pk
-                Field::unnamed(WATERMARK_TYPE.clone()), // watermark column
-            ],
-        };
+        let schema = Schema::new(vec![
+            Field::unnamed(DataType::Int16),        // pk
+            Field::unnamed(WATERMARK_TYPE.clone()), // watermark column
+        ]);
 
         let watermark_expr = build_from_pretty("(subtract:timestamp $1:timestamp 1day:interval)");
 
@@ -67,12 +67,10 @@ mod tests {
 
     #[tokio::test]
     async fn test_schema_ok() {
-        let schema = Schema {
-            fields: vec![
-                Field::unnamed(DataType::Int64),
-                Field::unnamed(DataType::Float64),
-            ],
-        };
+        let schema = Schema::new(vec![
+            Field::unnamed(DataType::Int64),
+            Field::unnamed(DataType::Float64),
+        ]);
 
         let (mut tx, source) = MockSource::channel();
         let source = source.into_executor(schema, vec![1]);
@@ -94,12 +92,10 @@ mod tests {
     #[should_panic]
     #[tokio::test]
     async fn test_schema_bad() {
-        let schema = Schema {
-            fields: vec![
-                Field::unnamed(DataType::Int64),
-                Field::unnamed(DataType::Float64),
-            ],
-        };
+        let schema = Schema::new(vec![
+            Field::unnamed(DataType::Int64),
+            Field::unnamed(DataType::Float64),
+        ]);
 
         let (mut tx, source) = MockSource::channel();
         let source = source.into_executor(schema, vec![1]);
@@ -0,0 +1,63 @@
+// Copyright 2025 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use risingwave_pb::stream_plan::BandedDynamicFilterNode;
+
+use super::*;
+use crate::common::table::state_table::StateTableBuilder;
+use crate::executor::BandedDynamicFilterExecutor;
+
+pub struct BandedDynamicFilterExecutorBuilder;
+
+impl ExecutorBuilder for BandedDynamicFilterExecutorBuilder {
+    type Node = BandedDynamicFilterNode;
+
+    async fn new_boxed_executor(
+        params: ExecutorParams,
+        node: &Self::Node,
+        store: impl StateStore,
+    ) -> StreamResult<Executor> {
+        let [source_l, source_r]: [_; 2] = params.input.try_into().unwrap();
+        let key_l = node.get_left_key() as usize;
+
+        let vnodes = params.vnode_bitmap.map(Arc::new);
+
+        let state_table_r = StateTableBuilder::new(node.get_right_table()?, store.clone(), None)
+            .enable_preload_all_rows_by_config(&params.config)
+            .build()
+            .await;
+
+        let state_table_l = StateTableBuilder::new(node.get_left_table()?, store, vnodes)
+            .enable_preload_all_rows_by_config(&params.config)
+            .build()
+            .await;
+
+        let exec = BandedDynamicFilterExecutor::new(
+            params.actor_context,
+            params.info.schema.clone(),
+            source_l,
+            source_r,
+            key_l,
+            state_table_l,
+            state_table_r,
+            params.executor_stats,
+            params.config.developer.chunk_size,
+        )
+        .boxed();
+
+        Ok((params.info, exec).into())
+    }
+}
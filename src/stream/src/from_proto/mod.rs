@@ -17,6 +17,7 @@
 mod agg_common;
 mod append_only_dedup;
 mod asof_join;
+mod banded_dynamic_filter;
 mod barrier_recv;
 mod batch_query;
 mod cdc_filter;
@@ -78,6 +79,7 @@ use self::append_only_dedup::*;
 use self::approx_percentile::global::*;
 use self::approx_percentile::local::*;
 use self::asof_join::AsOfJoinExecutorBuilder;
+use self::banded_dynamic_filter::*;
 use self::barrier_recv::*;
 use self::batch_query::*;
 use self::cdc_filter::CdcFilterExecutorBuilder;
@@ -185,6 +187,7 @@ pub async fn create_executor(
         NodeBody::LookupUnion => LookupUnionExecutorBuilder,
         NodeBody::Expand => ExpandExecutorBuilder,
         NodeBody::DynamicFilter => DynamicFilterExecutorBuilder,
+        NodeBody::BandedDynamicFilter => BandedDynamicFilterExecutorBuilder,
         NodeBody::ProjectSet => ProjectSetExecutorBuilder,
         NodeBody::GroupTopN => GroupTopNExecutorBuilder::<false>,
         NodeBody::AppendOnlyGroupTopN => GroupTopNExecutorBuilder::<true>,
@@ -18,6 +18,7 @@ use anyhow::anyhow;
 use risingwave_common::catalog::ColumnId;
 use risingwave_common::util::value_encoding::BasicSerde;
 use risingwave_common::util::value_encoding::column_aware_row_encoding::ColumnAwareSerde;
+use risingwave_common_rate_limit::AdaptiveRateLimitConfig;
 use risingwave_pb::plan_common::StorageTableDesc;
 use risingwave_pb::stream_plan::{StreamScanNode, StreamScanType};
 use risingwave_storage::table::batch_table::BatchTable;
@@ -50,6 +51,12 @@ impl ExecutorBuilder for StreamScanExecutorBuilder {
             .map(|&i| i as usize)
             .collect_vec();
 
+        // Bounds for the adaptive rate limit policy, a no-op for the other policies.
+        let adaptive_rate_limit_config = AdaptiveRateLimitConfig::with_bounds(
+            node.adaptive_rate_limit_min,
+            node.adaptive_rate_limit_max,
+        );
+
         let exec = match node.stream_scan_type() {
             StreamScanType::Chain | StreamScanType::UpstreamOnly => {
                 let [upstream, snapshot]: [_; 2] = params.input.try_into().unwrap();
@@ -96,6 +103,7 @@ impl ExecutorBuilder for StreamScanExecutorBuilder {
                     params.executor_stats.clone(),
                     params.config.developer.chunk_size,
                     node.rate_limit.into(),
+                    adaptive_rate_limit_config,
                     params.fragment_id,
                 )
                 .boxed()
@@ -139,6 +147,7 @@ impl ExecutorBuilder for StreamScanExecutorBuilder {
                             params.executor_stats.clone(),
                             params.config.developer.chunk_size,
                             node.rate_limit.into(),
+                            adaptive_rate_limit_config,
                             params.fragment_id,
                         )
                         .boxed()
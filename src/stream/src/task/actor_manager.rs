@@ -229,6 +229,7 @@ impl StreamActorManager {
                     | NodeBody::StreamScan(_)
                     | NodeBody::StreamCdcScan(_)
                     | NodeBody::DynamicFilter(_)
+                    | NodeBody::BandedDynamicFilter(_)
                     | NodeBody::GroupTopN(_)
                     | NodeBody::Now(_)
             )
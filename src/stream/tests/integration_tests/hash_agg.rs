@@ -22,13 +22,11 @@ use crate::prelude::*;
 async fn test_hash_agg_count_sum() {
     let store = MemoryStateStore::new();
 
-    let schema = Schema {
-        fields: vec![
-            Field::unnamed(DataType::Int64),
-            Field::unnamed(DataType::Int64),
-            Field::unnamed(DataType::Int64),
-        ],
-    };
+    let schema = Schema::new(vec![
+        Field::unnamed(DataType::Int64),
+        Field::unnamed(DataType::Int64),
+        Field::unnamed(DataType::Int64),
+    ]);
 
     // This is local hash aggregation, so we add another sum state
     let key_indices = vec![0];
@@ -100,16 +98,14 @@ async fn test_hash_agg_count_sum() {
 async fn test_hash_agg_min() {
     let store = MemoryStateStore::new();
 
-    let schema = Schema {
-        fields: vec![
-            // group key column
-            Field::unnamed(DataType::Int64),
-            // data column to get minimum
-            Field::unnamed(DataType::Int64),
-            // primary key column
-            Field::unnamed(DataType::Int64),
-        ],
-    };
+    let schema = Schema::new(vec![
+        // group key column
+        Field::unnamed(DataType::Int64),
+        // data column to get minimum
+        Field::unnamed(DataType::Int64),
+        // primary key column
+        Field::unnamed(DataType::Int64),
+    ]);
 
     // This is local hash aggregation, so we add another row count state
     let keys = vec![0];
@@ -177,16 +173,14 @@ async fn test_hash_agg_min() {
 async fn test_hash_agg_min_append_only() {
     let store = MemoryStateStore::new();
 
-    let schema = Schema {
-        fields: vec![
-            // group key column
-            Field::unnamed(DataType::Int64),
-            // data column to get minimum
-            Field::unnamed(DataType::Int64),
-            // primary key column
-            Field::unnamed(DataType::Int64),
-        ],
-    };
+    let schema = Schema::new(vec![
+        // group key column
+        Field::unnamed(DataType::Int64),
+        // data column to get minimum
+        Field::unnamed(DataType::Int64),
+        // primary key column
+        Field::unnamed(DataType::Int64),
+    ]);
 
     let keys = vec![0];
     let agg_calls = vec![
@@ -259,12 +253,10 @@ async fn test_hash_agg_min_append_only() {
 async fn test_hash_agg_emit_on_window_close() {
     let store = MemoryStateStore::new();
 
-    let input_schema = Schema {
-        fields: vec![
-            Field::unnamed(DataType::Varchar), // to ensure correct group key column mapping
-            Field::unnamed(DataType::Int64),   // window group key column
-        ],
-    };
+    let input_schema = Schema::new(vec![
+        Field::unnamed(DataType::Varchar), // to ensure correct group key column mapping
+        Field::unnamed(DataType::Int64),   // window group key column
+    ]);
     let input_window_col = 1;
     let group_key_indices = vec![input_window_col];
     let agg_calls = vec![AggCall::from_pretty("(count:int8)")];
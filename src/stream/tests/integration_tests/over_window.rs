@@ -62,7 +62,7 @@ async fn create_executor<S: StateStore>(
         calls.iter().for_each(|call| {
             fields.push(Field::unnamed(call.return_type.clone()));
         });
-        Schema { fields }
+        Schema::new(fields)
     };
 
     let state_table = StateTable::from_table_catalog(
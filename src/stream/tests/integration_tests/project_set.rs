@@ -23,12 +23,10 @@ use crate::prelude::*;
 const CHUNK_SIZE: usize = 1024;
 
 fn create_executor() -> (MessageSender, BoxedMessageStream) {
-    let schema = Schema {
-        fields: vec![
-            Field::unnamed(DataType::Int64),
-            Field::unnamed(DataType::Int64),
-        ],
-    };
+    let schema = Schema::new(vec![
+        Field::unnamed(DataType::Int64),
+        Field::unnamed(DataType::Int64),
+    ]);
     let (tx, source) = MockSource::channel();
     let source = source.into_executor(schema, StreamKey::new());
 
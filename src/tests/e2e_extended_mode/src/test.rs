@@ -76,6 +76,7 @@ impl TestSuite {
         Self::init_logger();
         self.binary_param_and_result().await?;
         self.dql_dml_with_param().await?;
+        self.untyped_param_inference().await?;
         self.max_row().await?;
         self.multiple_on_going_portal().await?;
         self.create_with_parameter().await?;
@@ -288,6 +289,80 @@ impl TestSuite {
         Ok(())
     }
 
+    /// Parameters whose types are left unspecified in `Parse` (e.g. by JDBC) should be
+    /// inferred from the context they appear in, reported back in `ParameterDescription`,
+    /// and fall back to varchar when there is nothing to infer from.
+    async fn untyped_param_inference(&self) -> anyhow::Result<()> {
+        let client = self.create_client(false).await?;
+
+        client
+            .execute("create table t(ts timestamptz, name varchar)", &[])
+            .await?;
+
+        let timestamptz = DateTime::<Utc>::from_naive_utc_and_offset(
+            NaiveDate::from_ymd_opt(2022, 1, 1)
+                .unwrap()
+                .and_hms_opt(10, 0, 0)
+                .unwrap(),
+            Utc,
+        );
+
+        // Inferred from the columns the parameters are compared to.
+        let statement = client
+            .prepare_typed("select * from t where ts > $1 and name = $2", &[])
+            .await?;
+        test_eq!(statement.params(), [Type::TIMESTAMPTZ, Type::VARCHAR]);
+        let rows = client.query(&statement, &[&timestamptz, &"foo"]).await?;
+        test_eq!(rows.len(), 0);
+
+        // Inferred from the other elements of an IN list.
+        let statement = client
+            .prepare_typed("select 1 where 1 in ($1, $2)", &[])
+            .await?;
+        test_eq!(statement.params(), [Type::INT4, Type::INT4]);
+        let rows = client.query(&statement, &[&1_i32, &2_i32]).await?;
+        test_eq!(rows.len(), 1);
+
+        // Inferred from the other branches of COALESCE and CASE.
+        let statement = client
+            .prepare_typed("select coalesce($1, ts) from t", &[])
+            .await?;
+        test_eq!(statement.params(), [Type::TIMESTAMPTZ]);
+
+        let statement = client
+            .prepare_typed(
+                "select case when $1 then $2 else 1.5::double precision end",
+                &[],
+            )
+            .await?;
+        test_eq!(statement.params(), [Type::BOOL, Type::FLOAT8]);
+        let rows = client.query(&statement, &[&true, &2.5_f64]).await?;
+        test_eq!(rows.first().unwrap().get::<usize, f64>(0), 2.5);
+
+        // Inferred from the target columns of an INSERT.
+        let statement = client
+            .prepare_typed("insert into t values ($1, $2)", &[])
+            .await?;
+        test_eq!(statement.params(), [Type::TIMESTAMPTZ, Type::VARCHAR]);
+        client.execute(&statement, &[&timestamptz, &"foo"]).await?;
+        client.execute("flush", &[]).await?;
+        let rows = client
+            .query("select name from t where ts = $1", &[&timestamptz])
+            .await?;
+        test_eq!(rows.len(), 1);
+        test_eq!(rows.first().unwrap().get::<usize, String>(0), "foo");
+
+        // No context to infer from: default to varchar, like text in PostgreSQL.
+        let statement = client.prepare_typed("select $1", &[]).await?;
+        test_eq!(statement.params(), [Type::VARCHAR]);
+        let rows = client.query(&statement, &[&"hello"]).await?;
+        test_eq!(rows.first().unwrap().get::<usize, String>(0), "hello");
+
+        client.execute("drop table t", &[]).await?;
+
+        Ok(())
+    }
+
     async fn max_row(&self) -> anyhow::Result<()> {
         let mut client = self.create_client(false).await?;
 
@@ -399,6 +399,36 @@ async fn test_background_mv_barrier_recovery() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn test_background_mv_survives_client_disconnect() -> Result<()> {
+    init_logger();
+    let mut cluster = Cluster::start(Configuration::for_background_ddl()).await?;
+    let mut session = cluster.start_session();
+
+    session.run(CREATE_TABLE).await?;
+    session.run(SEED_TABLE_500).await?;
+    session.flush().await?;
+    session.run(SET_RATE_LIMIT_2).await?;
+    session.run(SET_BACKGROUND_DDL).await?;
+    create_mv(&mut session).await?;
+
+    // Drop the client session while the backfill is still in progress. The job is owned
+    // by the meta node, so it must keep running and complete without the client.
+    drop(session);
+
+    let mut session = cluster.start_session();
+    session.run(WAIT).await?;
+
+    let t_count = session.run("SELECT COUNT(v1) FROM t").await?;
+    let mv1_count = session.run("SELECT COUNT(v1) FROM mv1").await?;
+    assert_eq!(t_count, mv1_count);
+
+    session.run(DROP_MV1).await?;
+    session.run(DROP_TABLE).await?;
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_background_join_mv_recovery() -> Result<()> {
     init_logger();
@@ -451,10 +481,22 @@ async fn test_ddl_cancel() -> Result<()> {
     session.run(SET_RATE_LIMIT_1).await?;
     session.run(SET_BACKGROUND_DDL).await?;
 
+    // Cancelled jobs must roll back their internal state tables, so the catalog should
+    // always return to this baseline after a cancel.
+    let internal_tables_baseline = session
+        .run("select count(*) from rw_catalog.rw_internal_tables;")
+        .await?;
+
     for _ in 0..5 {
         create_mv(&mut session).await?;
         let ids = cancel_stream_jobs(&mut session).await?;
         assert_eq!(ids.len(), 1);
+        wait_until(
+            &mut session,
+            "select count(*) from rw_catalog.rw_internal_tables;",
+            &internal_tables_baseline,
+        )
+        .await?;
     }
 
     session.run(SET_RATE_LIMIT_1).await?;
@@ -464,6 +506,12 @@ async fn test_ddl_cancel() -> Result<()> {
     kill_cn_and_wait_recover(&cluster).await;
     let ids = cancel_stream_jobs(&mut session).await?;
     assert_eq!(ids.len(), 1);
+    wait_until(
+        &mut session,
+        "select count(*) from rw_catalog.rw_internal_tables;",
+        &internal_tables_baseline,
+    )
+    .await?;
     tracing::info!("tested cancel background_ddl after recovery");
 
     sleep(Duration::from_secs(2)).await;
@@ -474,6 +522,12 @@ async fn test_ddl_cancel() -> Result<()> {
     kill_random_and_wait_recover(&cluster).await;
     let ids = cancel_stream_jobs(&mut session).await?;
     assert_eq!(ids.len(), 1);
+    wait_until(
+        &mut session,
+        "select count(*) from rw_catalog.rw_internal_tables;",
+        &internal_tables_baseline,
+    )
+    .await?;
     tracing::info!("tested cancel background_ddl after recovery from random node kill");
 
     // Test cancel by sigkill (only works for foreground mv)
@@ -334,6 +334,7 @@ impl StatementType {
             Statement::Explain { .. } => Ok(StatementType::EXPLAIN),
             Statement::DeclareCursor { .. } => Ok(StatementType::DECLARE_CURSOR),
             Statement::FetchCursor { .. } => Ok(StatementType::FETCH_CURSOR),
+            Statement::MoveCursor { .. } => Ok(StatementType::MOVE),
             Statement::CloseCursor { .. } => Ok(StatementType::CLOSE_CURSOR),
             Statement::Flush => Ok(StatementType::FLUSH),
             Statement::Wait(_) => Ok(StatementType::WAIT),